@@ -1,12 +1,14 @@
 use chrono::Utc;
 
 use std::env;
+use tapsilat::money::format_amount;
 use tapsilat::{
     types::{
         BasketItemDTO, BillingAddressDTO, SubscriptionBilling, SubscriptionCreateRequest,
-        SubscriptionUser,
+        SubscriptionPeriod, SubscriptionUser,
     },
-    Config, CreateBuyerRequest, CreateOrderRequest, TapsilatClient, Validators,
+    Config, CreateBuyerRequest, CreateOrderRequest, Currency, Locale, TapsilatClient,
+    ThreeDSPreference, Validators,
 };
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -44,7 +46,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Amount
     let amount = 10.99;
     match Validators::validate_amount(amount) {
-        Ok(_) => println!("   ✅ Amount {} → Valid", amount),
+        Ok(_) => println!(
+            "   ✅ Amount {} → Valid",
+            format_amount(amount, Currency::TRY, Locale::Tr)
+        ),
         Err(e) => println!("   ❌ Amount {} → Error: {}", amount, e),
     }
 
@@ -62,6 +67,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         city: Some("Istanbul".to_string()),
         country: Some("Turkey".to_string()),
         zip_code: None,
+        citizenship: None,
     };
 
     let billing_address = BillingAddressDTO {
@@ -99,6 +105,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         quantity_unit: None,
         sub_merchant_key: None,
         sub_merchant_price: None,
+        vat_rate: None,
     };
 
     let order_request = CreateOrderRequest {
@@ -130,8 +137,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         sub_organization: None,
         submerchants: None,
         tax_amount: None,
-        three_d_force: None,
+        three_ds: ThreeDSPreference::Auto,
         consents: None,
+        checkout_expires_in: None,
+        invoice: None,
     };
 
     println!("📦 Creating Order...");
@@ -185,7 +194,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let sub_request = SubscriptionCreateRequest {
         amount: Some(100.0),
         currency: Some("TRY".to_string()),
-        period: Some(1), // Monthly?
+        period: Some(SubscriptionPeriod::new(1)),
         title: Some("Test Subscription".to_string()),
         billing: Some(SubscriptionBilling {
             contact_name: Some("Sub Subscriber".to_string()),
@@ -215,6 +224,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         payment_date: None,
         price_option: None,
         success_url: None,
+        trial_days: None,
+        trial_end_date: None,
+        recurring: None,
     };
 
     match client.create_subscription(sub_request) {
@@ -0,0 +1,241 @@
+//! Command line interface for common Tapsilat operations.
+//!
+//! Built with `cargo install tapsilat --features cli`. The API key is read from
+//! `--api-key` or the `TAPSILAT_API_KEY` environment variable.
+
+use clap::{Args, Parser, Subcommand};
+use std::io::Read;
+use std::process::ExitCode;
+use tapsilat::{
+    Config, CreateBuyerRequest, CreateOrderRequest, RefundOrderRequest, SecretString,
+    TapsilatClient, ThreeDSPreference, WebhookModule,
+};
+
+#[derive(Parser)]
+#[command(name = "tapsilat", version, about = "Tapsilat command line tools")]
+struct Cli {
+    /// API key used to authenticate with Tapsilat
+    #[arg(long, env = "TAPSILAT_API_KEY", hide_env_values = true, global = true)]
+    api_key: Option<String>,
+
+    /// Base URL of the Tapsilat API
+    #[arg(long, env = "TAPSILAT_BASE_URL", global = true)]
+    base_url: Option<String>,
+
+    /// Request timeout in seconds
+    #[arg(long, default_value_t = 30, global = true)]
+    timeout: u64,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Order operations
+    #[command(subcommand)]
+    Order(OrderCommand),
+    /// Subscription operations
+    #[command(subcommand)]
+    Subscription(SubscriptionCommand),
+    /// Webhook utilities
+    #[command(subcommand)]
+    Webhook(WebhookCommand),
+}
+
+#[derive(Subcommand)]
+enum OrderCommand {
+    /// Create an order and print its checkout URL
+    Create(CreateOrderArgs),
+    /// Show an order by reference ID
+    Get {
+        /// Order reference ID
+        reference_id: String,
+    },
+    /// Refund an order fully or partially
+    Refund {
+        /// Order reference ID
+        reference_id: String,
+        /// Amount to refund
+        #[arg(long)]
+        amount: f64,
+    },
+}
+
+#[derive(Args)]
+struct CreateOrderArgs {
+    /// Order amount
+    #[arg(long)]
+    amount: f64,
+    /// Currency code, e.g. TRY
+    #[arg(long, default_value = "TRY")]
+    currency: String,
+    /// Checkout locale
+    #[arg(long, default_value = "tr")]
+    locale: String,
+    /// Buyer first name
+    #[arg(long)]
+    buyer_name: String,
+    /// Buyer last name
+    #[arg(long)]
+    buyer_surname: String,
+    /// Buyer email address
+    #[arg(long)]
+    buyer_email: Option<String>,
+    /// Conversation ID used to correlate the order
+    #[arg(long)]
+    conversation_id: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum SubscriptionCommand {
+    /// List subscriptions
+    List {
+        /// Page number
+        #[arg(long, default_value_t = 1)]
+        page: u32,
+        /// Items per page
+        #[arg(long, default_value_t = 10)]
+        per_page: u32,
+    },
+}
+
+#[derive(Subcommand)]
+enum WebhookCommand {
+    /// Verify a webhook signature
+    Verify {
+        /// File containing the raw webhook payload, or `-` for stdin
+        #[arg(long)]
+        payload: String,
+        /// Signature received with the webhook
+        #[arg(long)]
+        signature: String,
+        /// Webhook secret
+        #[arg(long, env = "TAPSILAT_WEBHOOK_SECRET", hide_env_values = true)]
+        secret: SecretString,
+    },
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
+    match cli.command {
+        Command::Webhook(WebhookCommand::Verify {
+            payload,
+            signature,
+            secret,
+        }) => {
+            let payload = read_payload(&payload)?;
+            if WebhookModule::verify_webhook(&payload, &signature, secret.expose_secret())? {
+                println!("valid");
+                Ok(())
+            } else {
+                Err("invalid webhook signature".into())
+            }
+        }
+        Command::Order(command) => {
+            let client = build_client(cli.api_key, cli.base_url, cli.timeout)?;
+            match command {
+                OrderCommand::Create(args) => {
+                    let response = client.create_order(order_request(args))?;
+                    print_json(&response)
+                }
+                OrderCommand::Get { reference_id } => print_json(&client.get_order(&reference_id)?),
+                OrderCommand::Refund {
+                    reference_id,
+                    amount,
+                } => {
+                    let response = client.refund_order(RefundOrderRequest {
+                        amount,
+                        reference_id,
+                        order_item_id: None,
+                        order_item_payment_id: None,
+                        reason: None,
+                    })?;
+                    print_json(&response)
+                }
+            }
+        }
+        Command::Subscription(SubscriptionCommand::List { page, per_page }) => {
+            let client = build_client(cli.api_key, cli.base_url, cli.timeout)?;
+            print_json(&client.list_subscriptions(page, per_page)?)
+        }
+    }
+}
+
+fn build_client(
+    api_key: Option<String>,
+    base_url: Option<String>,
+    timeout: u64,
+) -> Result<TapsilatClient, Box<dyn std::error::Error>> {
+    let api_key = api_key.ok_or("an API key is required (--api-key or TAPSILAT_API_KEY)")?;
+    let mut config = Config::new(api_key).with_timeout(timeout);
+    if let Some(base_url) = base_url {
+        config = config.with_base_url(base_url);
+    }
+    Ok(TapsilatClient::new(config)?)
+}
+
+fn order_request(args: CreateOrderArgs) -> CreateOrderRequest {
+    CreateOrderRequest {
+        amount: args.amount,
+        currency: args.currency,
+        locale: args.locale,
+        conversation_id: args.conversation_id,
+        buyer: CreateBuyerRequest {
+            name: args.buyer_name,
+            surname: args.buyer_surname,
+            email: args.buyer_email,
+            ..Default::default()
+        },
+        basket_items: None,
+        billing_address: None,
+        shipping_address: None,
+        checkout_design: None,
+        enabled_installments: None,
+        external_reference_id: None,
+        order_cards: None,
+        paid_amount: None,
+        partial_payment: None,
+        payment_failure_url: None,
+        payment_methods: None,
+        payment_mode: None,
+        payment_options: None,
+        payment_success_url: None,
+        payment_terms: None,
+        pf_sub_merchant: None,
+        redirect_failure_url: None,
+        redirect_success_url: None,
+        sub_organization: None,
+        submerchants: None,
+        tax_amount: None,
+        three_ds: ThreeDSPreference::Auto,
+        metadata: None,
+        consents: None,
+        checkout_expires_in: None,
+        invoice: None,
+    }
+}
+
+fn read_payload(source: &str) -> std::io::Result<String> {
+    let mut payload = String::new();
+    if source == "-" {
+        std::io::stdin().read_to_string(&mut payload)?;
+    } else {
+        payload = std::fs::read_to_string(source)?;
+    }
+    Ok(payload)
+}
+
+fn print_json<T: serde::Serialize>(value: &T) -> Result<(), Box<dyn std::error::Error>> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
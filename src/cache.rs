@@ -0,0 +1,108 @@
+//! ETag cache for conditional GET requests.
+
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+struct CachedResponse {
+    etag: String,
+    body: Value,
+    stored_at: u64,
+}
+
+#[derive(Default)]
+struct Entries {
+    responses: HashMap<String, CachedResponse>,
+    clock: u64,
+}
+
+/// Bounded map from URL to the last response body and its `ETag`
+///
+/// Entries are always revalidated with `If-None-Match`, so the cache never serves a
+/// body the API has not just confirmed. Clones share the same entries. When full,
+/// the oldest entry is evicted.
+#[derive(Clone)]
+pub(crate) struct EtagCache {
+    capacity: usize,
+    entries: Arc<Mutex<Entries>>,
+}
+
+impl EtagCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Arc::new(Mutex::new(Entries::default())),
+        }
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.capacity > 0
+    }
+
+    /// Returns the stored `ETag` and body for `url`
+    ///
+    /// Both come from one lookup, so the body answering a `304 Not Modified` is the
+    /// one the `ETag` was sent for, even if the entry is evicted meanwhile.
+    pub(crate) fn get(&self, url: &str) -> Option<(String, Value)> {
+        let entries = self.entries.lock().ok()?;
+        entries
+            .responses
+            .get(url)
+            .map(|cached| (cached.etag.clone(), cached.body.clone()))
+    }
+
+    pub(crate) fn store(&self, url: &str, etag: &str, body: &Value) {
+        if !self.is_enabled() {
+            return;
+        }
+        let Ok(mut entries) = self.entries.lock() else {
+            return;
+        };
+
+        if !entries.responses.contains_key(url) && entries.responses.len() >= self.capacity {
+            let oldest = entries
+                .responses
+                .iter()
+                .min_by_key(|(_, cached)| cached.stored_at)
+                .map(|(url, _)| url.clone());
+            if let Some(oldest) = oldest {
+                entries.responses.remove(&oldest);
+            }
+        }
+
+        entries.clock += 1;
+        let stored_at = entries.clock;
+        entries.responses.insert(
+            url.to_string(),
+            CachedResponse {
+                etag: etag.to_string(),
+                body: body.clone(),
+                stored_at,
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_etag_cache_eviction() {
+        let cache = EtagCache::new(2);
+        cache.store("a", "\"1\"", &json!({ "a": 1 }));
+        cache.store("b", "\"2\"", &json!({ "b": 2 }));
+        cache.store("c", "\"3\"", &json!({ "c": 3 }));
+
+        assert_eq!(cache.get("a"), None);
+        assert_eq!(
+            cache.get("c"),
+            Some(("\"3\"".to_string(), json!({ "c": 3 })))
+        );
+
+        let disabled = EtagCache::new(0);
+        disabled.store("a", "\"1\"", &json!(null));
+        assert_eq!(disabled.get("a"), None);
+    }
+}
@@ -3,14 +3,61 @@
 //! This module contains the main [`TapsilatClient`] which handles all HTTP communication
 //! with the Tapsilat API, including authentication, request/response processing, and error handling.
 
-use crate::config::Config;
+use crate::cache::EtagCache;
+use crate::config::{ClientCertificate, Config, ParseWarning, RequestOptions};
 use crate::error::{Result, TapsilatError};
 use crate::modules::{
-    InstallmentModule, OrderModule, OrganizationModule, PaymentModule, SubscriptionModule,
-    WebhookModule,
+    AccountModule, BuyerModule, CardModule, ComplianceModule, CouponModule, EventModule, FxModule,
+    InstallmentModule, InvoiceModule, KeyModule, OnboardingModule, OrderModule, OrganizationModule,
+    PaymentModule, ReportModule, SubscriptionModule, WebhookModule,
 };
+use crate::single_flight::SingleFlight;
+use crate::types::common::{decode_null, unwrap_envelope};
 use crate::types::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use serde_json::Value;
+use std::cell::{Cell, RefCell};
+use std::io::Read;
+use std::time::{Duration, Instant};
+
+thread_local! {
+    static LAST_TRACE_ID: RefCell<Option<String>> = const { RefCell::new(None) };
+    static LAST_STATUS: Cell<Option<u16>> = const { Cell::new(None) };
+    static LAST_HEADERS: RefCell<Vec<(String, String)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Response of a coalesced request, with the metadata followers copy to their thread
+#[derive(Clone)]
+struct SharedResponse {
+    value: Value,
+    trace_id: Option<String>,
+    status: Option<u16>,
+    headers: Vec<(String, String)>,
+}
+
+/// HTTP method of a request sent with [`TapsilatClient::request`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+}
+
+impl Method {
+    /// Returns the method name, e.g. `GET`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Patch => "PATCH",
+            Method::Delete => "DELETE",
+        }
+    }
+}
 
 /// Main client for interacting with the Tapsilat API.
 ///
@@ -20,30 +67,94 @@ use serde_json::Value;
 pub struct TapsilatClient {
     config: Config,
     http_client: ureq::Agent,
+    etag_cache: EtagCache,
+    in_flight: SingleFlight<SharedResponse>,
 }
 
 impl TapsilatClient {
     pub fn new(config: Config) -> Result<Self> {
         config.validate()?;
 
-        let http_client = ureq::Agent::new_with_defaults();
+        let mut agent_config = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(config.timeout)));
+        if !config.compression {
+            // An empty value suppresses the Accept-Encoding header entirely
+            agent_config = agent_config.accept_encoding("");
+        }
+        if let Some(certificate) = &config.client_certificate {
+            agent_config = agent_config.tls_config(
+                ureq::tls::TlsConfig::builder()
+                    .client_cert(Some(Self::client_cert(certificate)?))
+                    .build(),
+            );
+        }
+        let http_client = agent_config.build().new_agent();
+
+        let etag_cache = EtagCache::new(config.etag_cache_capacity);
 
         Ok(Self {
             config,
             http_client,
+            etag_cache,
+            in_flight: SingleFlight::new(),
         })
     }
 
+    /// Parses the PEM certificate chain and key of a mutual TLS client certificate
+    fn client_cert(certificate: &ClientCertificate) -> Result<ureq::tls::ClientCert> {
+        let invalid = |e: ureq::Error| {
+            TapsilatError::ConfigError(format!("Invalid client certificate: {}", e))
+        };
+
+        let mut chain = Vec::new();
+        for item in ureq::tls::parse_pem(&certificate.cert_pem) {
+            if let ureq::tls::PemItem::Certificate(cert) = item.map_err(invalid)? {
+                chain.push(cert);
+            }
+        }
+        if chain.is_empty() {
+            return Err(TapsilatError::ConfigError(
+                "Invalid client certificate: no certificate found in PEM data".to_string(),
+            ));
+        }
+
+        let key = ureq::tls::PrivateKey::from_pem(&certificate.key_pem).map_err(invalid)?;
+        Ok(ureq::tls::ClientCert::new_with_certs(&chain, key))
+    }
+
     pub fn from_api_key(api_key: impl Into<String>) -> Result<Self> {
         let config = Config::new(api_key);
         Self::new(config)
     }
 
+    /// Returns the trace ID from the most recent response received on the current thread.
+    ///
+    /// The ID is read from the header configured with [`Config::with_trace_header`].
+    pub fn last_trace_id(&self) -> Option<String> {
+        LAST_TRACE_ID.with(|last| last.borrow().clone())
+    }
+
+    /// Returns the configuration this client was built with.
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Access to merchant account balances and payout bank accounts
+    pub fn account(&self) -> AccountModule {
+        AccountModule::new(std::sync::Arc::new(self.clone()))
+    }
+
     /// Access to payment operations
     pub fn payments(&self) -> PaymentModule {
         PaymentModule::new(std::sync::Arc::new(self.clone()))
     }
 
+    /// Access to e-invoice and e-archive documents
+    pub fn invoices(&self) -> InvoiceModule {
+        InvoiceModule::new(std::sync::Arc::new(self.clone()))
+    }
+
     /// Access to order operations
     pub fn orders(&self) -> OrderModule {
         OrderModule::new(std::sync::Arc::new(self.clone()))
@@ -54,6 +165,21 @@ impl TapsilatClient {
         OrganizationModule::new(std::sync::Arc::new(self.clone()))
     }
 
+    /// Access to API key rotation and introspection
+    pub fn keys(&self) -> KeyModule {
+        KeyModule::new(std::sync::Arc::new(self.clone()))
+    }
+
+    /// Access to KYC verification and document collection for sub-organizations
+    pub fn onboarding(&self) -> OnboardingModule {
+        OnboardingModule::new(std::sync::Arc::new(self.clone()))
+    }
+
+    /// Access to settlement exchange rates
+    pub fn fx(&self) -> FxModule {
+        FxModule::new(std::sync::Arc::new(self.clone()))
+    }
+
     /// Access to installment operations
     pub fn installments(&self) -> InstallmentModule {
         InstallmentModule::new(std::sync::Arc::new(self.clone()))
@@ -64,37 +190,129 @@ impl TapsilatClient {
         SubscriptionModule::new(std::sync::Arc::new(self.clone()))
     }
 
+    /// Access to order and transaction exports
+    pub fn reports(&self) -> ReportModule {
+        ReportModule::new(std::sync::Arc::new(self.clone()))
+    }
+
+    /// Access to buyer payment history
+    pub fn buyers(&self) -> BuyerModule {
+        BuyerModule::new(std::sync::Arc::new(self.clone()))
+    }
+
+    /// Access to card tokenization
+    pub fn cards(&self) -> CardModule {
+        CardModule::new(std::sync::Arc::new(self.clone()))
+    }
+
+    /// Access to KVKK/GDPR data subject requests
+    pub fn compliance(&self) -> ComplianceModule {
+        ComplianceModule::new(std::sync::Arc::new(self.clone()))
+    }
+
+    /// Access to coupon operations
+    pub fn coupons(&self) -> CouponModule {
+        CouponModule::new(std::sync::Arc::new(self.clone()))
+    }
+
+    /// Access to event polling, for deployments that cannot receive webhooks
+    pub fn events(&self) -> EventModule {
+        EventModule::new(std::sync::Arc::new(self.clone()))
+    }
+
     /// Access to webhook operations
-    pub fn webhooks() -> &'static WebhookModule {
-        &WebhookModule
+    pub fn webhooks(&self) -> WebhookModule {
+        WebhookModule::new(std::sync::Arc::new(self.clone()))
     }
 
     // Direct Operations (Routing to modules for backward/direct compatibility mostly, or implementing essentials)
 
+    pub fn get_balance(&self) -> Result<AccountBalance> {
+        self.account().get_balance()
+    }
+
+    pub fn capabilities(&self) -> Result<Capabilities> {
+        self.account().get_capabilities()
+    }
+
+    pub fn list_bank_accounts(&self) -> Result<Vec<PayoutBankAccount>> {
+        self.account().list_bank_accounts()
+    }
+
+    pub fn add_bank_account(&self, request: AddBankAccountRequest) -> Result<PayoutBankAccount> {
+        self.account().add_bank_account(request)
+    }
+
+    pub fn remove_bank_account(&self, account_id: &str) -> Result<Value> {
+        self.account().remove_bank_account(account_id)
+    }
+
+    pub fn set_default_bank_account(&self, account_id: &str) -> Result<PayoutBankAccount> {
+        self.account().set_default_bank_account(account_id)
+    }
+
     pub fn create_order(&self, request: CreateOrderRequest) -> Result<CreateOrderResponse> {
         self.orders().create(request)
     }
 
+    pub fn find_order_by_external_reference(
+        &self,
+        external_reference_id: &str,
+    ) -> Result<Option<Order>> {
+        self.orders()
+            .find_by_external_reference(external_reference_id)
+    }
+
+    pub fn create_order_detailed(
+        &self,
+        request: CreateOrderRequest,
+    ) -> Result<Detailed<CreateOrderResponse>> {
+        self.orders().create_detailed(request)
+    }
+
     pub fn get_order(&self, reference_id: &str) -> Result<Order> {
         self.orders().get(reference_id)
     }
 
+    pub fn get_order_detailed(&self, reference_id: &str) -> Result<Detailed<Order>> {
+        self.orders().get_detailed(reference_id)
+    }
+
     pub fn get_order_by_conversation_id(&self, conversation_id: &str) -> Result<OrderResponse> {
-        let endpoint = format!("order/conversation/{}", conversation_id);
-        let response = self.make_request::<()>("GET", &endpoint, None)?;
-        serde_json::from_value(response).map_err(|e| {
-            TapsilatError::ConfigError(format!("Failed to parse order response: {}", e))
-        })
+        self.orders().get_by_conversation_id(conversation_id)
+    }
+
+    pub fn create_order_idempotent(
+        &self,
+        request: CreateOrderRequest,
+    ) -> Result<CreateOrderResponse> {
+        self.orders().create_idempotent(request)
     }
 
-    pub fn cancel_order(&self, reference_id: &str) -> Result<Value> {
+    pub fn cancel_order(&self, reference_id: &str) -> Result<CancelResponse> {
         self.orders().cancel(reference_id)
     }
 
+    pub fn cancel_order_with_reason(
+        &self,
+        reference_id: &str,
+        reason: CancelReason,
+    ) -> Result<CancelResponse> {
+        self.orders().cancel_with_reason(reference_id, reason)
+    }
+
     pub fn refund_order(&self, request: RefundOrderRequest) -> Result<Value> {
         self.orders().refund(request)
     }
 
+    pub fn refund_order_detailed(&self, request: RefundOrderRequest) -> Result<Detailed<Value>> {
+        self.orders().refund_detailed(request)
+    }
+
+    pub fn refund_orders(&self, requests: Vec<RefundOrderRequest>) -> Vec<Result<Value>> {
+        self.orders().refund_batch(requests)
+    }
+
     pub fn refund_all_order(&self, reference_id: &str) -> Result<Value> {
         self.orders().refund_all(reference_id)
     }
@@ -146,6 +364,94 @@ impl TapsilatClient {
         self.orders().get_checkout_url(reference_id)
     }
 
+    pub fn extend_checkout(&self, reference_id: &str, duration: Duration) -> Result<Value> {
+        self.orders().extend_checkout(reference_id, duration)
+    }
+
+    pub fn mark_order_paid_external(
+        &self,
+        reference_id: &str,
+        request: ManualPaymentRequest,
+    ) -> Result<Value> {
+        self.orders().mark_paid_external(reference_id, request)
+    }
+
+    pub fn pay_order_with_card(
+        &self,
+        reference_id: &str,
+        request: CardPaymentRequest,
+    ) -> Result<PaymentResponse> {
+        self.orders().pay_with_card(reference_id, request)
+    }
+
+    pub fn pay_order_with_wallet(
+        &self,
+        reference_id: &str,
+        request: WalletPaymentRequest,
+    ) -> Result<PaymentResponse> {
+        self.orders().pay_with_wallet(reference_id, request)
+    }
+
+    pub fn add_order_note(&self, reference_id: &str, text: &str) -> Result<OrderNote> {
+        self.orders().add_note(reference_id, text)
+    }
+
+    pub fn list_order_notes(&self, reference_id: &str) -> Result<Vec<OrderNote>> {
+        self.orders().list_notes(reference_id)
+    }
+
+    pub fn get_order_receipt(&self, reference_id: &str) -> Result<Receipt> {
+        self.orders().get_receipt(reference_id)
+    }
+
+    pub fn download_order_receipt<W: std::io::Write>(
+        &self,
+        reference_id: &str,
+        writer: &mut W,
+    ) -> Result<u64> {
+        self.orders().download_receipt(reference_id, writer)
+    }
+
+    pub fn update_order_shipping(
+        &self,
+        reference_id: &str,
+        update: ShippingUpdate,
+    ) -> Result<Value> {
+        self.orders().update_shipping(reference_id, update)
+    }
+
+    pub fn get_checkout_token(&self, reference_id: &str) -> Result<CheckoutToken> {
+        self.orders().get_checkout_token(reference_id)
+    }
+
+    pub fn get_short_checkout_url(
+        &self,
+        reference_id: &str,
+        domain: Option<&str>,
+    ) -> Result<ShortCheckoutUrl> {
+        self.orders().get_short_checkout_url(reference_id, domain)
+    }
+
+    pub fn wait_for_order_completion(
+        &self,
+        reference_id: &str,
+        options: PollOptions,
+    ) -> Result<OrderStatus> {
+        self.orders().wait_for_completion(reference_id, options)
+    }
+
+    pub fn get_refund(&self, refund_id: &str) -> Result<Refund> {
+        self.orders().get_refund(refund_id)
+    }
+
+    pub fn wait_for_refund(&self, refund_id: &str, options: PollOptions) -> Result<Refund> {
+        self.orders().wait_for_refund(refund_id, options)
+    }
+
+    pub fn get_bank_transfer_info(&self, reference_id: &str) -> Result<BankTransferInfo> {
+        self.orders().get_bank_transfer_info(reference_id)
+    }
+
     pub fn order_manual_callback(
         &self,
         reference_id: &str,
@@ -154,6 +460,13 @@ impl TapsilatClient {
         self.orders().manual_callback(reference_id, conversation_id)
     }
 
+    pub fn order_simulate_callback(
+        &self,
+        request: ManualCallbackRequest,
+    ) -> Result<ManualCallbackResponse> {
+        self.orders().simulate_callback(request)
+    }
+
     pub fn get_system_order_statuses(&self) -> Result<Value> {
         self.make_request::<()>("GET", "system/order-statuses", None)
     }
@@ -206,6 +519,55 @@ impl TapsilatClient {
         self.organization().get_suborganizations(page, per_page)
     }
 
+    pub fn list_api_keys(&self) -> Result<Vec<ApiKey>> {
+        self.keys().list()
+    }
+
+    pub fn create_api_key(&self, request: CreateApiKeyRequest) -> Result<CreatedApiKey> {
+        self.keys().create(request)
+    }
+
+    pub fn revoke_api_key(&self, key_id: &str) -> Result<Value> {
+        self.keys().revoke(key_id)
+    }
+
+    pub fn whoami(&self) -> Result<KeyIdentity> {
+        self.keys().whoami()
+    }
+
+    pub fn get_buyer_payment_history(
+        &self,
+        buyer_id: &str,
+        filter: &PaymentHistoryFilter,
+    ) -> Result<PaginatedResponse<PaymentHistoryEntry>> {
+        self.buyers().payment_history(buyer_id, filter)
+    }
+
+    pub fn request_buyer_erasure(&self, buyer_id: &str) -> Result<ErasureRequest> {
+        self.compliance().request_buyer_erasure(buyer_id)
+    }
+
+    pub fn get_erasure_status(&self, request_id: &str) -> Result<ErasureRequest> {
+        self.compliance().get_erasure_status(request_id)
+    }
+
+    pub fn get_onboarding_status(&self, sub_organization_id: &str) -> Result<OnboardingStatus> {
+        self.onboarding().get_status(sub_organization_id)
+    }
+
+    pub fn upload_onboarding_document(
+        &self,
+        sub_organization_id: &str,
+        upload: DocumentUpload,
+    ) -> Result<UploadedDocument> {
+        self.onboarding()
+            .upload_document(sub_organization_id, upload)
+    }
+
+    pub fn submit_onboarding(&self, sub_organization_id: &str) -> Result<OnboardingStatus> {
+        self.onboarding().submit(sub_organization_id)
+    }
+
     pub fn create_organization_user(&self, request: OrgCreateUserReq) -> Result<Value> {
         self.organization().create_user(request)
     }
@@ -256,7 +618,11 @@ impl TapsilatClient {
         self.orders().get_term(term_reference_id)
     }
 
-    pub fn order_terminate(&self, reference_id: &str) -> Result<Value> {
+    pub fn list_order_terms(&self, order_reference_id: &str) -> Result<Vec<PaymentTerm>> {
+        self.orders().list_terms(order_reference_id)
+    }
+
+    pub fn order_terminate(&self, reference_id: &str) -> Result<TerminateResponse> {
         self.orders().terminate(reference_id)
     }
 
@@ -264,7 +630,7 @@ impl TapsilatClient {
         &self,
         term_reference_id: &str,
         reason: Option<String>,
-    ) -> Result<Value> {
+    ) -> Result<TerminateResponse> {
         self.orders().terminate_term(term_reference_id, reason)
     }
 
@@ -296,6 +662,13 @@ impl TapsilatClient {
         self.subscriptions().get(request)
     }
 
+    pub fn get_subscription_detailed(
+        &self,
+        request: SubscriptionGetRequest,
+    ) -> Result<Detailed<SubscriptionDetail>> {
+        self.subscriptions().get_detailed(request)
+    }
+
     pub fn cancel_subscription(&self, request: SubscriptionCancelRequest) -> Result<Value> {
         self.subscriptions().cancel(request)
     }
@@ -307,10 +680,53 @@ impl TapsilatClient {
         self.subscriptions().create(request)
     }
 
+    pub fn create_subscriptions(
+        &self,
+        requests: Vec<SubscriptionCreateRequest>,
+    ) -> SubscriptionBatchReport {
+        self.subscriptions().create_batch(requests)
+    }
+
     pub fn list_subscriptions(&self, page: u32, per_page: u32) -> Result<Value> {
         self.subscriptions().list(page, per_page)
     }
 
+    pub fn retry_subscription_payment(
+        &self,
+        reference_id: &str,
+    ) -> Result<SubscriptionRetryPaymentResponse> {
+        self.subscriptions().retry_payment(reference_id)
+    }
+
+    pub fn get_subscription_failed_orders(
+        &self,
+        reference_id: &str,
+    ) -> Result<Vec<SubscriptionOrder>> {
+        self.subscriptions().get_failed_orders(reference_id)
+    }
+
+    pub fn preview_upcoming_subscription_invoice(
+        &self,
+        reference_id: &str,
+    ) -> Result<UpcomingInvoice> {
+        self.subscriptions().preview_upcoming(reference_id)
+    }
+
+    pub fn update_subscription_card(
+        &self,
+        reference_id: &str,
+        card_id: &str,
+    ) -> Result<SubscriptionUpdateCardResponse> {
+        self.subscriptions().update_card(reference_id, card_id)
+    }
+
+    pub fn subscription_card_update_url(
+        &self,
+        reference_id: &str,
+    ) -> Result<SubscriptionRedirectResponse> {
+        self.subscriptions().card_update_url(reference_id)
+    }
+
     pub fn redirect_subscription(
         &self,
         request: SubscriptionRedirectRequest,
@@ -318,12 +734,248 @@ impl TapsilatClient {
         self.subscriptions().redirect(request)
     }
 
+    pub fn tokenize_card(&self, card: CardDetails) -> Result<CardToken> {
+        self.cards().tokenize(card)
+    }
+
+    /// Sends a request to any endpoint with caller-defined request and response types.
+    ///
+    /// An escape hatch for endpoints the SDK does not cover yet. `path` is relative to
+    /// the base URL and may include a query string; the body is ignored for GET. The
+    /// request goes through the same authentication, retries, read-only and dry-run
+    /// handling as the built-in calls, and `{success, data, message}` envelopes are
+    /// unwrapped before decoding into `Resp`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use serde::{Deserialize, Serialize};
+    /// use tapsilat::{Method, TapsilatClient};
+    ///
+    /// #[derive(Serialize)]
+    /// struct PayoutRequest {
+    ///     amount: f64,
+    /// }
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Payout {
+    ///     id: String,
+    /// }
+    ///
+    /// # fn example() -> tapsilat::Result<()> {
+    /// let client = TapsilatClient::from_api_key("your-api-key")?;
+    /// let payout: Payout = client.request(
+    ///     Method::Post,
+    ///     "payouts",
+    ///     Some(&PayoutRequest { amount: 250.0 }),
+    /// )?;
+    /// println!("Payout {}", payout.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn request<Req, Resp>(&self, method: Method, path: &str, body: Option<&Req>) -> Result<Resp>
+    where
+        Req: Serialize,
+        Resp: DeserializeOwned,
+    {
+        self.request_with(method, path, body, &RequestOptions::default())
+    }
+
+    /// Like [`request`](Self::request), with per-request options.
+    pub fn request_with<Req, Resp>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&Req>,
+        options: &RequestOptions,
+    ) -> Result<Resp>
+    where
+        Req: Serialize,
+        Resp: DeserializeOwned,
+    {
+        let context = format!("{} {} response", method.as_str(), endpoint_route(path));
+        let response = self.make_request_with(method.as_str(), path, body, options)?;
+        self.decode_body(response, &context, |_: &Resp| None)
+    }
+
     pub(crate) fn make_request<T>(
         &self,
         method: &str,
         endpoint: &str,
         body: Option<&T>,
     ) -> Result<serde_json::Value>
+    where
+        T: serde::Serialize,
+    {
+        self.make_request_with(method, endpoint, body, &RequestOptions::default())
+    }
+
+    pub(crate) fn make_request_with<T>(
+        &self,
+        method: &str,
+        endpoint: &str,
+        body: Option<&T>,
+        options: &RequestOptions,
+    ) -> Result<serde_json::Value>
+    where
+        T: serde::Serialize,
+    {
+        options.validate()?;
+
+        if self.config.read_only && !method.eq_ignore_ascii_case("GET") {
+            return Err(TapsilatError::ReadOnly {
+                method: method.to_uppercase(),
+                endpoint: endpoint.to_string(),
+            });
+        }
+
+        if (self.config.dry_run || options.dry_run) && !method.eq_ignore_ascii_case("GET") {
+            return self.dry_run(method, endpoint, body);
+        }
+
+        // A follower must not wait on a leader with a longer timeout than its own
+        let coalesce = self.config.coalesce_requests
+            && method.eq_ignore_ascii_case("GET")
+            && options.headers.is_empty()
+            && options.trace_id.is_none()
+            && options.timeout.is_none();
+        if !coalesce {
+            return self.execute(method, endpoint, body, options);
+        }
+
+        let shared = self.in_flight.run(endpoint, || {
+            let value = self.execute(method, endpoint, body, options)?;
+            Ok::<_, TapsilatError>(SharedResponse {
+                value,
+                trace_id: self.last_trace_id(),
+                status: LAST_STATUS.with(Cell::get),
+                headers: LAST_HEADERS.with(|last| last.borrow().clone()),
+            })
+        })?;
+        LAST_TRACE_ID.with(|last| *last.borrow_mut() = shared.trace_id);
+        LAST_STATUS.with(|last| last.set(shared.status));
+        LAST_HEADERS.with(|last| *last.borrow_mut() = shared.headers);
+        Ok(shared.value)
+    }
+
+    /// Serializes a request body for the debug log with card data and tokens redacted
+    fn loggable_body<T: serde::Serialize>(body: &T) -> Result<String> {
+        let value = serde_json::to_value(body)?;
+        Ok(serde_json::to_string_pretty(&crate::masking::redact_json(
+            &value,
+        ))?)
+    }
+
+    /// Redacts a JSON response body for the debug log; other bodies are logged as is
+    fn loggable_text(body_text: &str) -> String {
+        match serde_json::from_str::<Value>(body_text) {
+            Ok(value) => crate::masking::redact_json(&value).to_string(),
+            Err(_) => body_text.to_string(),
+        }
+    }
+
+    /// Logs a mutating request instead of sending it and returns an empty object
+    fn dry_run<T>(&self, method: &str, endpoint: &str, body: Option<&T>) -> Result<Value>
+    where
+        T: serde::Serialize,
+    {
+        let url = format!(
+            "{}/{}",
+            self.config.base_url.trim_end_matches('/'),
+            endpoint.trim_start_matches('/')
+        );
+        let payload = match body {
+            Some(body) => Self::loggable_body(body)?,
+            None => "(empty)".to_string(),
+        };
+
+        eprintln!("\n🧪 Dry Run (not sent):");
+        eprintln!("   Method: {}", method.to_uppercase());
+        eprintln!("   URL: {}", url);
+        eprintln!("   Request Body:\n{}", payload);
+
+        LAST_TRACE_ID.with(|last| *last.borrow_mut() = None);
+        LAST_STATUS.with(|last| last.set(None));
+        LAST_HEADERS.with(|last| last.borrow_mut().clear());
+        Ok(Value::Object(serde_json::Map::new()))
+    }
+
+    fn execute<T>(
+        &self,
+        method: &str,
+        endpoint: &str,
+        body: Option<&T>,
+        options: &RequestOptions,
+    ) -> Result<serde_json::Value>
+    where
+        T: serde::Serialize,
+    {
+        LAST_STATUS.with(|last| last.set(None));
+        LAST_HEADERS.with(|last| last.borrow_mut().clear());
+        let started = Instant::now();
+
+        #[cfg(feature = "otel")]
+        let result = crate::telemetry::instrument(method, endpoint, || {
+            self.send_with_retries(method, endpoint, body, options)
+        });
+        #[cfg(not(feature = "otel"))]
+        let result = self.send_with_retries(method, endpoint, body, options);
+
+        if let Some(sink) = &self.config.metrics_sink {
+            sink.on_request(
+                &endpoint_route(endpoint),
+                &method.to_uppercase(),
+                LAST_STATUS.with(Cell::get),
+                started.elapsed(),
+            );
+        }
+        result
+    }
+
+    /// Sends a request, retrying failed GETs up to [`Config::max_retries`] times
+    fn send_with_retries<T>(
+        &self,
+        method: &str,
+        endpoint: &str,
+        body: Option<&T>,
+        options: &RequestOptions,
+    ) -> Result<serde_json::Value>
+    where
+        T: serde::Serialize,
+    {
+        let retryable_method = method.eq_ignore_ascii_case("GET");
+        let mut retries = 0;
+        loop {
+            let result = self.send_request(method, endpoint, body, options);
+            let retryable = match &result {
+                Err(TapsilatError::Timeout { .. } | TapsilatError::ConnectionFailed { .. }) => true,
+                Err(
+                    TapsilatError::ApiError { status_code, .. }
+                    | TapsilatError::UnexpectedContentType {
+                        status: status_code,
+                        ..
+                    },
+                ) => *status_code == 429 || *status_code >= 500,
+                _ => false,
+            };
+            if !retryable || !retryable_method || retries >= self.config.max_retries {
+                #[cfg(feature = "otel")]
+                crate::telemetry::record_retries(retries);
+                return result;
+            }
+
+            std::thread::sleep(Duration::from_millis(500 << retries.min(6)));
+            retries += 1;
+        }
+    }
+
+    fn send_request<T>(
+        &self,
+        method: &str,
+        endpoint: &str,
+        body: Option<&T>,
+        options: &RequestOptions,
+    ) -> Result<serde_json::Value>
     where
         T: serde::Serialize,
     {
@@ -337,124 +989,45 @@ impl TapsilatClient {
         eprintln!("\n🚀 HTTP Request Debug:");
         eprintln!("   Method: {}", method);
         eprintln!("   URL: {}", url);
-        let mask_key = if self.config.api_key.len() > 10 {
-            format!(
-                "{}...{}",
-                &self.config.api_key[..4],
-                &self.config.api_key[self.config.api_key.len() - 4..]
-            )
-        } else {
-            "***".to_string()
-        };
-
-        eprintln!("   Authorization: Bearer {}", mask_key);
+        eprintln!("   Authorization: Bearer {}", self.config.api_key);
 
         if let Some(body) = &body {
-            let body_json = serde_json::to_string_pretty(body).unwrap_or_default();
+            let body_json = Self::loggable_body(body).unwrap_or_default();
             eprintln!("   Request Body:\n{}", body_json);
         } else {
             eprintln!("   Request Body: (empty)");
         }
 
-        let mut response = match method.to_uppercase().as_str() {
-            "GET" => self
-                .http_client
-                .get(&url)
-                .header("Authorization", format!("Bearer {}", self.config.api_key))
-                .header("Content-Type", "application/json")
-                .header(
-                    "User-Agent",
-                    format!("tapsilat-rust/{}", env!("CARGO_PKG_VERSION")),
-                )
-                .call()?,
-            "POST" => match body {
-                Some(data) => self
-                    .http_client
-                    .post(&url)
-                    .header("Authorization", format!("Bearer {}", self.config.api_key))
-                    .header("Content-Type", "application/json")
-                    .header(
-                        "User-Agent",
-                        format!("tapsilat-rust/{}", env!("CARGO_PKG_VERSION")),
-                    )
-                    .send_json(data)?,
-                None => self
-                    .http_client
-                    .post(&url)
-                    .header("Authorization", format!("Bearer {}", self.config.api_key))
-                    .header("Content-Type", "application/json")
-                    .header(
-                        "User-Agent",
-                        format!("tapsilat-rust/{}", env!("CARGO_PKG_VERSION")),
-                    )
-                    .send_empty()?,
-            },
-            "PUT" => match body {
-                Some(data) => self
-                    .http_client
-                    .put(&url)
-                    .header("Authorization", format!("Bearer {}", self.config.api_key))
-                    .header("Content-Type", "application/json")
-                    .header(
-                        "User-Agent",
-                        format!("tapsilat-rust/{}", env!("CARGO_PKG_VERSION")),
-                    )
-                    .send_json(data)?,
-                None => self
-                    .http_client
-                    .put(&url)
-                    .header("Authorization", format!("Bearer {}", self.config.api_key))
-                    .header("Content-Type", "application/json")
-                    .header(
-                        "User-Agent",
-                        format!("tapsilat-rust/{}", env!("CARGO_PKG_VERSION")),
-                    )
-                    .send_empty()?,
-            },
-            "PATCH" => match body {
-                Some(data) => self
-                    .http_client
-                    .patch(&url)
-                    .header("Authorization", format!("Bearer {}", self.config.api_key))
-                    .header("Content-Type", "application/json")
-                    .header(
-                        "User-Agent",
-                        format!("tapsilat-rust/{}", env!("CARGO_PKG_VERSION")),
-                    )
-                    .send_json(data)?,
-                None => self
-                    .http_client
-                    .patch(&url)
-                    .header("Authorization", format!("Bearer {}", self.config.api_key))
-                    .header("Content-Type", "application/json")
-                    .header(
-                        "User-Agent",
-                        format!("tapsilat-rust/{}", env!("CARGO_PKG_VERSION")),
-                    )
-                    .send_empty()?,
-            },
+        // Revalidated GETs keep the cached body, answering a 304 even if it is evicted
+        let mut cached = None;
+        let result = match method.to_uppercase().as_str() {
+            "GET" => {
+                let mut request = self.apply_headers(self.http_client.get(&url), options);
+                cached = self.etag_cache.get(&url);
+                if let Some((etag, _)) = &cached {
+                    request = request.header("If-None-Match", etag);
+                }
+                request.call()
+            }
+            "POST" => Self::send(
+                self.apply_headers(self.http_client.post(&url), options),
+                body,
+            ),
+            "PUT" => Self::send(
+                self.apply_headers(self.http_client.put(&url), options),
+                body,
+            ),
+            "PATCH" => Self::send(
+                self.apply_headers(self.http_client.patch(&url), options),
+                body,
+            ),
             "DELETE" => match body {
                 Some(data) => self
-                    .http_client
-                    .delete(&url)
-                    .force_send_body()
-                    .header("Authorization", format!("Bearer {}", self.config.api_key))
-                    .header("Content-Type", "application/json")
-                    .header(
-                        "User-Agent",
-                        format!("tapsilat-rust/{}", env!("CARGO_PKG_VERSION")),
-                    )
-                    .send_json(data)?,
+                    .apply_headers(self.http_client.delete(&url).force_send_body(), options)
+                    .send_json(data),
                 None => self
-                    .http_client
-                    .delete(&url)
-                    .header("Authorization", format!("Bearer {}", self.config.api_key))
-                    .header("Content-Type", "application/json")
-                    .header(
-                        "User-Agent",
-                        format!("tapsilat-rust/{}", env!("CARGO_PKG_VERSION")),
-                    )
-                    .call()?,
+                    .apply_headers(self.http_client.delete(&url), options)
+                    .call(),
             },
             _ => {
                 return Err(TapsilatError::ConfigError(format!(
@@ -463,35 +1036,73 @@ impl TapsilatClient {
                 )))
             }
         };
+        let mut response = result.map_err(|e| TapsilatError::from_transport(e, &url))?;
 
         let status_code = response.status().as_u16();
-        let body_text = response.body_mut().read_to_string().map_err(|e| {
-            TapsilatError::ConfigError(format!("Failed to read response body: {}", e))
-        })?;
+        let trace_id = response
+            .headers()
+            .get(self.config.trace_header.as_str())
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| {
+                value
+                    .to_str()
+                    .ok()
+                    .map(|value| (name.as_str().to_string(), value.to_string()))
+            })
+            .collect();
+        LAST_TRACE_ID.with(|last| *last.borrow_mut() = trace_id.clone());
+        LAST_STATUS.with(|last| last.set(Some(status_code)));
+        LAST_HEADERS.with(|last| *last.borrow_mut() = headers);
+        #[cfg(feature = "otel")]
+        crate::telemetry::record_status(status_code);
+
+        if status_code == 304 {
+            return cached.map(|(_, body)| body).ok_or_else(|| {
+                TapsilatError::InvalidResponse(format!(
+                    "304 Not Modified without a cached response for {}",
+                    url
+                ))
+            });
+        }
+        let etag = response
+            .headers()
+            .get("ETag")
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let content_type = response
+            .headers()
+            .get("Content-Type")
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+
+        let body_text = self.read_body(&mut response)?;
+        if let Some(error) =
+            TapsilatError::unexpected_content_type(status_code, content_type.as_deref(), &body_text)
+        {
+            return Err(error);
+        }
 
         if status_code >= 400 {
             // Debug logging for errors
             eprintln!("\n❌ HTTP Error Response Debug:");
             eprintln!("   Status: {}", status_code);
-            eprintln!("   Error Body:\n{}", body_text);
-
-            let error_body: serde_json::Value =
-                serde_json::from_str(&body_text).unwrap_or_default();
-            let message = error_body["message"]
-                .as_str()
-                .unwrap_or("Unknown API error")
-                .to_string();
+            eprintln!("   Error Body:\n{}", Self::loggable_text(&body_text));
 
-            return Err(TapsilatError::ApiError {
+            return Err(TapsilatError::from_status(
                 status_code,
-                message,
-            });
+                &body_text,
+                trace_id,
+            ));
         }
 
         // Debug logging
         eprintln!("\n📥 HTTP Response Debug:");
         eprintln!("   Status: {}", status_code);
-        eprintln!("   Response Body:\n{}", body_text);
+        eprintln!("   Response Body:\n{}", Self::loggable_text(&body_text));
 
         if body_text.trim().is_empty() {
             return Ok(serde_json::Value::Null);
@@ -504,6 +1115,366 @@ impl TapsilatClient {
             ))
         })?;
 
+        if let Some(etag) = etag.filter(|_| method.eq_ignore_ascii_case("GET")) {
+            self.etag_cache.store(&url, &etag, &json_response);
+        }
+
         Ok(json_response)
     }
+
+    /// Streams a binary response body, such as a PDF document, to `writer`
+    ///
+    /// The body is copied as it arrives rather than buffered, so the response size limit
+    /// does not apply. Returns the number of bytes written.
+    pub(crate) fn download<W: std::io::Write>(
+        &self,
+        endpoint: &str,
+        accept: &str,
+        writer: &mut W,
+    ) -> Result<u64> {
+        let url = format!(
+            "{}/{}",
+            self.config.base_url.trim_end_matches('/'),
+            endpoint.trim_start_matches('/')
+        );
+
+        let mut response = self
+            .apply_headers(self.http_client.get(&url), &RequestOptions::default())
+            .header("Accept", accept)
+            .call()
+            .map_err(|e| TapsilatError::from_transport(e, &url))?;
+
+        let status_code = response.status().as_u16();
+        let trace_id = response
+            .headers()
+            .get(self.config.trace_header.as_str())
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        LAST_TRACE_ID.with(|last| *last.borrow_mut() = trace_id.clone());
+        LAST_STATUS.with(|last| last.set(Some(status_code)));
+
+        if status_code >= 400 {
+            let content_type = response
+                .headers()
+                .get("Content-Type")
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string);
+            let body_text = self.read_body(&mut response)?;
+            return Err(TapsilatError::unexpected_content_type(
+                status_code,
+                content_type.as_deref(),
+                &body_text,
+            )
+            .unwrap_or_else(|| TapsilatError::from_status(status_code, &body_text, trace_id)));
+        }
+
+        let written = std::io::copy(&mut response.body_mut().as_reader(), writer)?;
+        Ok(written)
+    }
+
+    /// Decodes a response body into `T`, whether or not it is wrapped in a
+    /// `{success, data, message}` envelope
+    ///
+    /// See [`unwrap_envelope`] for how envelopes are handled. Empty bodies and envelopes
+    /// with `data: null` decode only into types that accept `null`, such as `Option<_>`
+    /// or `serde_json::Value`.
+    pub(crate) fn decode_response<T>(&self, value: Value, context: &str) -> Result<T>
+    where
+        T: DeserializeOwned + Serialize,
+    {
+        self.decode_body(value, context, |typed: &T| serde_json::to_value(typed).ok())
+    }
+
+    /// Decodes an enveloped or bare response body into `T`
+    ///
+    /// The envelope's `data` is tried first; if it does not parse and the body has
+    /// fields besides the envelope's own, the whole body is parsed as a bare object
+    /// before giving up. `reserialize` turns the typed value back
+    /// into JSON for unknown-field detection and may return `None` to skip it.
+    fn decode_body<T>(
+        &self,
+        value: Value,
+        context: &str,
+        reserialize: impl Fn(&T) -> Option<Value>,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let enveloped = matches!(value.get("success"), Some(Value::Bool(_)));
+        let bare = value
+            .as_object()
+            .filter(|fields| {
+                enveloped
+                    && fields
+                        .keys()
+                        .any(|key| !ENVELOPE_FIELDS.contains(&key.as_str()))
+            })
+            .map(|_| value.clone());
+        let data = match unwrap_envelope(value, context, self.last_trace_id())? {
+            Value::Null => return decode_null(context),
+            data => data,
+        };
+
+        let (data, enveloped, typed) = match (serde_json::from_value::<T>(data.clone()), bare) {
+            (Ok(typed), _) => (data, enveloped, typed),
+            (Err(envelope_error), Some(bare)) => match serde_json::from_value::<T>(bare.clone()) {
+                Ok(typed) => (bare, false, typed),
+                Err(_) => {
+                    return Err(self.parse_failure(&data, enveloped, context, envelope_error))
+                }
+            },
+            (Err(error), None) => return Err(self.parse_failure(&data, enveloped, context, error)),
+        };
+
+        let config = &self.config;
+        if !config.strict_parsing && !config.log_unknown_fields && config.parse_warnings.is_none() {
+            return Ok(typed);
+        }
+        let Some(known) = reserialize(&typed) else {
+            return Ok(typed);
+        };
+
+        let mut unknown = Vec::new();
+        collect_unknown_fields(&data, &known, "", &mut unknown);
+        if unknown.is_empty() {
+            return Ok(typed);
+        }
+
+        if config.log_unknown_fields {
+            eprintln!(
+                "\n⚠️  Unknown fields in {}: {}",
+                context,
+                unknown.join(", ")
+            );
+        }
+        if config.strict_parsing {
+            let message = format!("Unknown fields in {}: {}", context, unknown.join(", "));
+            self.warn_parse(&data, enveloped, context, unknown, Some(message.clone()));
+            return Err(TapsilatError::InvalidResponse(message));
+        }
+        self.warn_parse(&data, enveloped, context, unknown, None);
+        Ok(typed)
+    }
+
+    /// Reports a body that failed to parse and builds the matching error
+    fn parse_failure(
+        &self,
+        data: &Value,
+        enveloped: bool,
+        context: &str,
+        error: serde_json::Error,
+    ) -> TapsilatError {
+        let message = format!("Failed to parse {}: {}", context, error);
+        self.warn_parse(
+            data,
+            enveloped,
+            context,
+            Vec::new(),
+            Some(error.to_string()),
+        );
+        TapsilatError::InvalidResponse(message)
+    }
+
+    fn warn_parse(
+        &self,
+        data: &Value,
+        enveloped: bool,
+        context: &str,
+        unknown_fields: Vec<String>,
+        error: Option<String>,
+    ) {
+        let Some(sink) = &self.config.parse_warnings else {
+            return;
+        };
+        let present_fields = data
+            .as_object()
+            .map(|fields| fields.keys().cloned().collect())
+            .unwrap_or_default();
+        sink.on_warning(&ParseWarning {
+            context: context.to_string(),
+            enveloped,
+            present_fields,
+            unknown_fields,
+            error,
+        });
+    }
+
+    /// Decodes a response like [`decode_response`](Self::decode_response), keeping the raw
+    /// body and the status and headers of the request that produced it
+    ///
+    /// Must be called right after the request, before another one is made on this thread.
+    pub(crate) fn decode_detailed<T>(&self, raw: Value, context: &str) -> Result<Detailed<T>>
+    where
+        T: DeserializeOwned + Serialize,
+    {
+        let status = LAST_STATUS.with(Cell::get);
+        let headers = LAST_HEADERS.with(|last| last.borrow().clone());
+        let value = self.decode_response(raw.clone(), context)?;
+        Ok(Detailed {
+            value,
+            raw,
+            status,
+            headers,
+        })
+    }
+
+    /// Deserializes an already unwrapped response value, such as a list of rows, into
+    /// `T`, honouring the configured parsing mode
+    pub(crate) fn parse_typed<T>(&self, value: Value, context: &str) -> Result<T>
+    where
+        T: DeserializeOwned + Serialize,
+    {
+        self.decode_response(value, context)
+    }
+
+    /// Adds authentication, content type, user agent, configured default headers
+    /// and the per-request overrides from `options`
+    fn apply_headers<B>(
+        &self,
+        request: ureq::RequestBuilder<B>,
+        options: &RequestOptions,
+    ) -> ureq::RequestBuilder<B> {
+        let mut request = request
+            .header(
+                "Authorization",
+                format!("Bearer {}", self.config.api_key.expose_secret()),
+            )
+            .header("Content-Type", "application/json")
+            .header("User-Agent", self.config.user_agent());
+
+        for (name, value) in self.config.default_headers.iter().chain(&options.headers) {
+            request = request.header(name.as_str(), value.as_str());
+        }
+
+        let trace_id = options.trace_id.clone().or_else(|| {
+            self.config
+                .trace_id_provider
+                .as_ref()
+                .and_then(|provider| provider.trace_id())
+        });
+        if let Some(trace_id) = trace_id {
+            request = request.header(self.config.trace_header.as_str(), trace_id);
+        }
+
+        if let Some(key) = &options.idempotency_key {
+            request = request.header("Idempotency-Key", key.as_str());
+        }
+
+        #[cfg(feature = "otel")]
+        for (name, value) in crate::telemetry::propagation_headers() {
+            request = request.header(name, value);
+        }
+
+        if let Some(timeout) = options.timeout {
+            request = request
+                .config()
+                .timeout_global(Some(Duration::from_secs(timeout)))
+                .build();
+        }
+
+        request
+    }
+
+    /// Reads a response body incrementally, failing once it exceeds the configured size limit
+    fn read_body(&self, response: &mut ureq::http::Response<ureq::Body>) -> Result<String> {
+        let limit = self.config.max_response_size;
+        let too_large = || {
+            TapsilatError::InvalidResponse(format!(
+                "Response body exceeds the {} byte limit",
+                limit
+            ))
+        };
+
+        if response
+            .body()
+            .content_length()
+            .is_some_and(|length| length > limit)
+        {
+            return Err(too_large());
+        }
+
+        let mut body = Vec::new();
+        response
+            .body_mut()
+            .as_reader()
+            .take(limit.saturating_add(1))
+            .read_to_end(&mut body)
+            .map_err(|e| {
+                TapsilatError::ConfigError(format!("Failed to read response body: {}", e))
+            })?;
+        if body.len() as u64 > limit {
+            return Err(too_large());
+        }
+
+        String::from_utf8(body).map_err(|e| {
+            TapsilatError::InvalidResponse(format!("Response body is not valid UTF-8: {}", e))
+        })
+    }
+
+    /// Sends a request that may carry a JSON body
+    fn send<T>(
+        request: ureq::RequestBuilder<ureq::typestate::WithBody>,
+        body: Option<&T>,
+    ) -> std::result::Result<ureq::http::Response<ureq::Body>, ureq::Error>
+    where
+        T: serde::Serialize,
+    {
+        match body {
+            Some(data) => request.send_json(data),
+            None => request.send_empty(),
+        }
+    }
+}
+
+/// Fields of the `{success, data, message}` response envelope
+const ENVELOPE_FIELDS: &[&str] = &["success", "data", "message", "errors"];
+
+/// Collects the paths of fields present in `raw` but absent from `typed`
+fn collect_unknown_fields(raw: &Value, typed: &Value, path: &str, unknown: &mut Vec<String>) {
+    match (raw, typed) {
+        (Value::Object(raw_map), Value::Object(typed_map)) => {
+            for (key, raw_value) in raw_map {
+                let field_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                match typed_map.get(key) {
+                    Some(typed_value) => {
+                        collect_unknown_fields(raw_value, typed_value, &field_path, unknown)
+                    }
+                    None => unknown.push(field_path),
+                }
+            }
+        }
+        (Value::Array(raw_items), Value::Array(typed_items)) => {
+            for (index, (raw_item, typed_item)) in raw_items.iter().zip(typed_items).enumerate() {
+                collect_unknown_fields(
+                    raw_item,
+                    typed_item,
+                    &format!("{}[{}]", path, index),
+                    unknown,
+                );
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Replaces path segments containing digits, such as reference IDs, with `{id}` so
+/// endpoint labels stay low-cardinality (`order/ord_123/status` becomes `order/{id}/status`)
+pub(crate) fn endpoint_route(endpoint: &str) -> String {
+    let path = endpoint.split('?').next().unwrap_or(endpoint);
+    path.trim_matches('/')
+        .split('/')
+        .enumerate()
+        .map(|(index, segment)| {
+            if index > 0 && segment.chars().any(|c| c.is_ascii_digit()) {
+                "{id}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
 }
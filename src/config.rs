@@ -4,6 +4,28 @@
 //! and request timeouts.
 
 use crate::error::{Result, TapsilatError};
+use crate::modules::validators::PhoneValidation;
+use crate::secret::SecretString;
+use crate::tax::PricingMode;
+use crate::types::{Currency, Locale};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Headers set by the SDK itself, which default and per-request headers may not override.
+const RESERVED_HEADERS: &[&str] = &["Authorization", "Content-Type", "User-Agent"];
+
+fn is_reserved_header(name: &str) -> bool {
+    RESERVED_HEADERS
+        .iter()
+        .any(|reserved| reserved.eq_ignore_ascii_case(name.trim()))
+}
 
 /// Configuration for the Tapsilat SDK client.
 ///
@@ -21,12 +43,58 @@ use crate::error::{Result, TapsilatError};
 /// ```
 #[derive(Debug, Clone)]
 pub struct Config {
-    /// API key for authenticating with the Tapsilat API.
-    pub api_key: String,
+    /// API key for authenticating with the Tapsilat API, masked in `Debug` output.
+    pub api_key: SecretString,
     /// Base URL for the Tapsilat API (default: <https://panel.tapsilat.dev/api/v1>).
     pub base_url: String,
     /// Request timeout in seconds (default: 30).
     pub timeout: u64,
+    /// Number of times a failed GET request is retried (default: 0).
+    pub max_retries: u32,
+    /// Whether every request other than GET is refused locally (default: false).
+    pub read_only: bool,
+    /// Whether requests other than GET are logged to stderr instead of sent (default: false).
+    pub dry_run: bool,
+    /// Whether gzip/brotli compressed responses are requested (default: true).
+    pub compression: bool,
+    /// Extra headers sent with every request (default: none).
+    pub default_headers: Vec<(String, String)>,
+    /// Suffix appended to the `User-Agent` header, e.g. `my-shop/1.2` (default: none).
+    pub user_agent_suffix: Option<String>,
+    /// Header used to propagate and read back trace IDs (default: `X-Request-Id`).
+    pub trace_header: String,
+    /// Source of the trace ID attached to each request (default: none).
+    pub trace_id_provider: Option<Arc<dyn TraceIdProvider>>,
+    /// Whether responses containing fields unknown to the SDK are rejected (default: false).
+    pub strict_parsing: bool,
+    /// Whether unknown response fields are logged to stderr (default: false).
+    pub log_unknown_fields: bool,
+    /// Receiver of response parsing warnings (default: none).
+    pub parse_warnings: Option<Arc<dyn ParseWarnings>>,
+    /// Whether refunds are checked against the order's refundable balance before sending (default: false).
+    pub refund_guard: bool,
+    /// Pricing mode used to fill in a missing `tax_amount` from basket VAT rates (default: none).
+    pub auto_tax: Option<PricingMode>,
+    /// Currency used when a request leaves it empty, and the only one accepted (default: none).
+    pub default_currency: Option<Currency>,
+    /// Checkout locale used when an order request leaves it empty (default: none).
+    pub default_locale: Option<Locale>,
+    /// Whether buyer identity numbers are checked by citizenship when creating orders (default: false).
+    pub identity_validation: bool,
+    /// How buyer phone numbers are checked when creating orders; none leaves it to the API (default: none).
+    pub phone_validation: Option<PhoneValidation>,
+    /// Whether callback and redirect URLs in requests must use https (default: false).
+    pub require_https_urls: bool,
+    /// Largest response body accepted, in bytes after decompression (default: 10 MB).
+    pub max_response_size: u64,
+    /// Client certificate presented for mutual TLS (default: none).
+    pub client_certificate: Option<ClientCertificate>,
+    /// Number of GET responses kept for `ETag` revalidation; 0 disables the cache (default: 0).
+    pub etag_cache_capacity: usize,
+    /// Whether concurrent identical GET requests share a single HTTP call (default: false).
+    pub coalesce_requests: bool,
+    /// Receiver of per-request metrics (default: none).
+    pub metrics_sink: Option<Arc<dyn MetricsSink>>,
 }
 
 impl Config {
@@ -47,10 +115,125 @@ impl Config {
     /// ```
     pub fn new(api_key: impl Into<String>) -> Self {
         Self {
-            api_key: api_key.into(),
+            api_key: SecretString::new(api_key),
             base_url: "https://panel.tapsilat.dev/api/v1".to_string(),
             timeout: 30,
+            max_retries: 0,
+            read_only: false,
+            dry_run: false,
+            compression: true,
+            default_headers: Vec::new(),
+            user_agent_suffix: None,
+            trace_header: "X-Request-Id".to_string(),
+            trace_id_provider: None,
+            strict_parsing: false,
+            log_unknown_fields: false,
+            parse_warnings: None,
+            refund_guard: false,
+            auto_tax: None,
+            default_currency: None,
+            default_locale: None,
+            identity_validation: false,
+            phone_validation: None,
+            require_https_urls: false,
+            max_response_size: 10 * 1024 * 1024,
+            client_certificate: None,
+            etag_cache_capacity: 0,
+            coalesce_requests: false,
+            metrics_sink: None,
+        }
+    }
+
+    /// Loads a configuration profile from a TOML file.
+    ///
+    /// Each top-level table of the file is a named profile. Keys missing from the
+    /// selected profile are taken from the `default` table, if present. The profile is
+    /// chosen by the `TAPSILAT_PROFILE` environment variable and falls back to
+    /// `default`. See [`from_file_profile`](Self::from_file_profile) for the supported
+    /// keys and environment overrides.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the TOML file
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tapsilat::Config;
+    ///
+    /// let config = Config::from_file("tapsilat.toml")?;
+    /// # Ok::<(), tapsilat::TapsilatError>(())
+    /// ```
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let profile = env::var("TAPSILAT_PROFILE").unwrap_or_else(|_| "default".to_string());
+        Self::from_file_profile(path, &profile)
+    }
+
+    /// Loads the named profile from a TOML file.
+    ///
+    /// Profiles support `api_key`, `base_url`, `timeout` (seconds) and `max_retries`.
+    /// The `TAPSILAT_API_KEY`, `TAPSILAT_BASE_URL`, `TAPSILAT_TIMEOUT` and
+    /// `TAPSILAT_MAX_RETRIES` environment variables override the file, so secrets can
+    /// stay out of it.
+    ///
+    /// ```toml
+    /// [default]
+    /// timeout = 30
+    ///
+    /// [sandbox]
+    /// base_url = "https://sandbox.tapsilat.dev/api/v1"
+    ///
+    /// [production]
+    /// base_url = "https://panel.tapsilat.dev/api/v1"
+    /// max_retries = 2
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the TOML file
+    /// * `profile` - Name of the profile to load
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TapsilatError::ConfigError`] if the file cannot be read or parsed, the
+    /// profile does not exist, an override is not a number or no API key is set.
+    pub fn from_file_profile(path: impl AsRef<Path>, profile: &str) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path).map_err(|e| {
+            TapsilatError::ConfigError(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+        let mut profiles: HashMap<String, Profile> = toml::from_str(&contents).map_err(|e| {
+            TapsilatError::ConfigError(format!("Failed to parse {}: {}", path.display(), e))
+        })?;
+
+        let defaults = profiles.get("default").cloned().unwrap_or_default();
+        let selected = profiles.remove(profile).ok_or_else(|| {
+            TapsilatError::ConfigError(format!(
+                "Profile '{}' not found in {}",
+                profile,
+                path.display()
+            ))
+        })?;
+        let settings = selected.or(defaults).with_env()?;
+
+        let api_key = settings.api_key.ok_or_else(|| {
+            TapsilatError::ConfigError(format!(
+                "No API key in profile '{}' or TAPSILAT_API_KEY",
+                profile
+            ))
+        })?;
+        let mut config = Config::new(api_key);
+        if let Some(base_url) = settings.base_url {
+            config.base_url = base_url;
+        }
+        if let Some(timeout) = settings.timeout {
+            config.timeout = timeout;
         }
+        if let Some(max_retries) = settings.max_retries {
+            config.max_retries = max_retries;
+        }
+        config.validate()?;
+        Ok(config)
     }
 
     /// Sets a custom base URL for the API.
@@ -91,6 +274,589 @@ impl Config {
         self
     }
 
+    /// Sets how many times failed GET requests are retried.
+    ///
+    /// Timeouts, connection failures, `429 Too Many Requests` and `5xx` responses are
+    /// retried with exponential backoff starting at 500 ms. Other methods are never
+    /// retried, since they may not be idempotent.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_retries` - Number of retries after the first attempt
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tapsilat::Config;
+    ///
+    /// let config = Config::new("api-key")
+    ///     .with_max_retries(3);
+    /// ```
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Makes the client refuse every request other than GET.
+    ///
+    /// Refused requests fail with [`TapsilatError::ReadOnly`] before anything is sent,
+    /// so a read-only client can be handed to reporting or analytics code without
+    /// letting it change payment state.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether mutating requests are refused
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tapsilat::Config;
+    ///
+    /// let config = Config::new("api-key")
+    ///     .with_read_only(true);
+    /// ```
+    pub fn with_read_only(mut self, enabled: bool) -> Self {
+        self.read_only = enabled;
+        self
+    }
+
+    /// Logs mutating requests instead of sending them.
+    ///
+    /// Requests other than GET still go through all local validation and
+    /// serialization, but the method, URL and exact JSON payload are printed to stderr
+    /// and an empty JSON object is returned in place of the API response. Responses
+    /// whose fields are all optional, such as [`CreateOrderResponse`](crate::CreateOrderResponse),
+    /// decode from it. GET requests are sent as usual, so lookups made during
+    /// validation keep working. Use [`RequestOptions::dry_run`] for a single request.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether mutating requests are only logged
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tapsilat::Config;
+    ///
+    /// let config = Config::new("api-key")
+    ///     .with_dry_run(true);
+    /// ```
+    pub fn with_dry_run(mut self, enabled: bool) -> Self {
+        self.dry_run = enabled;
+        self
+    }
+
+    /// Enables or disables response compression.
+    ///
+    /// When enabled, requests advertise `Accept-Encoding: gzip, br` and compressed
+    /// responses are decompressed transparently.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to request compressed responses
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tapsilat::Config;
+    ///
+    /// let config = Config::new("api-key")
+    ///     .with_compression(false);
+    /// ```
+    pub fn with_compression(mut self, enabled: bool) -> Self {
+        self.compression = enabled;
+        self
+    }
+
+    /// Adds a header that is sent with every request.
+    ///
+    /// Useful for partner identification headers required by a Tapsilat account.
+    /// `Authorization`, `Content-Type` and `User-Agent` are managed by the SDK and
+    /// are rejected by [`Config::validate`].
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Header name
+    /// * `value` - Header value
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tapsilat::Config;
+    ///
+    /// let config = Config::new("api-key")
+    ///     .with_default_header("X-Partner-Id", "partner-42");
+    /// ```
+    pub fn with_default_header(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.default_headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Appends a suffix to the `User-Agent` header.
+    ///
+    /// # Arguments
+    ///
+    /// * `suffix` - Product token identifying the integration, e.g. `my-shop/1.2`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tapsilat::Config;
+    ///
+    /// let config = Config::new("api-key")
+    ///     .with_user_agent_suffix("my-shop/1.2");
+    /// assert!(config.user_agent().ends_with(" my-shop/1.2"));
+    /// ```
+    pub fn with_user_agent_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.user_agent_suffix = Some(suffix.into());
+        self
+    }
+
+    /// Sets the header used to propagate trace IDs.
+    ///
+    /// The same header is read from responses, so the ID returned by the API can be
+    /// inspected via [`TapsilatError::trace_id`] or
+    /// [`TapsilatClient::last_trace_id`](crate::TapsilatClient::last_trace_id).
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Header name, e.g. `X-Request-Id` or `traceparent`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tapsilat::Config;
+    ///
+    /// let config = Config::new("api-key")
+    ///     .with_trace_header("traceparent");
+    /// ```
+    pub fn with_trace_header(mut self, name: impl Into<String>) -> Self {
+        self.trace_header = name.into();
+        self
+    }
+
+    /// Sets a hook supplying the trace ID attached to each request.
+    ///
+    /// The hook is called once per request; returning `None` sends no trace header.
+    /// A trace ID set through [`RequestOptions::trace_id`] takes precedence.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - Closure or [`TraceIdProvider`] returning the current trace ID
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tapsilat::Config;
+    ///
+    /// let config = Config::new("api-key")
+    ///     .with_trace_id_provider(|| Some("req-7f3a".to_string()));
+    /// ```
+    pub fn with_trace_id_provider(mut self, provider: impl TraceIdProvider + 'static) -> Self {
+        self.trace_id_provider = Some(Arc::new(provider));
+        self
+    }
+
+    /// Enables strict response parsing.
+    ///
+    /// In strict mode any response field the SDK's types do not know about results in
+    /// a [`TapsilatError::InvalidResponse`], which is useful in CI runs against the
+    /// sandbox to catch API contract drift. Production should keep the lenient default.
+    ///
+    /// # Arguments
+    ///
+    /// * `strict` - Whether unknown fields are rejected
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tapsilat::Config;
+    ///
+    /// let config = Config::new("api-key")
+    ///     .with_strict_parsing(true);
+    /// ```
+    pub fn with_strict_parsing(mut self, strict: bool) -> Self {
+        self.strict_parsing = strict;
+        self
+    }
+
+    /// Logs unknown response fields to stderr without rejecting the response.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether unknown fields are logged
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tapsilat::Config;
+    ///
+    /// let config = Config::new("api-key")
+    ///     .with_unknown_field_logging(true);
+    /// ```
+    pub fn with_unknown_field_logging(mut self, enabled: bool) -> Self {
+        self.log_unknown_fields = enabled;
+        self
+    }
+
+    /// Sets a sink notified when a response has unknown fields or fails to parse.
+    ///
+    /// Each [`ParseWarning`] names the response, whether it came in a
+    /// `{success, data, message}` envelope and which fields it had, which helps tell
+    /// API schema drift apart from SDK bugs.
+    ///
+    /// # Arguments
+    ///
+    /// * `sink` - Closure or [`ParseWarnings`] receiving parse warnings
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tapsilat::{Config, ParseWarning};
+    ///
+    /// let config = Config::new("api-key").with_parse_warnings(|warning: &ParseWarning| {
+    ///     eprintln!("{}: unknown {:?}", warning.context, warning.unknown_fields);
+    /// });
+    /// ```
+    pub fn with_parse_warnings(mut self, sink: impl ParseWarnings + 'static) -> Self {
+        self.parse_warnings = Some(Arc::new(sink));
+        self
+    }
+
+    /// Checks refunds against the order's paid and refunded amounts before sending them.
+    ///
+    /// When enabled, [`OrderModule::refund`](crate::OrderModule::refund) fetches the order
+    /// first and rejects refunds larger than the remaining balance with a
+    /// [`TapsilatError::ValidationError`] instead of a round trip to the API.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether refunds are checked client-side
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tapsilat::Config;
+    ///
+    /// let config = Config::new("api-key")
+    ///     .with_refund_guard(true);
+    /// ```
+    pub fn with_refund_guard(mut self, enabled: bool) -> Self {
+        self.refund_guard = enabled;
+        self
+    }
+
+    /// Computes a missing `tax_amount` from the basket's VAT rates when creating orders.
+    ///
+    /// When enabled, [`OrderModule::create`](crate::OrderModule::create) fills in
+    /// `tax_amount` with [`tax::apply_tax`](crate::tax::apply_tax) if it is `None` and every
+    /// basket item has a `vat_rate`. Requests with an explicit `tax_amount`, without a
+    /// basket or with items lacking a rate are sent unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Whether basket prices include or exclude VAT
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tapsilat::tax::PricingMode;
+    /// use tapsilat::Config;
+    ///
+    /// let config = Config::new("api-key")
+    ///     .with_auto_tax(PricingMode::Inclusive);
+    /// ```
+    pub fn with_auto_tax(mut self, mode: PricingMode) -> Self {
+        self.auto_tax = Some(mode);
+        self
+    }
+
+    /// Sets the currency of order and payment requests that leave it empty.
+    ///
+    /// Requests in any other currency are rejected with a validation error, so a
+    /// single-currency merchant cannot submit a mismatched one by mistake.
+    ///
+    /// # Arguments
+    ///
+    /// * `currency` - Currency all requests are made in
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tapsilat::{Config, Currency};
+    ///
+    /// let config = Config::new("api-key")
+    ///     .with_default_currency(Currency::TRY);
+    /// ```
+    pub fn with_default_currency(mut self, currency: Currency) -> Self {
+        self.default_currency = Some(currency);
+        self
+    }
+
+    /// Sets the checkout locale of order requests that leave it empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` - Language of the checkout page
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tapsilat::{Config, Locale};
+    ///
+    /// let config = Config::new("api-key")
+    ///     .with_default_locale(Locale::En);
+    /// ```
+    pub fn with_default_locale(mut self, locale: Locale) -> Self {
+        self.default_locale = Some(locale);
+        self
+    }
+
+    /// Fills an empty `currency` from [`default_currency`](Self::default_currency) and
+    /// rejects one that differs from it
+    #[cfg_attr(not(feature = "http"), allow(dead_code))]
+    pub(crate) fn apply_default_currency(&self, currency: &mut String) -> Result<()> {
+        let Some(default) = self.default_currency else {
+            return Ok(());
+        };
+        if currency.trim().is_empty() {
+            *currency = default.as_str().to_string();
+        } else if !currency.trim().eq_ignore_ascii_case(default.as_str()) {
+            return Err(TapsilatError::ValidationError(format!(
+                "Currency {} does not match the configured currency {}",
+                currency,
+                default.as_str()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Fills an empty `locale` from [`default_locale`](Self::default_locale)
+    #[cfg_attr(not(feature = "http"), allow(dead_code))]
+    pub(crate) fn apply_default_locale(&self, locale: &mut String) {
+        if let Some(default) = self.default_locale {
+            if locale.trim().is_empty() {
+                *locale = default.as_str().to_string();
+            }
+        }
+    }
+
+    /// Checks the buyer's `identity_number` before creating orders.
+    ///
+    /// The validator is chosen per buyer from their `citizenship` with
+    /// [`identity::validator_for`](crate::identity::validator_for): TC Kimlik numbers for
+    /// Turkish citizens and buyers without a citizenship, passport numbers for everyone
+    /// else.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether identity numbers are validated
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tapsilat::Config;
+    ///
+    /// let config = Config::new("api-key")
+    ///     .with_identity_validation(true);
+    /// ```
+    pub fn with_identity_validation(mut self, enabled: bool) -> Self {
+        self.identity_validation = enabled;
+        self
+    }
+
+    /// Checks the buyer's `gsm_number` before creating orders.
+    ///
+    /// Use [`PhoneValidation::TurkishGsm`] when all buyers have Turkish mobile numbers
+    /// and [`PhoneValidation::E164`] to also accept foreign numbers in international
+    /// format. Orders with an invalid number fail with a validation error instead of
+    /// being sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Validation strategy for buyer phone numbers
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tapsilat::{Config, PhoneValidation};
+    ///
+    /// let config = Config::new("api-key")
+    ///     .with_phone_validation(PhoneValidation::E164);
+    /// ```
+    pub fn with_phone_validation(mut self, mode: PhoneValidation) -> Self {
+        self.phone_validation = Some(mode);
+        self
+    }
+
+    /// Rejects plain `http` callback and redirect URLs before requests are sent.
+    ///
+    /// URLs are always checked with [`Validators::validate_url`](crate::Validators::validate_url);
+    /// this additionally requires the `https` scheme, which production checkouts should
+    /// use.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether only https URLs are accepted
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tapsilat::Config;
+    ///
+    /// let config = Config::new("api-key")
+    ///     .with_require_https_urls(true);
+    /// ```
+    pub fn with_require_https_urls(mut self, enabled: bool) -> Self {
+        self.require_https_urls = enabled;
+        self
+    }
+
+    /// Sets the largest response body the client will read.
+    ///
+    /// Bodies are read incrementally and the request fails with
+    /// [`TapsilatError::InvalidResponse`] as soon as the limit is crossed, so a
+    /// misbehaving endpoint or proxy cannot exhaust memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - Maximum decompressed body size in bytes
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tapsilat::Config;
+    ///
+    /// let config = Config::new("api-key")
+    ///     .with_max_response_size(2 * 1024 * 1024);
+    /// ```
+    pub fn with_max_response_size(mut self, bytes: u64) -> Self {
+        self.max_response_size = bytes;
+        self
+    }
+
+    /// Presents a client certificate when the API or a gateway in front of it requires mutual TLS.
+    ///
+    /// The PEM data is parsed when the client is built, and
+    /// [`TapsilatClient::new`](crate::TapsilatClient::new) fails with
+    /// [`TapsilatError::ConfigError`] if it is unusable.
+    ///
+    /// # Arguments
+    ///
+    /// * `cert` - PEM encoded certificate chain, leaf certificate first
+    /// * `key` - PEM encoded private key of the leaf certificate
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tapsilat::Config;
+    ///
+    /// let config = Config::new("api-key").with_client_certificate(
+    ///     std::fs::read("client.crt").unwrap(),
+    ///     std::fs::read("client.key").unwrap(),
+    /// );
+    /// ```
+    pub fn with_client_certificate(
+        mut self,
+        cert: impl Into<Vec<u8>>,
+        key: impl Into<Vec<u8>>,
+    ) -> Self {
+        self.client_certificate = Some(ClientCertificate {
+            cert_pem: cert.into(),
+            key_pem: key.into(),
+        });
+        self
+    }
+
+    /// Enables conditional GET requests for responses that carry an `ETag`.
+    ///
+    /// The last body of up to `capacity` URLs is kept together with its `ETag`, and
+    /// later GETs send `If-None-Match`. A `304 Not Modified` answer is served from the
+    /// cache, which saves quota on stable endpoints such as organization settings or
+    /// system order statuses. Every request still reaches the API, so cached bodies are
+    /// never stale.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Maximum number of cached URLs, or 0 to disable caching
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tapsilat::Config;
+    ///
+    /// let config = Config::new("api-key")
+    ///     .with_etag_cache(256);
+    /// ```
+    pub fn with_etag_cache(mut self, capacity: usize) -> Self {
+        self.etag_cache_capacity = capacity;
+        self
+    }
+
+    /// Coalesces concurrent identical GET requests into a single HTTP call.
+    ///
+    /// While a GET to a URL is in flight, other threads requesting the same URL through
+    /// this client or its clones wait for it and share its result instead of sending
+    /// their own request. This keeps bursts of webhook handlers polling
+    /// `order/{ref}/status` from stampeding the API. Requests with per-request headers
+    /// or a per-request timeout are never coalesced, and if the shared call fails each
+    /// waiting caller retries on its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether identical in-flight GET requests are coalesced
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tapsilat::Config;
+    ///
+    /// let config = Config::new("api-key")
+    ///     .with_request_coalescing(true);
+    /// ```
+    pub fn with_request_coalescing(mut self, enabled: bool) -> Self {
+        self.coalesce_requests = enabled;
+        self
+    }
+
+    /// Sets a sink notified after every API request.
+    ///
+    /// The sink receives the endpoint route, method, status code and latency of each
+    /// request, which is enough to feed Prometheus counters and histograms.
+    ///
+    /// # Arguments
+    ///
+    /// * `sink` - Closure or [`MetricsSink`] receiving request metrics
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tapsilat::Config;
+    ///
+    /// let config = Config::new("api-key").with_metrics_sink(
+    ///     |endpoint: &str, method: &str, status: Option<u16>, duration: std::time::Duration| {
+    ///         println!("{} {} {:?} {:?}", method, endpoint, status, duration);
+    ///     },
+    /// );
+    /// ```
+    pub fn with_metrics_sink(mut self, sink: impl MetricsSink + 'static) -> Self {
+        self.metrics_sink = Some(Arc::new(sink));
+        self
+    }
+
+    /// Returns the `User-Agent` header value sent with requests.
+    pub fn user_agent(&self) -> String {
+        let base = format!("tapsilat-rust/{}", env!("CARGO_PKG_VERSION"));
+        match self.user_agent_suffix.as_deref().map(str::trim) {
+            Some(suffix) if !suffix.is_empty() => format!("{} {}", base, suffix),
+            _ => base,
+        }
+    }
+
     /// Validates the configuration.
     ///
     /// Ensures that required fields are present and valid.
@@ -100,6 +866,9 @@ impl Config {
     /// Returns [`TapsilatError::ConfigError`] if:
     /// - API key is empty
     /// - Base URL is empty
+    /// - Maximum response size is zero
+    /// - A client certificate is set with an empty certificate or key
+    /// - A default header is empty or overrides a header managed by the SDK
     ///
     /// # Example
     ///
@@ -122,6 +891,307 @@ impl Config {
             ));
         }
 
+        if self.max_response_size == 0 {
+            return Err(TapsilatError::ConfigError(
+                "Maximum response size must be greater than zero".to_string(),
+            ));
+        }
+
+        if let Some(certificate) = &self.client_certificate {
+            if certificate.cert_pem.is_empty() || certificate.key_pem.is_empty() {
+                return Err(TapsilatError::ConfigError(
+                    "Client certificate and key cannot be empty".to_string(),
+                ));
+            }
+        }
+
+        if self.trace_header.trim().is_empty() {
+            return Err(TapsilatError::ConfigError(
+                "Trace header name cannot be empty".to_string(),
+            ));
+        }
+
+        for (name, _) in &self.default_headers {
+            if name.trim().is_empty() {
+                return Err(TapsilatError::ConfigError(
+                    "Default header name cannot be empty".to_string(),
+                ));
+            }
+
+            if is_reserved_header(name) {
+                return Err(TapsilatError::ConfigError(format!(
+                    "Header '{}' is managed by the SDK and cannot be overridden",
+                    name
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Settings of one profile in a configuration file
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Profile {
+    api_key: Option<String>,
+    base_url: Option<String>,
+    timeout: Option<u64>,
+    max_retries: Option<u32>,
+}
+
+impl Profile {
+    /// Fills settings missing from this profile with those of `defaults`
+    fn or(self, defaults: Profile) -> Profile {
+        Profile {
+            api_key: self.api_key.or(defaults.api_key),
+            base_url: self.base_url.or(defaults.base_url),
+            timeout: self.timeout.or(defaults.timeout),
+            max_retries: self.max_retries.or(defaults.max_retries),
+        }
+    }
+
+    /// Applies the `TAPSILAT_*` environment overrides
+    fn with_env(mut self) -> Result<Profile> {
+        fn number<T: std::str::FromStr>(name: &str) -> Result<Option<T>> {
+            match env::var(name) {
+                Ok(value) => {
+                    value.trim().parse().map(Some).map_err(|_| {
+                        TapsilatError::ConfigError(format!("{} must be a number", name))
+                    })
+                }
+                Err(_) => Ok(None),
+            }
+        }
+
+        if let Ok(api_key) = env::var("TAPSILAT_API_KEY") {
+            self.api_key = Some(api_key);
+        }
+        if let Ok(base_url) = env::var("TAPSILAT_BASE_URL") {
+            self.base_url = Some(base_url);
+        }
+        if let Some(timeout) = number("TAPSILAT_TIMEOUT")? {
+            self.timeout = Some(timeout);
+        }
+        if let Some(max_retries) = number("TAPSILAT_MAX_RETRIES")? {
+            self.max_retries = Some(max_retries);
+        }
+        Ok(self)
+    }
+}
+
+/// Per-request overrides applied on top of the client [`Config`].
+///
+/// # Example
+///
+/// ```rust
+/// use tapsilat::RequestOptions;
+///
+/// let options = RequestOptions::new()
+///     .timeout(5)
+///     .idempotency_key("checkout-123")
+///     .header("X-Channel", "web");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    /// Request timeout in seconds, overriding [`Config::timeout`].
+    pub timeout: Option<u64>,
+    /// Extra headers sent with this request only.
+    pub headers: Vec<(String, String)>,
+    /// Value sent in the `Idempotency-Key` header.
+    pub idempotency_key: Option<String>,
+    /// Trace ID for this request, overriding [`Config::trace_id_provider`].
+    pub trace_id: Option<String>,
+    /// Whether this request is only logged instead of sent, see [`Config::dry_run`].
+    pub dry_run: bool,
+    /// Whether order creation first checks that no order has the same `external_reference_id`.
+    pub unique_external_reference: bool,
+}
+
+impl RequestOptions {
+    /// Creates empty request options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the timeout in seconds for this request.
+    pub fn timeout(mut self, timeout: u64) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Adds a header to this request.
+    ///
+    /// `Authorization`, `Content-Type` and `User-Agent` are managed by the SDK; a request
+    /// setting one of them fails with [`TapsilatError::ValidationError`] before it is sent.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the idempotency key for this request.
+    pub fn idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+
+    /// Sets the trace ID sent in [`Config::trace_header`] for this request.
+    pub fn trace_id(mut self, trace_id: impl Into<String>) -> Self {
+        self.trace_id = Some(trace_id.into());
+        self
+    }
+
+    /// Logs this request instead of sending it, if it is not a GET.
+    pub fn dry_run(mut self) -> Self {
+        self.dry_run = true;
+        self
+    }
+
+    /// Fails order creation with [`TapsilatError::DuplicateOrder`] if an order with the
+    /// same `external_reference_id` already exists.
+    pub fn unique_external_reference(mut self) -> Self {
+        self.unique_external_reference = true;
+        self
+    }
+
+    /// Checks the per-request headers, rejecting empty and SDK-managed names.
+    #[cfg(feature = "http")]
+    pub(crate) fn validate(&self) -> Result<()> {
+        for (name, _) in &self.headers {
+            if name.trim().is_empty() {
+                return Err(TapsilatError::ValidationError(
+                    "Header name cannot be empty".to_string(),
+                ));
+            }
+
+            if is_reserved_header(name) {
+                return Err(TapsilatError::ValidationError(format!(
+                    "Header '{}' is managed by the SDK and cannot be overridden",
+                    name
+                )));
+            }
+        }
+
         Ok(())
     }
 }
+
+/// Supplies the correlation/trace ID attached to outgoing requests.
+///
+/// Implemented for any `Fn() -> Option<String>` closure, so a hook reading the
+/// current span or request context can be passed directly to
+/// [`Config::with_trace_id_provider`].
+pub trait TraceIdProvider: Send + Sync {
+    /// Returns the trace ID for the request about to be sent.
+    fn trace_id(&self) -> Option<String>;
+}
+
+impl<F> TraceIdProvider for F
+where
+    F: Fn() -> Option<String> + Send + Sync,
+{
+    fn trace_id(&self) -> Option<String> {
+        self()
+    }
+}
+
+/// Receives metrics for each API request.
+///
+/// Implemented for any `Fn(&str, &str, Option<u16>, Duration)` closure, so a hook
+/// updating Prometheus metrics can be passed directly to [`Config::with_metrics_sink`].
+pub trait MetricsSink: Send + Sync {
+    /// Called once a request has finished.
+    ///
+    /// `endpoint` is the route with identifiers replaced by `{id}`, such as
+    /// `order/{id}/status`, so it can be used as a metric label. `status` is `None`
+    /// when no response was received.
+    fn on_request(&self, endpoint: &str, method: &str, status: Option<u16>, duration: Duration);
+}
+
+impl<F> MetricsSink for F
+where
+    F: Fn(&str, &str, Option<u16>, Duration) + Send + Sync,
+{
+    fn on_request(&self, endpoint: &str, method: &str, status: Option<u16>, duration: Duration) {
+        self(endpoint, method, status, duration)
+    }
+}
+
+/// A response that did not match the SDK's types exactly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseWarning {
+    /// What was being parsed, e.g. `order response`
+    pub context: String,
+    /// Whether the body was read from a `{success, data, message}` envelope
+    pub enveloped: bool,
+    /// Top-level fields of the parsed body
+    pub present_fields: Vec<String>,
+    /// Paths of fields the SDK does not know, such as `checkout.theme`
+    pub unknown_fields: Vec<String>,
+    /// Deserialization error, if the body could not be parsed
+    pub error: Option<String>,
+}
+
+/// Receives warnings about responses that did not match the SDK's types.
+///
+/// Implemented for any `Fn(&ParseWarning)` closure, so a hook forwarding warnings to
+/// a logger can be passed directly to [`Config::with_parse_warnings`].
+pub trait ParseWarnings: Send + Sync {
+    /// Called when a response has unknown fields or fails to parse.
+    fn on_warning(&self, warning: &ParseWarning);
+}
+
+impl<F> ParseWarnings for F
+where
+    F: Fn(&ParseWarning) + Send + Sync,
+{
+    fn on_warning(&self, warning: &ParseWarning) {
+        self(warning)
+    }
+}
+
+/// PEM encoded client certificate and key used for mutual TLS.
+///
+/// The key is left out of the `Debug` output and zeroed when the certificate is dropped.
+#[derive(Clone)]
+pub struct ClientCertificate {
+    /// Certificate chain, leaf certificate first
+    pub cert_pem: Vec<u8>,
+    /// Private key of the leaf certificate
+    pub key_pem: Vec<u8>,
+}
+
+impl Drop for ClientCertificate {
+    fn drop(&mut self) {
+        self.key_pem.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for ClientCertificate {}
+
+impl fmt::Debug for ClientCertificate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientCertificate")
+            .field("cert_pem", &format_args!("<{} bytes>", self.cert_pem.len()))
+            .field("key_pem", &format_args!("<redacted>"))
+            .finish()
+    }
+}
+
+impl fmt::Debug for dyn TraceIdProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TraceIdProvider")
+    }
+}
+
+impl fmt::Debug for dyn MetricsSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MetricsSink")
+    }
+}
+
+impl fmt::Debug for dyn ParseWarnings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ParseWarnings")
+    }
+}
@@ -3,8 +3,14 @@
 //! This module defines the comprehensive error types that can occur when using the SDK,
 //! including HTTP errors, validation errors, and API-specific errors.
 
+use crate::messages::ErrorMessages;
+use crate::types::Locale;
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
+/// Number of body characters kept in [`TapsilatError::UnexpectedContentType`]
+const SNIPPET_LENGTH: usize = 200;
+
 /// Main error type for all Tapsilat SDK operations.
 ///
 /// This enum covers all possible error conditions that can occur when
@@ -12,7 +18,20 @@ use std::fmt;
 #[derive(Debug)]
 pub enum TapsilatError {
     /// HTTP transport error occurred during API communication.
+    #[cfg(feature = "http")]
     Http(Box<ureq::Error>),
+    /// The request did not complete within the configured timeout.
+    Timeout {
+        /// URL of the request that timed out
+        url: String,
+    },
+    /// The connection to the API could not be established or was dropped.
+    ConnectionFailed {
+        /// URL of the request that failed
+        url: String,
+        /// Description of the underlying transport failure
+        message: String,
+    },
     /// Error occurred while serializing or deserializing data.
     Serialization(std::io::Error),
     /// API returned an invalid or unexpected response format.
@@ -23,33 +42,409 @@ pub enum TapsilatError {
         status_code: u16,
         /// Error message from the API
         message: String,
+        /// Trace ID returned in the configured trace header, if any
+        trace_id: Option<String>,
     },
     /// Configuration error, such as missing API key or invalid base URL.
     ConfigError(String),
     /// Input validation error occurred before making API request.
     ValidationError(String),
+    /// API rejected the request (HTTP 422) with per-field validation errors.
+    ValidationFailed {
+        /// Error message from the API
+        message: String,
+        /// Fields rejected by the API
+        fields: Vec<FieldError>,
+        /// Trace ID returned in the configured trace header, if any
+        trace_id: Option<String>,
+    },
+    /// A mutating request was refused locally because the client is read-only.
+    ReadOnly {
+        /// HTTP method of the refused request
+        method: String,
+        /// Endpoint of the refused request
+        endpoint: String,
+    },
+    /// An order with the same external reference ID already exists.
+    DuplicateOrder {
+        /// External reference ID of the rejected order
+        external_reference_id: String,
+        /// Reference ID of the existing order, if known
+        reference_id: Option<String>,
+    },
+    /// The response body is not JSON, e.g. an HTML page from a proxy or firewall.
+    UnexpectedContentType {
+        /// HTTP status code of the response
+        status: u16,
+        /// `Content-Type` header of the response, if any
+        content_type: Option<String>,
+        /// Start of the response body, with whitespace collapsed
+        snippet: String,
+    },
+    /// Polling gave up before the resource reached a final state.
+    PollTimeout {
+        /// Reference of the resource being polled
+        reference_id: String,
+        /// Last status observed before giving up, if any
+        last_status: Option<String>,
+    },
+}
+
+/// Customer-facing category of a [`TapsilatError`].
+///
+/// Codes group errors by what the customer can do about them rather than by where
+/// they were raised; see [`TapsilatError::code`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    /// The request was rejected because of the data entered
+    InvalidRequest,
+    /// The same order or payment was already submitted
+    Duplicate,
+    /// The merchant credentials were rejected
+    Unauthorized,
+    NotFound,
+    RateLimited,
+    CardDeclined,
+    InsufficientFunds,
+    /// The issuer or fraud screening blocked the payment
+    FraudSuspected,
+    /// The API could not be reached
+    Network,
+    Timeout,
+    /// The API or a proxy in front of it failed
+    ServiceUnavailable,
+    Unknown,
+}
+
+impl ErrorCode {
+    /// Returns the code as a stable snake_case string, e.g. `card_declined`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ErrorCode::InvalidRequest => "invalid_request",
+            ErrorCode::Duplicate => "duplicate",
+            ErrorCode::Unauthorized => "unauthorized",
+            ErrorCode::NotFound => "not_found",
+            ErrorCode::RateLimited => "rate_limited",
+            ErrorCode::CardDeclined => "card_declined",
+            ErrorCode::InsufficientFunds => "insufficient_funds",
+            ErrorCode::FraudSuspected => "fraud_suspected",
+            ErrorCode::Network => "network",
+            ErrorCode::Timeout => "timeout",
+            ErrorCode::ServiceUnavailable => "service_unavailable",
+            ErrorCode::Unknown => "unknown",
+        }
+    }
+}
+
+/// A single field rejected by the API's request validation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldError {
+    /// Path of the rejected field, e.g. `buyer.email` or `basket_items[0].price`
+    #[serde(alias = "path", alias = "param")]
+    pub field: String,
+    /// Human readable reason the field was rejected
+    #[serde(alias = "error", alias = "msg", default)]
+    pub message: String,
+    /// Machine readable error code, if the API provides one
+    #[serde(default)]
+    pub code: Option<String>,
+}
+
+impl FieldError {
+    /// Parses the `errors` member of an API error body.
+    ///
+    /// Accepts an array of `{field, message}` objects as well as an object mapping
+    /// field names to a message or a list of messages. Plain string entries carry no
+    /// field information and are skipped.
+    #[cfg_attr(not(any(feature = "http", feature = "wasm")), allow(dead_code))]
+    pub(crate) fn parse_list(errors: &serde_json::Value) -> Vec<FieldError> {
+        match errors {
+            serde_json::Value::Array(items) => items
+                .iter()
+                .filter(|item| item.is_object())
+                .filter_map(|item| serde_json::from_value(item.clone()).ok())
+                .collect(),
+            serde_json::Value::Object(map) => map
+                .iter()
+                .flat_map(|(field, messages)| {
+                    let messages = match messages {
+                        serde_json::Value::Array(list) => list
+                            .iter()
+                            .filter_map(|m| m.as_str().map(str::to_string))
+                            .collect(),
+                        serde_json::Value::String(message) => vec![message.clone()],
+                        _ => Vec::new(),
+                    };
+                    messages.into_iter().map(move |message| FieldError {
+                        field: field.clone(),
+                        message,
+                        code: None,
+                    })
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
 }
 
 impl fmt::Display for TapsilatError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            #[cfg(feature = "http")]
             TapsilatError::Http(err) => write!(f, "HTTP error: {}", err),
+            TapsilatError::Timeout { url } => write!(f, "Request to {} timed out", url),
+            TapsilatError::ConnectionFailed { url, message } => {
+                write!(f, "Connection to {} failed: {}", url, message)
+            }
             TapsilatError::Serialization(err) => write!(f, "Serialization error: {}", err),
             TapsilatError::InvalidResponse(msg) => write!(f, "Invalid response: {}", msg),
             TapsilatError::ApiError {
                 status_code,
                 message,
+                trace_id,
             } => {
-                write!(f, "API error ({}): {}", status_code, message)
+                write!(f, "API error ({}): {}", status_code, message)?;
+                if let Some(trace_id) = trace_id {
+                    write!(f, " [trace ID: {}]", trace_id)?;
+                }
+                Ok(())
             }
             TapsilatError::ConfigError(msg) => write!(f, "Configuration error: {}", msg),
             TapsilatError::ValidationError(msg) => write!(f, "Validation error: {}", msg),
+            TapsilatError::ValidationFailed {
+                message, fields, ..
+            } => {
+                write!(f, "Validation failed: {}", message)?;
+                for field in fields {
+                    write!(f, "; {}: {}", field.field, field.message)?;
+                }
+                Ok(())
+            }
+            TapsilatError::ReadOnly { method, endpoint } => write!(
+                f,
+                "Refused {} {}: the client is configured as read-only",
+                method, endpoint
+            ),
+            TapsilatError::DuplicateOrder {
+                external_reference_id,
+                reference_id,
+            } => {
+                write!(
+                    f,
+                    "An order with external reference {} already exists",
+                    external_reference_id
+                )?;
+                if let Some(reference_id) = reference_id {
+                    write!(f, " ({})", reference_id)?;
+                }
+                Ok(())
+            }
+            TapsilatError::UnexpectedContentType {
+                status,
+                content_type,
+                snippet,
+            } => write!(
+                f,
+                "Unexpected {} response (HTTP {}): {}",
+                content_type.as_deref().unwrap_or("non-JSON"),
+                status,
+                snippet
+            ),
+            TapsilatError::PollTimeout {
+                reference_id,
+                last_status,
+            } => {
+                write!(f, "Timed out waiting for {}", reference_id)?;
+                if let Some(last_status) = last_status {
+                    write!(f, " (last status: {})", last_status)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl TapsilatError {
+    /// Classifies the error into an [`ErrorCode`].
+    ///
+    /// Payment failures are recognised from the API message, e.g. "insufficient
+    /// funds"; other API errors are classified by HTTP status.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            #[cfg(feature = "http")]
+            TapsilatError::Http(_) => ErrorCode::Network,
+            TapsilatError::ConnectionFailed { .. } => ErrorCode::Network,
+            TapsilatError::Timeout { .. } | TapsilatError::PollTimeout { .. } => ErrorCode::Timeout,
+            TapsilatError::ValidationError(_) | TapsilatError::ValidationFailed { .. } => {
+                ErrorCode::InvalidRequest
+            }
+            TapsilatError::DuplicateOrder { .. } => ErrorCode::Duplicate,
+            TapsilatError::UnexpectedContentType { .. } => ErrorCode::ServiceUnavailable,
+            TapsilatError::ApiError {
+                status_code,
+                message,
+                ..
+            } => {
+                let message = message.to_lowercase();
+                if message.contains("insufficient") || message.contains("yetersiz") {
+                    return ErrorCode::InsufficientFunds;
+                }
+                if message.contains("fraud") {
+                    return ErrorCode::FraudSuspected;
+                }
+                if message.contains("declin") || *status_code == 402 {
+                    return ErrorCode::CardDeclined;
+                }
+                match status_code {
+                    401 | 403 => ErrorCode::Unauthorized,
+                    404 => ErrorCode::NotFound,
+                    409 => ErrorCode::Duplicate,
+                    429 => ErrorCode::RateLimited,
+                    400..=499 => ErrorCode::InvalidRequest,
+                    500..=599 => ErrorCode::ServiceUnavailable,
+                    _ => ErrorCode::Unknown,
+                }
+            }
+            TapsilatError::Serialization(_)
+            | TapsilatError::InvalidResponse(_)
+            | TapsilatError::ConfigError(_)
+            | TapsilatError::ReadOnly { .. } => ErrorCode::Unknown,
+        }
+    }
+
+    /// Returns a message for this error that is safe to show to customers.
+    ///
+    /// Unlike the [`Display`](fmt::Display) output it never includes API messages,
+    /// URLs or trace IDs; see [`ErrorMessages`].
+    pub fn localized_message(&self, locale: Locale) -> &'static str {
+        ErrorMessages::message(self.code(), locale)
+    }
+
+    /// Returns the trace ID the API attached to this error, if any.
+    pub fn trace_id(&self) -> Option<&str> {
+        match self {
+            TapsilatError::ApiError { trace_id, .. }
+            | TapsilatError::ValidationFailed { trace_id, .. } => trace_id.as_deref(),
+            _ => None,
         }
     }
 }
 
 impl std::error::Error for TapsilatError {}
 
+impl TapsilatError {
+    /// Builds the error for an API response with a `status_code` of 400 or above.
+    ///
+    /// A 422 carrying per-field errors becomes [`TapsilatError::ValidationFailed`];
+    /// everything else is an [`TapsilatError::ApiError`].
+    #[cfg_attr(not(any(feature = "http", feature = "wasm")), allow(dead_code))]
+    pub(crate) fn from_status(status_code: u16, body: &str, trace_id: Option<String>) -> Self {
+        let error_body: serde_json::Value = serde_json::from_str(body).unwrap_or_default();
+        let message = error_body["message"]
+            .as_str()
+            .unwrap_or("Unknown API error")
+            .to_string();
+
+        if status_code == 422 {
+            let fields = FieldError::parse_list(&error_body["errors"]);
+            if !fields.is_empty() {
+                return TapsilatError::ValidationFailed {
+                    message,
+                    fields,
+                    trace_id,
+                };
+            }
+        }
+
+        TapsilatError::ApiError {
+            status_code,
+            message,
+            trace_id,
+        }
+    }
+
+    /// Builds the error for a response body that is not JSON, if it is one.
+    ///
+    /// Bodies that parse as JSON are left to the regular handling. Otherwise the body
+    /// counts as unexpected when it looks like markup or is sent with a content type
+    /// other than JSON.
+    #[cfg_attr(not(any(feature = "http", feature = "wasm")), allow(dead_code))]
+    pub(crate) fn unexpected_content_type(
+        status: u16,
+        content_type: Option<&str>,
+        body: &str,
+    ) -> Option<Self> {
+        let body = body.trim();
+        if body.is_empty() || serde_json::from_str::<serde_json::Value>(body).is_ok() {
+            return None;
+        }
+        let json_type = content_type.is_some_and(|value| {
+            value
+                .split(';')
+                .next()
+                .is_some_and(|mime| mime.trim().to_ascii_lowercase().ends_with("json"))
+        });
+        let markup = body.starts_with('<');
+        if !markup && (json_type || content_type.is_none()) {
+            return None;
+        }
+
+        let collapsed = body.split_whitespace().collect::<Vec<_>>().join(" ");
+        let mut snippet: String = collapsed.chars().take(SNIPPET_LENGTH).collect();
+        if collapsed.chars().count() > SNIPPET_LENGTH {
+            snippet.push_str("...");
+        }
+        Some(TapsilatError::UnexpectedContentType {
+            status,
+            content_type: content_type.map(str::to_string),
+            snippet,
+        })
+    }
+
+    /// Classifies a transport error raised while calling `url`.
+    ///
+    /// Timeouts and connection level failures get their own variants so they can be
+    /// told apart from protocol or TLS problems, which stay [`TapsilatError::Http`].
+    #[cfg(feature = "http")]
+    pub(crate) fn from_transport(err: ureq::Error, url: &str) -> Self {
+        use std::io::ErrorKind;
+
+        match err {
+            ureq::Error::Timeout(_) => TapsilatError::Timeout {
+                url: url.to_string(),
+            },
+            ureq::Error::Io(ref io) if io.kind() == ErrorKind::TimedOut => TapsilatError::Timeout {
+                url: url.to_string(),
+            },
+            ureq::Error::HostNotFound | ureq::Error::ConnectionFailed => {
+                TapsilatError::ConnectionFailed {
+                    url: url.to_string(),
+                    message: err.to_string(),
+                }
+            }
+            ureq::Error::Io(ref io)
+                if matches!(
+                    io.kind(),
+                    ErrorKind::ConnectionRefused
+                        | ErrorKind::ConnectionReset
+                        | ErrorKind::ConnectionAborted
+                        | ErrorKind::NotConnected
+                        | ErrorKind::BrokenPipe
+                        | ErrorKind::UnexpectedEof
+                ) =>
+            {
+                TapsilatError::ConnectionFailed {
+                    url: url.to_string(),
+                    message: err.to_string(),
+                }
+            }
+            err => TapsilatError::Http(Box::new(err)),
+        }
+    }
+}
+
+#[cfg(feature = "http")]
 impl From<ureq::Error> for TapsilatError {
     fn from(err: ureq::Error) -> Self {
         TapsilatError::Http(Box::new(err))
@@ -0,0 +1,1510 @@
+//! Turkish province (il) and district (ilçe) reference data.
+//!
+//! Covers the 81 provinces with their plate codes and the official district lists,
+//! for checking billing and shipping addresses before they reach the API. Lookups
+//! go through [`normalize`], so "Istanbul", "İSTANBUL" and "istanbul" all find
+//! İstanbul.
+
+/// A province with its official districts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Province {
+    /// Plate code, 1-81
+    pub plate_code: u8,
+    /// Official name, e.g. `İstanbul`
+    pub name: &'static str,
+    /// Official district names; provinces without a metropolitan municipality list
+    /// their central district as `Merkez`
+    pub districts: &'static [&'static str],
+}
+
+impl Province {
+    /// Returns the official name of a district of this province
+    pub fn find_district(&self, district: &str) -> Option<&'static str> {
+        let key = normalize(district);
+        self.districts
+            .iter()
+            .copied()
+            .find(|name| normalize(name) == key)
+    }
+}
+
+/// Common alternative province names and their official forms
+const ALIASES: &[(&str, &str)] = &[
+    ("afyon", "Afyonkarahisar"),
+    ("antep", "Gaziantep"),
+    ("icel", "Mersin"),
+    ("maras", "Kahramanmaraş"),
+    ("k.maras", "Kahramanmaraş"),
+    ("urfa", "Şanlıurfa"),
+];
+
+/// Folds a place name into a comparison key
+///
+/// Turkish letters are mapped to their ASCII base letters, with dotted and dotless
+/// `i` treated alike, case is ignored and whitespace collapsed.
+///
+/// # Example
+///
+/// ```rust
+/// use tapsilat::geo::normalize;
+///
+/// assert_eq!(normalize("İstanbul"), normalize("ISTANBUL"));
+/// assert_eq!(normalize("  Şanlı  Urfa "), "sanli urfa");
+/// ```
+pub fn normalize(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .map(|c| match c {
+            'İ' | 'I' | 'ı' | 'Î' | 'î' => 'i',
+            'Ç' | 'ç' => 'c',
+            'Ğ' | 'ğ' => 'g',
+            'Ö' | 'ö' => 'o',
+            'Ş' | 'ş' => 's',
+            'Ü' | 'ü' | 'Û' | 'û' => 'u',
+            'Â' | 'â' => 'a',
+            c => c.to_ascii_lowercase(),
+        })
+        .collect()
+}
+
+/// Finds a province by name, alias or plate code
+///
+/// # Example
+///
+/// ```rust
+/// use tapsilat::geo::find_province;
+///
+/// assert_eq!(find_province("izmir").unwrap().name, "İzmir");
+/// assert_eq!(find_province("34").unwrap().name, "İstanbul");
+/// assert_eq!(find_province("Urfa").unwrap().plate_code, 63);
+/// ```
+pub fn find_province(name: &str) -> Option<&'static Province> {
+    if let Ok(plate_code) = name.trim().parse::<u8>() {
+        return PROVINCES
+            .iter()
+            .find(|province| province.plate_code == plate_code);
+    }
+
+    let key = normalize(name);
+    let key = ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == key)
+        .map_or(key, |(_, official)| normalize(official));
+    PROVINCES
+        .iter()
+        .find(|province| normalize(province.name) == key)
+}
+
+/// Returns whether a country name or code refers to Turkey
+pub fn is_turkey(country: &str) -> bool {
+    matches!(
+        normalize(country).as_str(),
+        "tr" | "tur" | "turkey" | "turkiye" | "turkiye cumhuriyeti"
+    )
+}
+
+/// The 81 provinces, ordered by plate code
+pub static PROVINCES: &[Province] = &[
+    Province {
+        plate_code: 1,
+        name: "Adana",
+        districts: &[
+            "Aladağ",
+            "Ceyhan",
+            "Çukurova",
+            "Feke",
+            "İmamoğlu",
+            "Karaisalı",
+            "Karataş",
+            "Kozan",
+            "Pozantı",
+            "Saimbeyli",
+            "Sarıçam",
+            "Seyhan",
+            "Tufanbeyli",
+            "Yumurtalık",
+            "Yüreğir",
+        ],
+    },
+    Province {
+        plate_code: 2,
+        name: "Adıyaman",
+        districts: &[
+            "Besni",
+            "Çelikhan",
+            "Gerger",
+            "Gölbaşı",
+            "Kahta",
+            "Merkez",
+            "Samsat",
+            "Sincik",
+            "Tut",
+        ],
+    },
+    Province {
+        plate_code: 3,
+        name: "Afyonkarahisar",
+        districts: &[
+            "Başmakçı",
+            "Bayat",
+            "Bolvadin",
+            "Çay",
+            "Çobanlar",
+            "Dazkırı",
+            "Dinar",
+            "Emirdağ",
+            "Evciler",
+            "Hocalar",
+            "İhsaniye",
+            "İscehisar",
+            "Kızılören",
+            "Merkez",
+            "Sandıklı",
+            "Sinanpaşa",
+            "Sultandağı",
+            "Şuhut",
+        ],
+    },
+    Province {
+        plate_code: 4,
+        name: "Ağrı",
+        districts: &[
+            "Diyadin",
+            "Doğubayazıt",
+            "Eleşkirt",
+            "Hamur",
+            "Merkez",
+            "Patnos",
+            "Taşlıçay",
+            "Tutak",
+        ],
+    },
+    Province {
+        plate_code: 5,
+        name: "Amasya",
+        districts: &[
+            "Göynücek",
+            "Gümüşhacıköy",
+            "Hamamözü",
+            "Merkez",
+            "Merzifon",
+            "Suluova",
+            "Taşova",
+        ],
+    },
+    Province {
+        plate_code: 6,
+        name: "Ankara",
+        districts: &[
+            "Akyurt",
+            "Altındağ",
+            "Ayaş",
+            "Bala",
+            "Beypazarı",
+            "Çamlıdere",
+            "Çankaya",
+            "Çubuk",
+            "Elmadağ",
+            "Etimesgut",
+            "Evren",
+            "Gölbaşı",
+            "Güdül",
+            "Haymana",
+            "Kahramankazan",
+            "Kalecik",
+            "Keçiören",
+            "Kızılcahamam",
+            "Mamak",
+            "Nallıhan",
+            "Polatlı",
+            "Pursaklar",
+            "Sincan",
+            "Şereflikoçhisar",
+            "Yenimahalle",
+        ],
+    },
+    Province {
+        plate_code: 7,
+        name: "Antalya",
+        districts: &[
+            "Akseki",
+            "Aksu",
+            "Alanya",
+            "Demre",
+            "Döşemealtı",
+            "Elmalı",
+            "Finike",
+            "Gazipaşa",
+            "Gündoğmuş",
+            "İbradı",
+            "Kaş",
+            "Kemer",
+            "Kepez",
+            "Konyaaltı",
+            "Korkuteli",
+            "Kumluca",
+            "Manavgat",
+            "Muratpaşa",
+            "Serik",
+        ],
+    },
+    Province {
+        plate_code: 8,
+        name: "Artvin",
+        districts: &[
+            "Ardanuç",
+            "Arhavi",
+            "Borçka",
+            "Hopa",
+            "Kemalpaşa",
+            "Merkez",
+            "Murgul",
+            "Şavşat",
+            "Yusufeli",
+        ],
+    },
+    Province {
+        plate_code: 9,
+        name: "Aydın",
+        districts: &[
+            "Bozdoğan",
+            "Buharkent",
+            "Çine",
+            "Didim",
+            "Efeler",
+            "Germencik",
+            "İncirliova",
+            "Karacasu",
+            "Karpuzlu",
+            "Koçarlı",
+            "Köşk",
+            "Kuşadası",
+            "Kuyucak",
+            "Nazilli",
+            "Söke",
+            "Sultanhisar",
+            "Yenipazar",
+        ],
+    },
+    Province {
+        plate_code: 10,
+        name: "Balıkesir",
+        districts: &[
+            "Altıeylül",
+            "Ayvalık",
+            "Balya",
+            "Bandırma",
+            "Bigadiç",
+            "Burhaniye",
+            "Dursunbey",
+            "Edremit",
+            "Erdek",
+            "Gömeç",
+            "Gönen",
+            "Havran",
+            "İvrindi",
+            "Karesi",
+            "Kepsut",
+            "Manyas",
+            "Marmara",
+            "Savaştepe",
+            "Sındırgı",
+            "Susurluk",
+        ],
+    },
+    Province {
+        plate_code: 11,
+        name: "Bilecik",
+        districts: &[
+            "Bozüyük",
+            "Gölpazarı",
+            "İnhisar",
+            "Merkez",
+            "Osmaneli",
+            "Pazaryeri",
+            "Söğüt",
+            "Yenipazar",
+        ],
+    },
+    Province {
+        plate_code: 12,
+        name: "Bingöl",
+        districts: &[
+            "Adaklı",
+            "Genç",
+            "Karlıova",
+            "Kiğı",
+            "Merkez",
+            "Solhan",
+            "Yayladere",
+            "Yedisu",
+        ],
+    },
+    Province {
+        plate_code: 13,
+        name: "Bitlis",
+        districts: &[
+            "Adilcevaz",
+            "Ahlat",
+            "Güroymak",
+            "Hizan",
+            "Merkez",
+            "Mutki",
+            "Tatvan",
+        ],
+    },
+    Province {
+        plate_code: 14,
+        name: "Bolu",
+        districts: &[
+            "Dörtdivan",
+            "Gerede",
+            "Göynük",
+            "Kıbrıscık",
+            "Mengen",
+            "Merkez",
+            "Mudurnu",
+            "Seben",
+            "Yeniçağa",
+        ],
+    },
+    Province {
+        plate_code: 15,
+        name: "Burdur",
+        districts: &[
+            "Ağlasun",
+            "Altınyayla",
+            "Bucak",
+            "Çavdır",
+            "Çeltikçi",
+            "Gölhisar",
+            "Karamanlı",
+            "Kemer",
+            "Merkez",
+            "Tefenni",
+            "Yeşilova",
+        ],
+    },
+    Province {
+        plate_code: 16,
+        name: "Bursa",
+        districts: &[
+            "Büyükorhan",
+            "Gemlik",
+            "Gürsu",
+            "Harmancık",
+            "İnegöl",
+            "İznik",
+            "Karacabey",
+            "Keles",
+            "Kestel",
+            "Mudanya",
+            "Mustafakemalpaşa",
+            "Nilüfer",
+            "Orhaneli",
+            "Orhangazi",
+            "Osmangazi",
+            "Yenişehir",
+            "Yıldırım",
+        ],
+    },
+    Province {
+        plate_code: 17,
+        name: "Çanakkale",
+        districts: &[
+            "Ayvacık",
+            "Bayramiç",
+            "Biga",
+            "Bozcaada",
+            "Çan",
+            "Eceabat",
+            "Ezine",
+            "Gelibolu",
+            "Gökçeada",
+            "Lapseki",
+            "Merkez",
+            "Yenice",
+        ],
+    },
+    Province {
+        plate_code: 18,
+        name: "Çankırı",
+        districts: &[
+            "Atkaracalar",
+            "Bayramören",
+            "Çerkeş",
+            "Eldivan",
+            "Ilgaz",
+            "Kızılırmak",
+            "Korgun",
+            "Kurşunlu",
+            "Merkez",
+            "Orta",
+            "Şabanözü",
+            "Yapraklı",
+        ],
+    },
+    Province {
+        plate_code: 19,
+        name: "Çorum",
+        districts: &[
+            "Alaca",
+            "Bayat",
+            "Boğazkale",
+            "Dodurga",
+            "İskilip",
+            "Kargı",
+            "Laçin",
+            "Mecitözü",
+            "Merkez",
+            "Oğuzlar",
+            "Ortaköy",
+            "Osmancık",
+            "Sungurlu",
+            "Uğurludağ",
+        ],
+    },
+    Province {
+        plate_code: 20,
+        name: "Denizli",
+        districts: &[
+            "Acıpayam",
+            "Babadağ",
+            "Baklan",
+            "Bekilli",
+            "Beyağaç",
+            "Bozkurt",
+            "Buldan",
+            "Çal",
+            "Çameli",
+            "Çardak",
+            "Çivril",
+            "Güney",
+            "Honaz",
+            "Kale",
+            "Merkezefendi",
+            "Pamukkale",
+            "Sarayköy",
+            "Serinhisar",
+            "Tavas",
+        ],
+    },
+    Province {
+        plate_code: 21,
+        name: "Diyarbakır",
+        districts: &[
+            "Bağlar",
+            "Bismil",
+            "Çermik",
+            "Çınar",
+            "Çüngüş",
+            "Dicle",
+            "Eğil",
+            "Ergani",
+            "Hani",
+            "Hazro",
+            "Kayapınar",
+            "Kocaköy",
+            "Kulp",
+            "Lice",
+            "Silvan",
+            "Sur",
+            "Yenişehir",
+        ],
+    },
+    Province {
+        plate_code: 22,
+        name: "Edirne",
+        districts: &[
+            "Enez",
+            "Havsa",
+            "İpsala",
+            "Keşan",
+            "Lalapaşa",
+            "Meriç",
+            "Merkez",
+            "Süloğlu",
+            "Uzunköprü",
+        ],
+    },
+    Province {
+        plate_code: 23,
+        name: "Elazığ",
+        districts: &[
+            "Ağın",
+            "Alacakaya",
+            "Arıcak",
+            "Baskil",
+            "Karakoçan",
+            "Keban",
+            "Kovancılar",
+            "Maden",
+            "Merkez",
+            "Palu",
+            "Sivrice",
+        ],
+    },
+    Province {
+        plate_code: 24,
+        name: "Erzincan",
+        districts: &[
+            "Çayırlı",
+            "İliç",
+            "Kemah",
+            "Kemaliye",
+            "Merkez",
+            "Otlukbeli",
+            "Refahiye",
+            "Tercan",
+            "Üzümlü",
+        ],
+    },
+    Province {
+        plate_code: 25,
+        name: "Erzurum",
+        districts: &[
+            "Aşkale",
+            "Aziziye",
+            "Çat",
+            "Hınıs",
+            "Horasan",
+            "İspir",
+            "Karaçoban",
+            "Karayazı",
+            "Köprüköy",
+            "Narman",
+            "Oltu",
+            "Olur",
+            "Palandöken",
+            "Pasinler",
+            "Pazaryolu",
+            "Şenkaya",
+            "Tekman",
+            "Tortum",
+            "Uzundere",
+            "Yakutiye",
+        ],
+    },
+    Province {
+        plate_code: 26,
+        name: "Eskişehir",
+        districts: &[
+            "Alpu",
+            "Beylikova",
+            "Çifteler",
+            "Günyüzü",
+            "Han",
+            "İnönü",
+            "Mahmudiye",
+            "Mihalgazi",
+            "Mihalıççık",
+            "Odunpazarı",
+            "Sarıcakaya",
+            "Seyitgazi",
+            "Sivrihisar",
+            "Tepebaşı",
+        ],
+    },
+    Province {
+        plate_code: 27,
+        name: "Gaziantep",
+        districts: &[
+            "Araban",
+            "İslahiye",
+            "Karkamış",
+            "Nizip",
+            "Nurdağı",
+            "Oğuzeli",
+            "Şahinbey",
+            "Şehitkamil",
+            "Yavuzeli",
+        ],
+    },
+    Province {
+        plate_code: 28,
+        name: "Giresun",
+        districts: &[
+            "Alucra",
+            "Bulancak",
+            "Çamoluk",
+            "Çanakçı",
+            "Dereli",
+            "Doğankent",
+            "Espiye",
+            "Eynesil",
+            "Görele",
+            "Güce",
+            "Keşap",
+            "Merkez",
+            "Piraziz",
+            "Şebinkarahisar",
+            "Tirebolu",
+            "Yağlıdere",
+        ],
+    },
+    Province {
+        plate_code: 29,
+        name: "Gümüşhane",
+        districts: &["Kelkit", "Köse", "Kürtün", "Merkez", "Şiran", "Torul"],
+    },
+    Province {
+        plate_code: 30,
+        name: "Hakkari",
+        districts: &["Çukurca", "Derecik", "Merkez", "Şemdinli", "Yüksekova"],
+    },
+    Province {
+        plate_code: 31,
+        name: "Hatay",
+        districts: &[
+            "Altınözü",
+            "Antakya",
+            "Arsuz",
+            "Belen",
+            "Defne",
+            "Dörtyol",
+            "Erzin",
+            "Hassa",
+            "İskenderun",
+            "Kırıkhan",
+            "Kumlu",
+            "Payas",
+            "Reyhanlı",
+            "Samandağ",
+            "Yayladağı",
+        ],
+    },
+    Province {
+        plate_code: 32,
+        name: "Isparta",
+        districts: &[
+            "Aksu",
+            "Atabey",
+            "Eğirdir",
+            "Gelendost",
+            "Gönen",
+            "Keçiborlu",
+            "Merkez",
+            "Senirkent",
+            "Sütçüler",
+            "Şarkikaraağaç",
+            "Uluborlu",
+            "Yalvaç",
+            "Yenişarbademli",
+        ],
+    },
+    Province {
+        plate_code: 33,
+        name: "Mersin",
+        districts: &[
+            "Akdeniz",
+            "Anamur",
+            "Aydıncık",
+            "Bozyazı",
+            "Çamlıyayla",
+            "Erdemli",
+            "Gülnar",
+            "Mezitli",
+            "Mut",
+            "Silifke",
+            "Tarsus",
+            "Toroslar",
+            "Yenişehir",
+        ],
+    },
+    Province {
+        plate_code: 34,
+        name: "İstanbul",
+        districts: &[
+            "Adalar",
+            "Arnavutköy",
+            "Ataşehir",
+            "Avcılar",
+            "Bağcılar",
+            "Bahçelievler",
+            "Bakırköy",
+            "Başakşehir",
+            "Bayrampaşa",
+            "Beşiktaş",
+            "Beykoz",
+            "Beylikdüzü",
+            "Beyoğlu",
+            "Büyükçekmece",
+            "Çatalca",
+            "Çekmeköy",
+            "Esenler",
+            "Esenyurt",
+            "Eyüpsultan",
+            "Fatih",
+            "Gaziosmanpaşa",
+            "Güngören",
+            "Kadıköy",
+            "Kağıthane",
+            "Kartal",
+            "Küçükçekmece",
+            "Maltepe",
+            "Pendik",
+            "Sancaktepe",
+            "Sarıyer",
+            "Silivri",
+            "Sultanbeyli",
+            "Sultangazi",
+            "Şile",
+            "Şişli",
+            "Tuzla",
+            "Ümraniye",
+            "Üsküdar",
+            "Zeytinburnu",
+        ],
+    },
+    Province {
+        plate_code: 35,
+        name: "İzmir",
+        districts: &[
+            "Aliağa",
+            "Balçova",
+            "Bayındır",
+            "Bayraklı",
+            "Bergama",
+            "Beydağ",
+            "Bornova",
+            "Buca",
+            "Çeşme",
+            "Çiğli",
+            "Dikili",
+            "Foça",
+            "Gaziemir",
+            "Güzelbahçe",
+            "Karabağlar",
+            "Karaburun",
+            "Karşıyaka",
+            "Kemalpaşa",
+            "Kınık",
+            "Kiraz",
+            "Konak",
+            "Menderes",
+            "Menemen",
+            "Narlıdere",
+            "Ödemiş",
+            "Seferihisar",
+            "Selçuk",
+            "Tire",
+            "Torbalı",
+            "Urla",
+        ],
+    },
+    Province {
+        plate_code: 36,
+        name: "Kars",
+        districts: &[
+            "Akyaka",
+            "Arpaçay",
+            "Digor",
+            "Kağızman",
+            "Merkez",
+            "Sarıkamış",
+            "Selim",
+            "Susuz",
+        ],
+    },
+    Province {
+        plate_code: 37,
+        name: "Kastamonu",
+        districts: &[
+            "Abana",
+            "Ağlı",
+            "Araç",
+            "Azdavay",
+            "Bozkurt",
+            "Cide",
+            "Çatalzeytin",
+            "Daday",
+            "Devrekani",
+            "Doğanyurt",
+            "Hanönü",
+            "İhsangazi",
+            "İnebolu",
+            "Küre",
+            "Merkez",
+            "Pınarbaşı",
+            "Seydiler",
+            "Şenpazar",
+            "Taşköprü",
+            "Tosya",
+        ],
+    },
+    Province {
+        plate_code: 38,
+        name: "Kayseri",
+        districts: &[
+            "Akkışla",
+            "Bünyan",
+            "Develi",
+            "Felahiye",
+            "Hacılar",
+            "İncesu",
+            "Kocasinan",
+            "Melikgazi",
+            "Özvatan",
+            "Pınarbaşı",
+            "Sarıoğlan",
+            "Sarız",
+            "Talas",
+            "Tomarza",
+            "Yahyalı",
+            "Yeşilhisar",
+        ],
+    },
+    Province {
+        plate_code: 39,
+        name: "Kırklareli",
+        districts: &[
+            "Babaeski",
+            "Demirköy",
+            "Kofçaz",
+            "Lüleburgaz",
+            "Merkez",
+            "Pehlivanköy",
+            "Pınarhisar",
+            "Vize",
+        ],
+    },
+    Province {
+        plate_code: 40,
+        name: "Kırşehir",
+        districts: &[
+            "Akçakent",
+            "Akpınar",
+            "Boztepe",
+            "Çiçekdağı",
+            "Kaman",
+            "Merkez",
+            "Mucur",
+        ],
+    },
+    Province {
+        plate_code: 41,
+        name: "Kocaeli",
+        districts: &[
+            "Başiskele",
+            "Çayırova",
+            "Darıca",
+            "Derince",
+            "Dilovası",
+            "Gebze",
+            "Gölcük",
+            "İzmit",
+            "Kandıra",
+            "Karamürsel",
+            "Kartepe",
+            "Körfez",
+        ],
+    },
+    Province {
+        plate_code: 42,
+        name: "Konya",
+        districts: &[
+            "Ahırlı",
+            "Akören",
+            "Akşehir",
+            "Altınekin",
+            "Beyşehir",
+            "Bozkır",
+            "Cihanbeyli",
+            "Çeltik",
+            "Çumra",
+            "Derbent",
+            "Derebucak",
+            "Doğanhisar",
+            "Emirgazi",
+            "Ereğli",
+            "Güneysınır",
+            "Hadim",
+            "Halkapınar",
+            "Hüyük",
+            "Ilgın",
+            "Kadınhanı",
+            "Karapınar",
+            "Karatay",
+            "Kulu",
+            "Meram",
+            "Sarayönü",
+            "Selçuklu",
+            "Seydişehir",
+            "Taşkent",
+            "Tuzlukçu",
+            "Yalıhüyük",
+            "Yunak",
+        ],
+    },
+    Province {
+        plate_code: 43,
+        name: "Kütahya",
+        districts: &[
+            "Altıntaş",
+            "Aslanapa",
+            "Çavdarhisar",
+            "Domaniç",
+            "Dumlupınar",
+            "Emet",
+            "Gediz",
+            "Hisarcık",
+            "Merkez",
+            "Pazarlar",
+            "Simav",
+            "Şaphane",
+            "Tavşanlı",
+        ],
+    },
+    Province {
+        plate_code: 44,
+        name: "Malatya",
+        districts: &[
+            "Akçadağ",
+            "Arapgir",
+            "Arguvan",
+            "Battalgazi",
+            "Darende",
+            "Doğanşehir",
+            "Doğanyol",
+            "Hekimhan",
+            "Kale",
+            "Kuluncak",
+            "Pütürge",
+            "Yazıhan",
+            "Yeşilyurt",
+        ],
+    },
+    Province {
+        plate_code: 45,
+        name: "Manisa",
+        districts: &[
+            "Ahmetli",
+            "Akhisar",
+            "Alaşehir",
+            "Demirci",
+            "Gölmarmara",
+            "Gördes",
+            "Kırkağaç",
+            "Köprübaşı",
+            "Kula",
+            "Salihli",
+            "Sarıgöl",
+            "Saruhanlı",
+            "Selendi",
+            "Soma",
+            "Şehzadeler",
+            "Turgutlu",
+            "Yunusemre",
+        ],
+    },
+    Province {
+        plate_code: 46,
+        name: "Kahramanmaraş",
+        districts: &[
+            "Afşin",
+            "Andırın",
+            "Çağlayancerit",
+            "Dulkadiroğlu",
+            "Ekinözü",
+            "Elbistan",
+            "Göksun",
+            "Nurhak",
+            "Onikişubat",
+            "Pazarcık",
+            "Türkoğlu",
+        ],
+    },
+    Province {
+        plate_code: 47,
+        name: "Mardin",
+        districts: &[
+            "Artuklu",
+            "Dargeçit",
+            "Derik",
+            "Kızıltepe",
+            "Mazıdağı",
+            "Midyat",
+            "Nusaybin",
+            "Ömerli",
+            "Savur",
+            "Yeşilli",
+        ],
+    },
+    Province {
+        plate_code: 48,
+        name: "Muğla",
+        districts: &[
+            "Bodrum",
+            "Dalaman",
+            "Datça",
+            "Fethiye",
+            "Kavaklıdere",
+            "Köyceğiz",
+            "Marmaris",
+            "Menteşe",
+            "Milas",
+            "Ortaca",
+            "Seydikemer",
+            "Ula",
+            "Yatağan",
+        ],
+    },
+    Province {
+        plate_code: 49,
+        name: "Muş",
+        districts: &[
+            "Bulanık",
+            "Hasköy",
+            "Korkut",
+            "Malazgirt",
+            "Merkez",
+            "Varto",
+        ],
+    },
+    Province {
+        plate_code: 50,
+        name: "Nevşehir",
+        districts: &[
+            "Acıgöl",
+            "Avanos",
+            "Derinkuyu",
+            "Gülşehir",
+            "Hacıbektaş",
+            "Kozaklı",
+            "Merkez",
+            "Ürgüp",
+        ],
+    },
+    Province {
+        plate_code: 51,
+        name: "Niğde",
+        districts: &[
+            "Altunhisar",
+            "Bor",
+            "Çamardı",
+            "Çiftlik",
+            "Merkez",
+            "Ulukışla",
+        ],
+    },
+    Province {
+        plate_code: 52,
+        name: "Ordu",
+        districts: &[
+            "Akkuş",
+            "Altınordu",
+            "Aybastı",
+            "Çamaş",
+            "Çatalpınar",
+            "Çaybaşı",
+            "Fatsa",
+            "Gölköy",
+            "Gülyalı",
+            "Gürgentepe",
+            "İkizce",
+            "Kabadüz",
+            "Kabataş",
+            "Korgan",
+            "Kumru",
+            "Mesudiye",
+            "Perşembe",
+            "Ulubey",
+            "Ünye",
+        ],
+    },
+    Province {
+        plate_code: 53,
+        name: "Rize",
+        districts: &[
+            "Ardeşen",
+            "Çamlıhemşin",
+            "Çayeli",
+            "Derepazarı",
+            "Fındıklı",
+            "Güneysu",
+            "Hemşin",
+            "İkizdere",
+            "İyidere",
+            "Kalkandere",
+            "Merkez",
+            "Pazar",
+        ],
+    },
+    Province {
+        plate_code: 54,
+        name: "Sakarya",
+        districts: &[
+            "Adapazarı",
+            "Akyazı",
+            "Arifiye",
+            "Erenler",
+            "Ferizli",
+            "Geyve",
+            "Hendek",
+            "Karapürçek",
+            "Karasu",
+            "Kaynarca",
+            "Kocaali",
+            "Pamukova",
+            "Sapanca",
+            "Serdivan",
+            "Söğütlü",
+            "Taraklı",
+        ],
+    },
+    Province {
+        plate_code: 55,
+        name: "Samsun",
+        districts: &[
+            "19 Mayıs",
+            "Alaçam",
+            "Asarcık",
+            "Atakum",
+            "Ayvacık",
+            "Bafra",
+            "Canik",
+            "Çarşamba",
+            "Havza",
+            "İlkadım",
+            "Kavak",
+            "Ladik",
+            "Salıpazarı",
+            "Tekkeköy",
+            "Terme",
+            "Vezirköprü",
+            "Yakakent",
+        ],
+    },
+    Province {
+        plate_code: 56,
+        name: "Siirt",
+        districts: &[
+            "Baykan", "Eruh", "Kurtalan", "Merkez", "Pervari", "Şirvan", "Tillo",
+        ],
+    },
+    Province {
+        plate_code: 57,
+        name: "Sinop",
+        districts: &[
+            "Ayancık",
+            "Boyabat",
+            "Dikmen",
+            "Durağan",
+            "Erfelek",
+            "Gerze",
+            "Merkez",
+            "Saraydüzü",
+            "Türkeli",
+        ],
+    },
+    Province {
+        plate_code: 58,
+        name: "Sivas",
+        districts: &[
+            "Akıncılar",
+            "Altınyayla",
+            "Divriği",
+            "Doğanşar",
+            "Gemerek",
+            "Gölova",
+            "Gürün",
+            "Hafik",
+            "İmranlı",
+            "Kangal",
+            "Koyulhisar",
+            "Merkez",
+            "Suşehri",
+            "Şarkışla",
+            "Ulaş",
+            "Yıldızeli",
+            "Zara",
+        ],
+    },
+    Province {
+        plate_code: 59,
+        name: "Tekirdağ",
+        districts: &[
+            "Çerkezköy",
+            "Çorlu",
+            "Ergene",
+            "Hayrabolu",
+            "Kapaklı",
+            "Malkara",
+            "Marmaraereğlisi",
+            "Muratlı",
+            "Saray",
+            "Süleymanpaşa",
+            "Şarköy",
+        ],
+    },
+    Province {
+        plate_code: 60,
+        name: "Tokat",
+        districts: &[
+            "Almus",
+            "Artova",
+            "Başçiftlik",
+            "Erbaa",
+            "Merkez",
+            "Niksar",
+            "Pazar",
+            "Reşadiye",
+            "Sulusaray",
+            "Turhal",
+            "Yeşilyurt",
+            "Zile",
+        ],
+    },
+    Province {
+        plate_code: 61,
+        name: "Trabzon",
+        districts: &[
+            "Akçaabat",
+            "Araklı",
+            "Arsin",
+            "Beşikdüzü",
+            "Çarşıbaşı",
+            "Çaykara",
+            "Dernekpazarı",
+            "Düzköy",
+            "Hayrat",
+            "Köprübaşı",
+            "Maçka",
+            "Of",
+            "Ortahisar",
+            "Sürmene",
+            "Şalpazarı",
+            "Tonya",
+            "Vakfıkebir",
+            "Yomra",
+        ],
+    },
+    Province {
+        plate_code: 62,
+        name: "Tunceli",
+        districts: &[
+            "Çemişgezek",
+            "Hozat",
+            "Mazgirt",
+            "Merkez",
+            "Nazımiye",
+            "Ovacık",
+            "Pertek",
+            "Pülümür",
+        ],
+    },
+    Province {
+        plate_code: 63,
+        name: "Şanlıurfa",
+        districts: &[
+            "Akçakale",
+            "Birecik",
+            "Bozova",
+            "Ceylanpınar",
+            "Eyyübiye",
+            "Halfeti",
+            "Haliliye",
+            "Harran",
+            "Hilvan",
+            "Karaköprü",
+            "Siverek",
+            "Suruç",
+            "Viranşehir",
+        ],
+    },
+    Province {
+        plate_code: 64,
+        name: "Uşak",
+        districts: &["Banaz", "Eşme", "Karahallı", "Merkez", "Sivaslı", "Ulubey"],
+    },
+    Province {
+        plate_code: 65,
+        name: "Van",
+        districts: &[
+            "Bahçesaray",
+            "Başkale",
+            "Çaldıran",
+            "Çatak",
+            "Edremit",
+            "Erciş",
+            "Gevaş",
+            "Gürpınar",
+            "İpekyolu",
+            "Muradiye",
+            "Özalp",
+            "Saray",
+            "Tuşba",
+        ],
+    },
+    Province {
+        plate_code: 66,
+        name: "Yozgat",
+        districts: &[
+            "Akdağmadeni",
+            "Aydıncık",
+            "Boğazlıyan",
+            "Çandır",
+            "Çayıralan",
+            "Çekerek",
+            "Kadışehri",
+            "Merkez",
+            "Saraykent",
+            "Sarıkaya",
+            "Sorgun",
+            "Şefaatli",
+            "Yenifakılı",
+            "Yerköy",
+        ],
+    },
+    Province {
+        plate_code: 67,
+        name: "Zonguldak",
+        districts: &[
+            "Alaplı",
+            "Çaycuma",
+            "Devrek",
+            "Ereğli",
+            "Gökçebey",
+            "Kilimli",
+            "Kozlu",
+            "Merkez",
+        ],
+    },
+    Province {
+        plate_code: 68,
+        name: "Aksaray",
+        districts: &[
+            "Ağaçören",
+            "Eskil",
+            "Gülağaç",
+            "Güzelyurt",
+            "Merkez",
+            "Ortaköy",
+            "Sarıyahşi",
+            "Sultanhanı",
+        ],
+    },
+    Province {
+        plate_code: 69,
+        name: "Bayburt",
+        districts: &["Aydıntepe", "Demirözü", "Merkez"],
+    },
+    Province {
+        plate_code: 70,
+        name: "Karaman",
+        districts: &[
+            "Ayrancı",
+            "Başyayla",
+            "Ermenek",
+            "Kazımkarabekir",
+            "Merkez",
+            "Sarıveliler",
+        ],
+    },
+    Province {
+        plate_code: 71,
+        name: "Kırıkkale",
+        districts: &[
+            "Bahşılı",
+            "Balışeyh",
+            "Çelebi",
+            "Delice",
+            "Karakeçili",
+            "Keskin",
+            "Merkez",
+            "Sulakyurt",
+            "Yahşihan",
+        ],
+    },
+    Province {
+        plate_code: 72,
+        name: "Batman",
+        districts: &["Beşiri", "Gercüş", "Hasankeyf", "Kozluk", "Merkez", "Sason"],
+    },
+    Province {
+        plate_code: 73,
+        name: "Şırnak",
+        districts: &[
+            "Beytüşşebap",
+            "Cizre",
+            "Güçlükonak",
+            "İdil",
+            "Merkez",
+            "Silopi",
+            "Uludere",
+        ],
+    },
+    Province {
+        plate_code: 74,
+        name: "Bartın",
+        districts: &["Amasra", "Kurucaşile", "Merkez", "Ulus"],
+    },
+    Province {
+        plate_code: 75,
+        name: "Ardahan",
+        districts: &["Çıldır", "Damal", "Göle", "Hanak", "Merkez", "Posof"],
+    },
+    Province {
+        plate_code: 76,
+        name: "Iğdır",
+        districts: &["Aralık", "Karakoyunlu", "Merkez", "Tuzluca"],
+    },
+    Province {
+        plate_code: 77,
+        name: "Yalova",
+        districts: &[
+            "Altınova",
+            "Armutlu",
+            "Çınarcık",
+            "Çiftlikköy",
+            "Merkez",
+            "Termal",
+        ],
+    },
+    Province {
+        plate_code: 78,
+        name: "Karabük",
+        districts: &[
+            "Eflani",
+            "Eskipazar",
+            "Merkez",
+            "Ovacık",
+            "Safranbolu",
+            "Yenice",
+        ],
+    },
+    Province {
+        plate_code: 79,
+        name: "Kilis",
+        districts: &["Elbeyli", "Merkez", "Musabeyli", "Polateli"],
+    },
+    Province {
+        plate_code: 80,
+        name: "Osmaniye",
+        districts: &[
+            "Bahçe",
+            "Düziçi",
+            "Hasanbeyli",
+            "Kadirli",
+            "Merkez",
+            "Sumbas",
+            "Toprakkale",
+        ],
+    },
+    Province {
+        plate_code: 81,
+        name: "Düzce",
+        districts: &[
+            "Akçakoca",
+            "Cumayeri",
+            "Çilimli",
+            "Gölyaka",
+            "Gümüşova",
+            "Kaynaşlı",
+            "Merkez",
+            "Yığılca",
+        ],
+    },
+];
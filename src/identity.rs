@@ -0,0 +1,73 @@
+//! Buyer identity number validation.
+//!
+//! Turkish citizens are identified by their TC Kimlik number, foreign buyers by their
+//! passport number. [`validator_for`] picks the [`IdentityValidator`] matching a
+//! buyer's citizenship, and custom validators can be plugged in by implementing the
+//! trait.
+
+use crate::error::{Result, TapsilatError};
+use crate::geo;
+use crate::modules::validators::Validators;
+
+/// Checks the identity number of a buyer
+pub trait IdentityValidator: Send + Sync {
+    /// Validates `identity_number`, returning a validation error if it is malformed
+    fn validate(&self, identity_number: &str) -> Result<()>;
+}
+
+impl<F> IdentityValidator for F
+where
+    F: Fn(&str) -> Result<()> + Send + Sync,
+{
+    fn validate(&self, identity_number: &str) -> Result<()> {
+        self(identity_number)
+    }
+}
+
+/// Turkish identity numbers (TC Kimlik No), see [`Validators::validate_identity_number`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TcKimlikValidator;
+
+impl IdentityValidator for TcKimlikValidator {
+    fn validate(&self, identity_number: &str) -> Result<()> {
+        Validators::validate_identity_number(identity_number)
+    }
+}
+
+/// Passport numbers: 6 to 9 letters and digits, as printed in ICAO 9303 travel documents
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PassportValidator;
+
+impl IdentityValidator for PassportValidator {
+    fn validate(&self, identity_number: &str) -> Result<()> {
+        let number = identity_number.trim();
+        if !(6..=9).contains(&number.len()) || !number.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(TapsilatError::ValidationError(
+                "Passport number must be 6 to 9 letters and digits".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Returns the validator for a buyer's citizenship
+///
+/// Buyers without a citizenship or with Turkish citizenship (`TR`, `Turkey`,
+/// `Türkiye`) are checked as TC Kimlik numbers, everyone else as passport numbers.
+///
+/// # Example
+///
+/// ```rust
+/// use tapsilat::identity::validator_for;
+///
+/// assert!(validator_for(Some("DE")).validate("C01X00T47").is_ok());
+/// assert!(validator_for(Some("TR")).validate("C01X00T47").is_err());
+/// ```
+pub fn validator_for(citizenship: Option<&str>) -> &'static dyn IdentityValidator {
+    match citizenship {
+        Some(country) if !country.trim().is_empty() && !geo::is_turkey(country) => {
+            &PassportValidator
+        }
+        _ => &TcKimlikValidator,
+    }
+}
@@ -0,0 +1,54 @@
+//! Helpers for generating identifiers that correlate orders with your own records.
+
+use crate::error::Result;
+use crate::modules::Validators;
+use uuid::Uuid;
+
+/// Generates a time-sortable conversation ID such as `order_01890a5d7c8e7b4c9f3a2d1e0b6c5a49`.
+///
+/// The suffix is a UUIDv7 in simple (unhyphenated) form, so IDs generated later sort
+/// after earlier ones and are safe to use as database keys. An empty `prefix` yields
+/// the bare UUID.
+///
+/// # Errors
+///
+/// Returns [`TapsilatError::ValidationError`](crate::TapsilatError::ValidationError) if
+/// the prefix would make the ID invalid according to
+/// [`Validators::validate_conversation_id`].
+///
+/// # Example
+///
+/// ```rust
+/// use tapsilat::ids::generate_conversation_id;
+///
+/// let id = generate_conversation_id("order").unwrap();
+/// assert!(id.starts_with("order_"));
+/// ```
+pub fn generate_conversation_id(prefix: &str) -> Result<String> {
+    let suffix = Uuid::now_v7().simple().to_string();
+    let id = if prefix.is_empty() {
+        suffix
+    } else {
+        format!("{}_{}", prefix, suffix)
+    };
+
+    Validators::validate_conversation_id(&id)?;
+    Ok(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversation_id_generation() {
+        let first = generate_conversation_id("order").unwrap();
+        let second = generate_conversation_id("order").unwrap();
+
+        assert!(Validators::validate_conversation_id(&first).is_ok());
+        assert_eq!(first.len(), "order_".len() + 32);
+        assert!(first < second);
+        assert_eq!(generate_conversation_id("").unwrap().len(), 32);
+        assert!(generate_conversation_id("bad prefix").is_err());
+    }
+}
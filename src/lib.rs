@@ -11,7 +11,7 @@
 //! ## Quick Start
 //!
 //! ```rust,no_run
-//! use tapsilat::{Config, TapsilatClient, CreateOrderRequest, Currency};
+//! use tapsilat::{Config, TapsilatClient, CreateOrderRequest, Currency, ThreeDSPreference};
 //!
 //! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 //! // Initialize the client
@@ -30,7 +30,7 @@
 //!         name: "John".to_string(),
 //!         surname: "Doe".to_string(),
 //!         email: Some("john@example.com".to_string()),
-//!         gsm_number: None, identity_number: None, registration_address: None, ip: None, city: None, country: None, zip_code: None
+//!         gsm_number: None, identity_number: None, registration_address: None, ip: None, city: None, country: None, zip_code: None, citizenship: None
 //!     },
 //!     basket_items: None,
 //!     billing_address: None,
@@ -53,9 +53,11 @@
 //!     sub_organization: None,
 //!     submerchants: None,
 //!     tax_amount: None,
-//!     three_d_force: None,
+//!     three_ds: ThreeDSPreference::Auto,
 //!     metadata: None,
 //!     consents: None,
+//!     checkout_expires_in: None,
+//!     invoice: None,
 //! };
 //!
 //! let order_response = client.create_order(order_request)?;
@@ -71,31 +73,69 @@
 //! - **Validation**: Built-in validators for Turkish phone numbers, emails, and identity numbers
 //! - **Webhook Support**: Cryptographic webhook verification
 //! - **Installments**: Support for installment plan creation and management
+//! - **WebAssembly**: A `fetch`-based client in the `wasm` module, behind the `wasm` feature
+//! - **Tracing**: OpenTelemetry client spans and context propagation, behind the `otel` feature
+//! - **Streams**: `Stream` over all subscriptions with lazy page fetching, behind the `async` feature
 //!
 //! ## Module Organization
 //!
 //! - [`client`] - Core HTTP client and API methods
 //! - [`config`] - Configuration management
 //! - [`error`] - Error types and handling
+//! - [`geo`] - Turkish province and district reference data
+//! - [`identity`] - Buyer identity number validation by citizenship
 //! - [`types`] - Data types for API requests and responses
 //! - [`modules`] - Modular API interfaces (orders, payments, webhooks, etc.)
 
+#[cfg(feature = "http")]
+mod cache;
+#[cfg(feature = "http")]
 pub mod client;
 pub mod config;
 pub mod error;
+pub mod geo;
+pub mod identity;
+pub mod ids;
+pub mod masking;
+pub mod messages;
 pub mod modules;
+pub mod money;
+pub mod reconcile;
+pub mod secret;
+#[cfg(feature = "http")]
+mod single_flight;
+pub mod tax;
+#[cfg(feature = "otel")]
+mod telemetry;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod types;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
-pub use client::TapsilatClient;
-pub use config::Config;
-pub use error::{Result, TapsilatError};
-pub use modules::{InstallmentModule, OrderModule, PaymentModule, Validators, WebhookModule};
+#[cfg(feature = "http")]
+pub use client::{Method, TapsilatClient};
+pub use config::{
+    ClientCertificate, Config, MetricsSink, ParseWarning, ParseWarnings, RequestOptions,
+    TraceIdProvider,
+};
+pub use error::{ErrorCode, FieldError, Result, TapsilatError};
+#[cfg(feature = "http")]
+pub use modules::{
+    AccountModule, BuyerModule, CardModule, ComplianceModule, CouponModule, EventModule, FxModule,
+    InstallmentModule, InvoiceModule, KeyModule, OnboardingModule, OrderModule, PaymentModule,
+    ReportModule,
+};
+pub use modules::{PhoneValidation, Validators, WebhookModule};
+pub use secret::SecretString;
 pub use types::*;
 
 // Re-export installment types for convenience
+#[cfg(feature = "http")]
 pub use modules::installments::{
-    CreateInstallmentPlanRequest, Installment, InstallmentPlan, InstallmentStatus,
-    RefundInstallmentRequest, UpdateInstallmentRequest,
+    CreateInstallmentPlanRequest, Installment, InstallmentPlan, InstallmentPlanSummary,
+    InstallmentScheduleRow, InstallmentStatus, OverdueFilter, OverdueInstallment, PayoffQuote,
+    RefundInstallmentRequest, RescheduleRequest, RescheduledInstallment, UpdateInstallmentRequest,
 };
 
 #[cfg(test)]
@@ -103,6 +143,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[cfg(feature = "http")]
     fn test_client_creation() {
         let config = Config::new("test-api-key");
         let client = TapsilatClient::new(config);
@@ -0,0 +1,252 @@
+//! Masking helpers for card numbers, IBANs and other sensitive values.
+//!
+//! The SDK never receives full card numbers from the API, but integrators often hold
+//! them in their own request and log types. [`mask_card_number`] and [`mask_iban`]
+//! produce display-safe forms, [`Redacted`] keeps a value out of `Debug` output
+//! altogether and [`redact_json`] prepares request and response bodies for logging.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+
+/// Fields holding a card number, masked with [`mask_card_number`]
+const CARD_NUMBER_FIELDS: &[&str] = &["card_number", "pan"];
+
+/// Fields replaced completely when a body is logged
+const REDACTED_FIELDS: &[&str] = &[
+    "holder_name",
+    "card_holder_name",
+    "cvv",
+    "cvc",
+    "security_code",
+    "card_token",
+    "apple_pay_token",
+    "google_pay_token",
+    "token",
+];
+
+/// Masks a card number (PAN), keeping the first six and last four digits
+///
+/// Spaces and dashes are ignored. Numbers shorter than 12 digits only keep their
+/// last four digits, and anything of four digits or fewer is masked completely.
+///
+/// # Example
+///
+/// ```rust
+/// use tapsilat::masking::mask_card_number;
+///
+/// assert_eq!(mask_card_number("4543 6000 1234 4242"), "454360******4242");
+/// ```
+pub fn mask_card_number(card_number: &str) -> String {
+    let digits: Vec<char> = card_number.chars().filter(char::is_ascii_digit).collect();
+    let len = digits.len();
+    let (head, tail) = match len {
+        0..=4 => (0, 0),
+        5..=11 => (0, 4),
+        _ => (6, 4),
+    };
+
+    digits
+        .iter()
+        .enumerate()
+        .map(|(index, digit)| {
+            if index < head || index >= len - tail {
+                *digit
+            } else {
+                '*'
+            }
+        })
+        .collect()
+}
+
+/// Masks an IBAN, keeping the country code, check digits and last four characters
+///
+/// Spaces are ignored and letters are upper-cased.
+///
+/// # Example
+///
+/// ```rust
+/// use tapsilat::masking::mask_iban;
+///
+/// assert_eq!(
+///     mask_iban("TR33 0006 1005 1978 6457 8413 26"),
+///     "TR33******************1326"
+/// );
+/// ```
+pub fn mask_iban(iban: &str) -> String {
+    let chars: Vec<char> = iban
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    if chars.len() <= 8 {
+        return "*".repeat(chars.len());
+    }
+
+    chars
+        .iter()
+        .enumerate()
+        .map(|(index, c)| {
+            if index < 4 || index >= chars.len() - 4 {
+                *c
+            } else {
+                '*'
+            }
+        })
+        .collect()
+}
+
+/// Returns a copy of a JSON body that is safe to write to logs
+///
+/// Card numbers and IBANs are masked, while card holder names, security codes and
+/// card or wallet tokens are replaced by `<redacted>`, at any depth of the body.
+///
+/// # Example
+///
+/// ```rust
+/// use serde_json::json;
+/// use tapsilat::masking::redact_json;
+///
+/// let body = json!({ "card_number": "4543600012344242", "holder_name": "Ada Lovelace" });
+/// assert_eq!(
+///     redact_json(&body),
+///     json!({ "card_number": "454360******4242", "holder_name": "<redacted>" })
+/// );
+/// ```
+pub fn redact_json(value: &Value) -> Value {
+    match value {
+        Value::Object(fields) => Value::Object(
+            fields
+                .iter()
+                .map(|(key, field)| (key.clone(), redact_field(key, field)))
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(redact_json).collect()),
+        other => other.clone(),
+    }
+}
+
+fn redact_field(key: &str, value: &Value) -> Value {
+    let key = key.to_ascii_lowercase();
+    match value {
+        Value::Null => Value::Null,
+        Value::String(text) if CARD_NUMBER_FIELDS.contains(&key.as_str()) => {
+            Value::String(mask_card_number(text))
+        }
+        Value::String(text) if key == "iban" => Value::String(mask_iban(text)),
+        _ if CARD_NUMBER_FIELDS.contains(&key.as_str())
+            || key == "iban"
+            || REDACTED_FIELDS.contains(&key.as_str()) =>
+        {
+            Value::String("<redacted>".to_string())
+        }
+        other => redact_json(other),
+    }
+}
+
+/// Wrapper that hides a value from `Debug` output
+///
+/// Serialization is transparent, so a `Redacted` field is still sent to and read from
+/// the API unchanged; only `Debug` prints `<redacted>` instead of the value. Use it for
+/// fields of your own types that may carry card data.
+///
+/// # Example
+///
+/// ```rust
+/// use tapsilat::masking::Redacted;
+///
+/// let pan = Redacted::new("4543600012344242".to_string());
+/// assert_eq!(format!("{:?}", pan), "<redacted>");
+/// assert_eq!(serde_json::to_string(&pan).unwrap(), "\"4543600012344242\"");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Redacted<T>(T);
+
+impl<T> Redacted<T> {
+    /// Wraps a sensitive value.
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns a reference to the wrapped value.
+    pub fn expose(&self) -> &T {
+        &self.0
+    }
+
+    /// Unwraps the value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Redacted<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> fmt::Debug for Redacted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("<redacted>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_masking() {
+        assert_eq!(mask_card_number("4543-6000-1234-4242"), "454360******4242");
+        assert_eq!(mask_card_number("123456789"), "*****6789");
+        assert_eq!(mask_card_number("1234"), "****");
+        assert_eq!(
+            mask_iban("tr330006100519786457841326"),
+            "TR33******************1326"
+        );
+        assert_eq!(mask_iban("TR33"), "****");
+
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        struct Card {
+            number: Redacted<String>,
+        }
+        let card = Card {
+            number: "4543600012344242".to_string().into(),
+        };
+        assert!(!format!("{:?}", card).contains("4543"));
+    }
+
+    #[test]
+    fn test_redact_json() {
+        let body = serde_json::json!({
+            "card_number": "4543 6000 1234 4242",
+            "expiry_month": 12,
+            "holder_name": "Ada Lovelace",
+            "apple_pay_token": { "paymentData": "eyJ2ZXJzaW9uIjoi" },
+            "google_pay_token": "{\"signature\":\"MEUC\"}",
+            "accounts": [{ "iban": "TR330006100519786457841326", "currency": "TRY" }],
+            "reference_id": "ref_1",
+            "cvv": null
+        });
+
+        let redacted = redact_json(&body);
+        assert_eq!(redacted["card_number"], "454360******4242");
+        assert_eq!(redacted["expiry_month"], 12);
+        assert_eq!(redacted["holder_name"], "<redacted>");
+        assert_eq!(redacted["apple_pay_token"], "<redacted>");
+        assert_eq!(redacted["google_pay_token"], "<redacted>");
+        assert_eq!(
+            redacted["accounts"][0]["iban"],
+            "TR33******************1326"
+        );
+        assert_eq!(redacted["reference_id"], "ref_1");
+        assert!(redacted["cvv"].is_null());
+
+        let logged = redacted.to_string();
+        assert!(!logged.contains("1234"));
+        assert!(!logged.contains("Lovelace"));
+        assert!(!logged.contains("MEUC"));
+    }
+}
@@ -0,0 +1,88 @@
+//! Customer-safe error messages in Turkish and English.
+//!
+//! Messages are keyed by [`ErrorCode`] and say what the customer can do next; they
+//! never repeat details from the API, so they can be shown on a storefront as is.
+
+use crate::error::ErrorCode;
+use crate::types::Locale;
+
+/// Lookup of customer-facing messages for [`ErrorCode`]s
+pub struct ErrorMessages;
+
+impl ErrorMessages {
+    /// Returns the message for `code` in `locale`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tapsilat::messages::ErrorMessages;
+    /// use tapsilat::{ErrorCode, Locale};
+    ///
+    /// assert_eq!(
+    ///     ErrorMessages::message(ErrorCode::InsufficientFunds, Locale::En),
+    ///     "Your card has insufficient funds. Please try another card."
+    /// );
+    /// ```
+    pub fn message(code: ErrorCode, locale: Locale) -> &'static str {
+        match locale {
+            Locale::Tr => Self::turkish(code),
+            Locale::En => Self::english(code),
+        }
+    }
+
+    fn turkish(code: ErrorCode) -> &'static str {
+        match code {
+            ErrorCode::InvalidRequest => "Girdiğiniz bilgileri kontrol edip tekrar deneyin.",
+            ErrorCode::Duplicate => "Bu işlem daha önce gerçekleştirilmiş.",
+            ErrorCode::Unauthorized => {
+                "Ödeme şu anda alınamıyor. Lütfen daha sonra tekrar deneyin."
+            }
+            ErrorCode::NotFound => "İşlem bulunamadı.",
+            ErrorCode::RateLimited => {
+                "Çok fazla deneme yapıldı. Lütfen biraz bekleyip tekrar deneyin."
+            }
+            ErrorCode::CardDeclined => {
+                "Kartınız bankanız tarafından onaylanmadı. Lütfen başka bir kart deneyin."
+            }
+            ErrorCode::InsufficientFunds => {
+                "Kartınızın bakiyesi yetersiz. Lütfen başka bir kart deneyin."
+            }
+            ErrorCode::FraudSuspected => {
+                "Ödeme güvenlik nedeniyle onaylanmadı. Lütfen bankanızla iletişime geçin."
+            }
+            ErrorCode::Network => "Bağlantı hatası oluştu. Lütfen tekrar deneyin.",
+            ErrorCode::Timeout => "İşlem zaman aşımına uğradı. Lütfen tekrar deneyin.",
+            ErrorCode::ServiceUnavailable => {
+                "Ödeme servisi şu anda kullanılamıyor. Lütfen daha sonra tekrar deneyin."
+            }
+            ErrorCode::Unknown => "Beklenmeyen bir hata oluştu. Lütfen tekrar deneyin.",
+        }
+    }
+
+    fn english(code: ErrorCode) -> &'static str {
+        match code {
+            ErrorCode::InvalidRequest => "Please check the details you entered and try again.",
+            ErrorCode::Duplicate => "This payment has already been processed.",
+            ErrorCode::Unauthorized => {
+                "Payments cannot be taken right now. Please try again later."
+            }
+            ErrorCode::NotFound => "The payment could not be found.",
+            ErrorCode::RateLimited => "Too many attempts. Please wait a moment and try again.",
+            ErrorCode::CardDeclined => {
+                "Your card was declined by your bank. Please try another card."
+            }
+            ErrorCode::InsufficientFunds => {
+                "Your card has insufficient funds. Please try another card."
+            }
+            ErrorCode::FraudSuspected => {
+                "The payment was declined for security reasons. Please contact your bank."
+            }
+            ErrorCode::Network => "A connection error occurred. Please try again.",
+            ErrorCode::Timeout => "The request timed out. Please try again.",
+            ErrorCode::ServiceUnavailable => {
+                "The payment service is currently unavailable. Please try again later."
+            }
+            ErrorCode::Unknown => "An unexpected error occurred. Please try again.",
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::error::Result;
+use crate::modules::pagination::RowPage;
+use crate::modules::validators::Validators;
+use crate::types::{AccountBalance, AddBankAccountRequest, Capabilities, PayoutBankAccount};
+use std::sync::Arc;
+
+pub struct AccountModule {
+    client: Arc<crate::client::TapsilatClient>,
+}
+
+impl AccountModule {
+    pub fn new(client: Arc<crate::client::TapsilatClient>) -> Self {
+        Self { client }
+    }
+
+    /// Gets the available, pending and reserved balances of the account per currency
+    pub fn get_balance(&self) -> Result<AccountBalance> {
+        let response = self
+            .client
+            .make_request::<()>("GET", "account/balance", None)?;
+        self.client
+            .decode_response(response, "account balance response")
+    }
+
+    /// Gets the payment options, currencies, installment counts and features enabled for
+    /// the account, so checkout pages can offer only what the merchant supports
+    pub fn get_capabilities(&self) -> Result<Capabilities> {
+        let response = self
+            .client
+            .make_request::<()>("GET", "account/capabilities", None)?;
+        self.client
+            .decode_response(response, "capabilities response")
+    }
+
+    /// Lists the bank accounts settlements can be paid out to
+    pub fn list_bank_accounts(&self) -> Result<Vec<PayoutBankAccount>> {
+        let response = self
+            .client
+            .make_request::<()>("GET", "account/bank-accounts", None)?;
+        let rows = RowPage::from_value(&response).rows;
+        self.client
+            .parse_typed(serde_json::Value::Array(rows), "bank account list response")
+    }
+
+    /// Adds a payout bank account after validating its IBAN
+    pub fn add_bank_account(
+        &self,
+        mut request: AddBankAccountRequest,
+    ) -> Result<PayoutBankAccount> {
+        request.iban = Validators::validate_iban(&request.iban)?;
+        if request.account_holder.trim().is_empty() {
+            return Err(crate::error::TapsilatError::ValidationError(
+                "Account holder cannot be empty".to_string(),
+            ));
+        }
+
+        let response = self
+            .client
+            .make_request("POST", "account/bank-accounts", Some(&request))?;
+        self.client
+            .decode_response(response, "bank account response")
+    }
+
+    /// Removes a payout bank account
+    pub fn remove_bank_account(&self, account_id: &str) -> Result<serde_json::Value> {
+        let endpoint = format!("account/bank-accounts/{}", account_id);
+        self.client.make_request::<()>("DELETE", &endpoint, None)
+    }
+
+    /// Makes a bank account the default settlement account
+    pub fn set_default_bank_account(&self, account_id: &str) -> Result<PayoutBankAccount> {
+        let endpoint = format!("account/bank-accounts/{}/default", account_id);
+        let response = self.client.make_request::<()>("POST", &endpoint, None)?;
+        self.client
+            .decode_response(response, "bank account response")
+    }
+}
@@ -0,0 +1,60 @@
+//! Bounded-concurrency helpers shared by the batch operations of the API modules.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// Default number of worker threads used by batch operations.
+pub const DEFAULT_BATCH_CONCURRENCY: usize = 8;
+
+/// Runs `f` over every item using at most `concurrency` worker threads.
+///
+/// Results are returned in the same order as `items`.
+pub(crate) fn run_bounded<T, R, F>(items: &[T], concurrency: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let workers = concurrency.max(1).min(items.len());
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<R>>> = Mutex::new(items.iter().map(|_| None).collect());
+
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                if index >= items.len() {
+                    break;
+                }
+                let result = f(&items[index]);
+                results.lock().unwrap_or_else(|e| e.into_inner())[index] = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap_or_else(|e| e.into_inner())
+        .into_iter()
+        .map(|result| result.expect("every batch item is processed by a worker"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_bounded_preserves_order() {
+        let items: Vec<u32> = (0..50).collect();
+        let results = run_bounded(&items, 4, |n| n * 2);
+        assert_eq!(results, items.iter().map(|n| n * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_run_bounded_empty() {
+        let items: Vec<u32> = Vec::new();
+        let results = run_bounded(&items, 4, |n| *n);
+        assert!(results.is_empty());
+    }
+}
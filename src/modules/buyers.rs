@@ -0,0 +1,56 @@
+use crate::error::{Result, TapsilatError};
+use crate::types::{PaginatedResponse, PaymentHistoryEntry, PaymentHistoryFilter};
+use std::sync::Arc;
+
+pub struct BuyerModule {
+    client: Arc<crate::client::TapsilatClient>,
+}
+
+impl BuyerModule {
+    pub fn new(client: Arc<crate::client::TapsilatClient>) -> Self {
+        Self { client }
+    }
+
+    /// Lists the orders, payments and refunds of a buyer as one timeline, newest first
+    pub fn payment_history(
+        &self,
+        buyer_id: &str,
+        filter: &PaymentHistoryFilter,
+    ) -> Result<PaginatedResponse<PaymentHistoryEntry>> {
+        if buyer_id.trim().is_empty() {
+            return Err(TapsilatError::ValidationError(
+                "Buyer ID cannot be empty".to_string(),
+            ));
+        }
+
+        let mut query_params = Vec::new();
+        if let Some(kind) = &filter.kind {
+            let kind = serde_json::to_value(kind)?;
+            if let Some(kind) = kind.as_str() {
+                query_params.push(format!("type={}", kind));
+            }
+        }
+        if let Some(start_date) = &filter.start_date {
+            query_params.push(format!("start_date={}", start_date));
+        }
+        if let Some(end_date) = &filter.end_date {
+            query_params.push(format!("end_date={}", end_date));
+        }
+        if let Some(page) = filter.page {
+            query_params.push(format!("page={}", page));
+        }
+        if let Some(per_page) = filter.per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
+
+        let mut endpoint = format!("buyer/{}/payment-history", buyer_id);
+        if !query_params.is_empty() {
+            endpoint.push('?');
+            endpoint.push_str(&query_params.join("&"));
+        }
+
+        let response = self.client.make_request::<()>("GET", &endpoint, None)?;
+        self.client
+            .decode_response(response, "payment history response")
+    }
+}
@@ -0,0 +1,33 @@
+use crate::error::{Result, TapsilatError};
+use crate::modules::validators::Validators;
+use crate::types::{CardDetails, CardToken};
+use std::sync::Arc;
+
+pub struct CardModule {
+    client: Arc<crate::client::TapsilatClient>,
+}
+
+impl CardModule {
+    pub fn new(client: Arc<crate::client::TapsilatClient>) -> Self {
+        Self { client }
+    }
+
+    /// Tokenizes a card so it can be charged later without handling the card number
+    ///
+    /// Only for merchants whose own systems are in PCI DSS scope. The card number is
+    /// checked with the Luhn algorithm and the expiry date must not be in the past.
+    pub fn tokenize(&self, mut card: CardDetails) -> Result<CardToken> {
+        card.card_number = Validators::validate_card_number(&card.card_number)?;
+        Validators::validate_card_expiry(card.expiry_month, card.expiry_year)?;
+        if card.holder_name.trim().is_empty() {
+            return Err(TapsilatError::ValidationError(
+                "Card holder name cannot be empty".to_string(),
+            ));
+        }
+
+        let response = self
+            .client
+            .make_request("POST", "card/tokenize", Some(&card))?;
+        self.client.decode_response(response, "card token response")
+    }
+}
@@ -0,0 +1,37 @@
+use crate::error::{Result, TapsilatError};
+use crate::types::ErasureRequest;
+use std::sync::Arc;
+
+pub struct ComplianceModule {
+    client: Arc<crate::client::TapsilatClient>,
+}
+
+impl ComplianceModule {
+    pub fn new(client: Arc<crate::client::TapsilatClient>) -> Self {
+        Self { client }
+    }
+
+    /// Forwards a data subject's request to erase the personal data of a buyer
+    pub fn request_buyer_erasure(&self, buyer_id: &str) -> Result<ErasureRequest> {
+        if buyer_id.trim().is_empty() {
+            return Err(TapsilatError::ValidationError(
+                "Buyer ID cannot be empty".to_string(),
+            ));
+        }
+
+        let payload = serde_json::json!({ "buyer_id": buyer_id });
+        let response =
+            self.client
+                .make_request("POST", "compliance/erasure-requests", Some(&payload))?;
+        self.client
+            .decode_response(response, "erasure request response")
+    }
+
+    /// Gets the processing status of an erasure request
+    pub fn get_erasure_status(&self, request_id: &str) -> Result<ErasureRequest> {
+        let endpoint = format!("compliance/erasure-requests/{}", request_id);
+        let response = self.client.make_request::<()>("GET", &endpoint, None)?;
+        self.client
+            .decode_response(response, "erasure request response")
+    }
+}
@@ -0,0 +1,67 @@
+use crate::error::{Result, TapsilatError};
+use crate::modules::pagination::RowPage;
+use crate::types::{Coupon, CouponDiscountType, CreateCouponRequest};
+use std::sync::Arc;
+
+pub struct CouponModule {
+    client: Arc<crate::client::TapsilatClient>,
+}
+
+impl CouponModule {
+    pub fn new(client: Arc<crate::client::TapsilatClient>) -> Self {
+        Self { client }
+    }
+
+    /// Creates a coupon code
+    pub fn create(&self, request: CreateCouponRequest) -> Result<Coupon> {
+        self.validate_create_request(&request)?;
+        let endpoint = "coupon/create";
+        let response = self.client.make_request("POST", endpoint, Some(&request))?;
+        self.client.decode_response(response, "coupon response")
+    }
+
+    /// Lists coupons with pagination
+    pub fn list(&self, page: u32, per_page: u32) -> Result<Vec<Coupon>> {
+        let endpoint = format!("coupon/list?page={}&per_page={}", page, per_page);
+        let response = self.client.make_request::<()>("GET", &endpoint, None)?;
+        let rows = RowPage::from_value(&response).rows;
+        self.client
+            .parse_typed(serde_json::Value::Array(rows), "coupon list response")
+    }
+
+    /// Deactivates a coupon so it can no longer be redeemed
+    pub fn deactivate(&self, code: &str) -> Result<serde_json::Value> {
+        let endpoint = "coupon/deactivate";
+        let payload = serde_json::json!({ "code": code });
+        self.client.make_request("POST", endpoint, Some(&payload))
+    }
+
+    /// Validates create coupon request
+    fn validate_create_request(&self, request: &CreateCouponRequest) -> Result<()> {
+        if request.code.trim().is_empty() {
+            return Err(TapsilatError::ValidationError(
+                "Coupon code cannot be empty".to_string(),
+            ));
+        }
+
+        match request.discount_type {
+            CouponDiscountType::Percentage => {
+                if request.value <= 0.0 || request.value > 100.0 {
+                    return Err(TapsilatError::ValidationError(
+                        "Percentage discount must be greater than 0 and at most 100".to_string(),
+                    ));
+                }
+            }
+            CouponDiscountType::FixedAmount => {
+                crate::modules::Validators::validate_amount(request.value)?;
+                if request.currency.is_none() {
+                    return Err(TapsilatError::ValidationError(
+                        "Fixed amount coupons require a currency".to_string(),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use crate::error::Result;
+use crate::modules::pagination::{encode_query_value, RowPage};
+use crate::types::{EventPage, EventPollError, WebhookEvent};
+use serde_json::Value;
+use std::ops::ControlFlow;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Polling access to the events normally delivered by webhooks
+pub struct EventModule {
+    client: Arc<crate::client::TapsilatClient>,
+}
+
+impl EventModule {
+    pub fn new(client: Arc<crate::client::TapsilatClient>) -> Self {
+        Self { client }
+    }
+
+    /// Lists events after `since_cursor`, or from the oldest retained event when `None`
+    pub fn list_events(&self, since_cursor: Option<&str>) -> Result<EventPage> {
+        let endpoint = match since_cursor {
+            Some(cursor) => format!("events?cursor={}", encode_query_value(cursor)),
+            None => "events".to_string(),
+        };
+        let response = self.client.make_request::<()>("GET", &endpoint, None)?;
+
+        let rows = RowPage::from_value(&response).rows;
+        let events: Vec<WebhookEvent> = self
+            .client
+            .parse_typed(Value::Array(rows), "events response")?;
+
+        let body = match response.get("data") {
+            Some(data @ Value::Object(_)) => data,
+            _ => &response,
+        };
+        let next_cursor = body
+            .get("next_cursor")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .or_else(|| since_cursor.map(str::to_string));
+        let has_more = body
+            .get("has_more")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+
+        Ok(EventPage {
+            events,
+            next_cursor,
+            has_more,
+        })
+    }
+
+    /// Polls for events every `interval`, passing each one to `handler` in order
+    ///
+    /// Pages are fetched back to back while the API reports more events. The loop
+    /// stops when `handler` returns [`ControlFlow::Break`] and returns the cursor to
+    /// resume from so callers can persist it.
+    ///
+    /// The cursor only moves past a page once `handler` has seen every event on it.
+    /// After a break partway through a page, resuming delivers that page again from
+    /// its first event, so handlers should tolerate seeing an event twice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EventPollError`] carrying the last good cursor if a request fails.
+    pub fn poll_loop<F>(
+        &self,
+        since_cursor: Option<String>,
+        interval: Duration,
+        mut handler: F,
+    ) -> std::result::Result<Option<String>, EventPollError>
+    where
+        F: FnMut(WebhookEvent) -> ControlFlow<()>,
+    {
+        let mut cursor = since_cursor;
+        loop {
+            let page = match self.list_events(cursor.as_deref()) {
+                Ok(page) => page,
+                Err(error) => return Err(EventPollError { error, cursor }),
+            };
+
+            let mut events = page.events.into_iter();
+            while let Some(event) = events.next() {
+                if handler(event).is_break() {
+                    if events.len() == 0 {
+                        cursor = page.next_cursor;
+                    }
+                    return Ok(cursor);
+                }
+            }
+            cursor = page.next_cursor;
+
+            if !page.has_more {
+                std::thread::sleep(interval);
+            }
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::error::Result;
+use crate::types::ExchangeRates;
+use std::sync::Arc;
+
+pub struct FxModule {
+    client: Arc<crate::client::TapsilatClient>,
+}
+
+impl FxModule {
+    pub fn new(client: Arc<crate::client::TapsilatClient>) -> Self {
+        Self { client }
+    }
+
+    /// Gets the current settlement exchange rates quoted against `base`, e.g. `USD`
+    pub fn get_rates(&self, base: &str) -> Result<ExchangeRates> {
+        let endpoint = format!("fx/rates?base={}", base.to_ascii_uppercase());
+        let response = self.client.make_request::<()>("GET", &endpoint, None)?;
+        self.client
+            .decode_response(response, "exchange rates response")
+    }
+
+    /// Converts `amount` at the current settlement rate, rounded to minor units
+    pub fn convert(&self, amount: f64, from: &str, to: &str) -> Result<f64> {
+        self.get_rates(from)?.convert(amount, from, to)
+    }
+}
@@ -1,6 +1,8 @@
 use crate::error::Result;
+use crate::modules::pagination::RowPage;
 use crate::modules::validators::Validators;
-use crate::types::{ApiResponse, PaginatedResponse, PaginationParams};
+use crate::types::{from_minor_units, to_minor_units, PaginatedResponse, PaginationParams};
+use chrono::{DateTime, Months, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
@@ -27,7 +29,7 @@ pub struct Installment {
     pub status: InstallmentStatus,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum InstallmentStatus {
     #[serde(rename = "pending")]
     Pending,
@@ -39,6 +41,207 @@ pub enum InstallmentStatus {
     Cancelled,
     #[serde(rename = "refunded")]
     Refunded,
+    #[serde(rename = "paused")]
+    Paused,
+}
+
+impl Installment {
+    /// Parses the due date, given either as `YYYY-MM-DD` or as an RFC 3339 timestamp
+    pub fn due_date(&self) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(&self.due_date, "%Y-%m-%d")
+            .ok()
+            .or_else(|| {
+                DateTime::parse_from_rfc3339(&self.due_date)
+                    .ok()
+                    .map(|at| at.with_timezone(&Utc).date_naive())
+            })
+    }
+
+    /// Whether the installment still has to be paid
+    pub fn is_outstanding(&self) -> bool {
+        matches!(
+            self.status,
+            InstallmentStatus::Pending | InstallmentStatus::Overdue
+        )
+    }
+
+    /// Days between the due date and `now` for an outstanding installment
+    ///
+    /// Returns 0 when the installment is not yet due, is settled or has an
+    /// unparsable due date.
+    pub fn days_overdue(&self, now: DateTime<Utc>) -> u32 {
+        if !self.is_outstanding() {
+            return 0;
+        }
+        self.due_date()
+            .map(|due| (now.date_naive() - due).num_days())
+            .and_then(|days| u32::try_from(days).ok())
+            .unwrap_or(0)
+    }
+
+    /// Whether the installment is outstanding and past due as of `now`, or already
+    /// marked overdue by the API
+    pub fn is_overdue(&self, now: DateTime<Utc>) -> bool {
+        self.is_outstanding()
+            && (self.status == InstallmentStatus::Overdue || self.days_overdue(now) > 0)
+    }
+}
+
+/// An outstanding installment past its due date, as reported by
+/// [`InstallmentModule::list_overdue`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OverdueInstallment {
+    pub plan_id: String,
+    pub order_id: String,
+    pub installment_id: String,
+    pub installment_number: u8,
+    pub currency: String,
+    pub due_date: String,
+    pub days_late: u32,
+    pub amount_outstanding: f64,
+}
+
+/// Amount needed to settle an installment plan early, as of the time it was quoted
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PayoffQuote {
+    pub plan_id: String,
+    pub currency: String,
+    /// Sum of the installments still to be paid
+    pub remaining_principal: f64,
+    /// Reduction granted for settling early
+    #[serde(default)]
+    pub early_settlement_discount: f64,
+    /// Amount charged by [`InstallmentModule::settle`]
+    pub payoff_amount: f64,
+    #[serde(default)]
+    pub remaining_installments: u8,
+    /// Time after which the quote is no longer honoured
+    pub valid_until: Option<String>,
+}
+
+/// Filters for [`InstallmentModule::list_overdue`]
+#[derive(Debug, Clone, Default)]
+pub struct OverdueFilter {
+    /// Date overdue installments are counted against (default: now)
+    pub as_of: Option<DateTime<Utc>>,
+    /// Only installments at least this many days late
+    pub min_days_late: Option<u32>,
+    pub currency: Option<String>,
+    pub order_id: Option<String>,
+    /// Whether installments of paused plans are included (default: false)
+    pub include_paused: bool,
+}
+
+/// One row of an installment plan's payment schedule
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InstallmentScheduleRow {
+    pub installment_number: u8,
+    pub due_date: String,
+    pub amount: f64,
+    pub status: InstallmentStatus,
+    pub paid_at: Option<String>,
+    /// Total paid up to and including this installment
+    pub paid_to_date: f64,
+    /// Amount still to be paid after this installment is settled
+    pub remaining_after: f64,
+}
+
+/// Payment progress of an installment plan at a point in time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InstallmentPlanSummary {
+    pub plan_id: String,
+    pub currency: String,
+    pub total_amount: f64,
+    pub paid_amount: f64,
+    pub outstanding_amount: f64,
+    pub paid_count: usize,
+    pub overdue_count: usize,
+    pub overdue_amount: f64,
+    /// Largest number of days any outstanding installment is past due
+    pub max_days_overdue: u32,
+    /// Earliest outstanding installment
+    pub next_due: Option<InstallmentScheduleRow>,
+}
+
+impl InstallmentPlan {
+    /// Returns the payment schedule ordered by installment number, with running totals
+    pub fn schedule_table(&self) -> Vec<InstallmentScheduleRow> {
+        let mut installments: Vec<&Installment> = self.installments.iter().collect();
+        installments.sort_by_key(|installment| installment.installment_number);
+
+        let total: i64 = installments
+            .iter()
+            .map(|installment| to_minor_units(installment.amount))
+            .sum();
+        let mut paid = 0;
+        let mut scheduled = 0;
+        installments
+            .into_iter()
+            .map(|installment| {
+                let amount = to_minor_units(installment.amount);
+                scheduled += amount;
+                if installment.status == InstallmentStatus::Paid {
+                    paid += amount;
+                }
+                InstallmentScheduleRow {
+                    installment_number: installment.installment_number,
+                    due_date: installment.due_date.clone(),
+                    amount: installment.amount,
+                    status: installment.status.clone(),
+                    paid_at: installment.paid_at.clone(),
+                    paid_to_date: from_minor_units(paid),
+                    remaining_after: from_minor_units(total - scheduled),
+                }
+            })
+            .collect()
+    }
+
+    /// Summarizes what has been paid and what is overdue as of `now`
+    pub fn summary(&self, now: DateTime<Utc>) -> InstallmentPlanSummary {
+        let mut total = 0;
+        let mut paid = 0;
+        let mut outstanding = 0;
+        let mut overdue = 0;
+        let mut paid_count = 0;
+        let mut overdue_count = 0;
+        let mut max_days_overdue = 0;
+        for installment in &self.installments {
+            let amount = to_minor_units(installment.amount);
+            total += amount;
+            if installment.status == InstallmentStatus::Paid {
+                paid += amount;
+                paid_count += 1;
+            }
+            if installment.is_outstanding() {
+                outstanding += amount;
+                if installment.is_overdue(now) {
+                    overdue += amount;
+                    overdue_count += 1;
+                    max_days_overdue = max_days_overdue.max(installment.days_overdue(now));
+                }
+            }
+        }
+
+        let next_due = self.schedule_table().into_iter().find(|row| {
+            matches!(
+                row.status,
+                InstallmentStatus::Pending | InstallmentStatus::Overdue
+            )
+        });
+
+        InstallmentPlanSummary {
+            plan_id: self.id.clone(),
+            currency: self.currency.clone(),
+            total_amount: from_minor_units(total),
+            paid_amount: from_minor_units(paid),
+            outstanding_amount: from_minor_units(outstanding),
+            paid_count,
+            overdue_count,
+            overdue_amount: from_minor_units(overdue),
+            max_days_overdue,
+            next_due,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -54,6 +257,54 @@ pub struct UpdateInstallmentRequest {
     pub amount: Option<f64>,
 }
 
+/// New due date for one installment of a plan
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RescheduledInstallment {
+    pub installment_number: u8,
+    pub due_date: String, // ISO 8601 date
+}
+
+/// Replacement due dates for the outstanding installments of a plan
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RescheduleRequest {
+    pub installments: Vec<RescheduledInstallment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl RescheduleRequest {
+    /// Moves every outstanding installment of `plan` back by `months`, e.g. to let a
+    /// customer skip one payment
+    ///
+    /// Installments with an unparsable due date are left out of the request.
+    pub fn shift(plan: &InstallmentPlan, months: u32) -> Self {
+        let installments = plan
+            .installments
+            .iter()
+            .filter(|installment| installment.is_outstanding())
+            .filter_map(|installment| {
+                let due = installment
+                    .due_date()?
+                    .checked_add_months(Months::new(months))?;
+                Some(RescheduledInstallment {
+                    installment_number: installment.installment_number,
+                    due_date: due.format("%Y-%m-%d").to_string(),
+                })
+            })
+            .collect();
+        Self {
+            installments,
+            reason: None,
+        }
+    }
+
+    /// Sets the reason recorded with the new schedule
+    pub fn with_reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RefundInstallmentRequest {
     pub amount: Option<f64>, // None for full refund
@@ -77,16 +328,8 @@ impl InstallmentModule {
         let response = self
             .client
             .make_request("POST", "installments/plans", Some(&request))?;
-        let api_response: ApiResponse<InstallmentPlan> = serde_json::from_value(response)?;
-
-        match api_response.data {
-            Some(plan) => Ok(plan),
-            None => Err(crate::error::TapsilatError::InvalidResponse(
-                api_response
-                    .message
-                    .unwrap_or("No installment plan data in response".to_string()),
-            )),
-        }
+        self.client
+            .decode_response(response, "installment response")
     }
 
     /// Gets an installment plan by ID
@@ -99,16 +342,8 @@ impl InstallmentModule {
 
         let endpoint = format!("installments/plans/{}", plan_id);
         let response = self.client.make_request::<()>("GET", &endpoint, None)?;
-        let api_response: ApiResponse<InstallmentPlan> = serde_json::from_value(response)?;
-
-        match api_response.data {
-            Some(plan) => Ok(plan),
-            None => Err(crate::error::TapsilatError::InvalidResponse(
-                api_response
-                    .message
-                    .unwrap_or("No installment plan data in response".to_string()),
-            )),
-        }
+        self.client
+            .decode_response(response, "installment response")
     }
 
     /// Gets installment plans for an order
@@ -121,16 +356,8 @@ impl InstallmentModule {
 
         let endpoint = format!("orders/{}/installments/plans", order_id);
         let response = self.client.make_request::<()>("GET", &endpoint, None)?;
-        let api_response: ApiResponse<Vec<InstallmentPlan>> = serde_json::from_value(response)?;
-
-        match api_response.data {
-            Some(plans) => Ok(plans),
-            None => Err(crate::error::TapsilatError::InvalidResponse(
-                api_response
-                    .message
-                    .unwrap_or("No installment plans data in response".to_string()),
-            )),
-        }
+        self.client
+            .decode_response(response, "installment response")
     }
 
     /// Updates an installment
@@ -152,16 +379,8 @@ impl InstallmentModule {
 
         let endpoint = format!("installments/{}", installment_id);
         let response = self.client.make_request("PUT", &endpoint, Some(&request))?;
-        let api_response: ApiResponse<Installment> = serde_json::from_value(response)?;
-
-        match api_response.data {
-            Some(installment) => Ok(installment),
-            None => Err(crate::error::TapsilatError::InvalidResponse(
-                api_response
-                    .message
-                    .unwrap_or("No installment data in response".to_string()),
-            )),
-        }
+        self.client
+            .decode_response(response, "installment response")
     }
 
     /// Cancels an installment plan
@@ -174,16 +393,74 @@ impl InstallmentModule {
 
         let endpoint = format!("installments/plans/{}/cancel", plan_id);
         let response = self.client.make_request::<()>("POST", &endpoint, None)?;
-        let api_response: ApiResponse<InstallmentPlan> = serde_json::from_value(response)?;
-
-        match api_response.data {
-            Some(plan) => Ok(plan),
-            None => Err(crate::error::TapsilatError::InvalidResponse(
-                api_response
-                    .message
-                    .unwrap_or("No installment plan data in response".to_string()),
-            )),
+        self.client
+            .decode_response(response, "installment response")
+    }
+
+    /// Pauses an installment plan, so no installment is collected until it is resumed
+    pub fn pause_plan(&self, plan_id: &str) -> Result<InstallmentPlan> {
+        self.plan_action(plan_id, "pause")
+    }
+
+    /// Resumes a paused installment plan
+    pub fn resume_plan(&self, plan_id: &str) -> Result<InstallmentPlan> {
+        self.plan_action(plan_id, "resume")
+    }
+
+    /// Replaces the due dates of a plan's outstanding installments
+    ///
+    /// See [`RescheduleRequest::shift`] for moving the whole schedule back.
+    pub fn reschedule(
+        &self,
+        plan_id: &str,
+        new_schedule: RescheduleRequest,
+    ) -> Result<InstallmentPlan> {
+        if plan_id.is_empty() {
+            return Err(crate::error::TapsilatError::ValidationError(
+                "Plan ID cannot be empty".to_string(),
+            ));
+        }
+        self.validate_reschedule_request(&new_schedule)?;
+
+        let endpoint = format!("installments/plans/{}/reschedule", plan_id);
+        let response = self
+            .client
+            .make_request("POST", &endpoint, Some(&new_schedule))?;
+        self.client
+            .decode_response(response, "installment response")
+    }
+
+    /// Quotes the amount needed to pay off the rest of a plan at once
+    pub fn payoff_quote(&self, plan_id: &str) -> Result<PayoffQuote> {
+        if plan_id.is_empty() {
+            return Err(crate::error::TapsilatError::ValidationError(
+                "Plan ID cannot be empty".to_string(),
+            ));
+        }
+
+        let endpoint = format!("installments/plans/{}/payoff-quote", plan_id);
+        let response = self.client.make_request::<()>("GET", &endpoint, None)?;
+        self.client
+            .decode_response(response, "payoff quote response")
+    }
+
+    /// Pays off the remaining installments of a plan at once, at the amount given by
+    /// [`payoff_quote`](Self::payoff_quote)
+    pub fn settle(&self, plan_id: &str) -> Result<InstallmentPlan> {
+        self.plan_action(plan_id, "settle")
+    }
+
+    fn plan_action(&self, plan_id: &str, action: &str) -> Result<InstallmentPlan> {
+        if plan_id.is_empty() {
+            return Err(crate::error::TapsilatError::ValidationError(
+                "Plan ID cannot be empty".to_string(),
+            ));
         }
+
+        let endpoint = format!("installments/plans/{}/{}", plan_id, action);
+        let response = self.client.make_request::<()>("POST", &endpoint, None)?;
+        self.client
+            .decode_response(response, "installment response")
     }
 
     /// Refunds an installment
@@ -207,16 +484,78 @@ impl InstallmentModule {
         let response = self
             .client
             .make_request("POST", &endpoint, Some(&request))?;
-        let api_response: ApiResponse<Installment> = serde_json::from_value(response)?;
-
-        match api_response.data {
-            Some(installment) => Ok(installment),
-            None => Err(crate::error::TapsilatError::InvalidResponse(
-                api_response
-                    .message
-                    .unwrap_or("No installment data in response".to_string()),
-            )),
+        self.client
+            .decode_response(response, "installment response")
+    }
+
+    /// Lists overdue installments across all installment plans, most overdue first
+    ///
+    /// Walks every page of plans and evaluates each installment against
+    /// `filter.as_of`. Installments of cancelled and refunded plans are never included.
+    pub fn list_overdue(&self, filter: &OverdueFilter) -> Result<Vec<OverdueInstallment>> {
+        let now = filter.as_of.unwrap_or_else(Utc::now);
+        let per_page = 100;
+        let mut overdue = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let endpoint = format!("installments/plans?page={}&per_page={}", page, per_page);
+            let response = self.client.make_request::<()>("GET", &endpoint, None)?;
+            let rows = RowPage::from_value(&response);
+            let plans: Vec<InstallmentPlan> = self.client.parse_typed(
+                serde_json::Value::Array(rows.rows.clone()),
+                "installment response",
+            )?;
+
+            for plan in plans.iter().filter(|plan| Self::plan_matches(plan, filter)) {
+                overdue.extend(
+                    plan.installments
+                        .iter()
+                        .filter(|installment| installment.is_overdue(now))
+                        .map(|installment| OverdueInstallment {
+                            plan_id: plan.id.clone(),
+                            order_id: plan.order_id.clone(),
+                            installment_id: installment.id.clone(),
+                            installment_number: installment.installment_number,
+                            currency: plan.currency.clone(),
+                            due_date: installment.due_date.clone(),
+                            days_late: installment.days_overdue(now),
+                            amount_outstanding: installment.amount,
+                        })
+                        .filter(|item| item.days_late >= filter.min_days_late.unwrap_or(0)),
+                );
+            }
+
+            if !rows.has_more(page, per_page) {
+                break;
+            }
+            page += 1;
         }
+
+        overdue.sort_by(|a, b| {
+            b.days_late
+                .cmp(&a.days_late)
+                .then_with(|| a.plan_id.cmp(&b.plan_id))
+                .then_with(|| a.installment_number.cmp(&b.installment_number))
+        });
+        Ok(overdue)
+    }
+
+    fn plan_matches(plan: &InstallmentPlan, filter: &OverdueFilter) -> bool {
+        let status_ok = match plan.status {
+            InstallmentStatus::Cancelled | InstallmentStatus::Refunded => false,
+            InstallmentStatus::Paused => filter.include_paused,
+            _ => true,
+        };
+        status_ok
+            && filter
+                .currency
+                .as_ref()
+                .is_none_or(|currency| plan.currency.eq_ignore_ascii_case(currency))
+            && filter
+                .order_id
+                .as_ref()
+                .is_none_or(|order_id| &plan.order_id == order_id)
     }
 
     /// Lists all installment plans with pagination
@@ -245,17 +584,8 @@ impl InstallmentModule {
         }
 
         let response = self.client.make_request::<()>("GET", &endpoint, None)?;
-        let api_response: ApiResponse<PaginatedResponse<InstallmentPlan>> =
-            serde_json::from_value(response)?;
-
-        match api_response.data {
-            Some(paginated_plans) => Ok(paginated_plans),
-            None => Err(crate::error::TapsilatError::InvalidResponse(
-                api_response
-                    .message
-                    .unwrap_or("No installment plans data in response".to_string()),
-            )),
-        }
+        self.client
+            .decode_response(response, "installment response")
     }
 
     /// Validates create installment plan request
@@ -278,4 +608,31 @@ impl InstallmentModule {
 
         Ok(())
     }
+
+    /// Validates reschedule request
+    fn validate_reschedule_request(&self, request: &RescheduleRequest) -> Result<()> {
+        if request.installments.is_empty() {
+            return Err(crate::error::TapsilatError::ValidationError(
+                "New schedule must contain at least one installment".to_string(),
+            ));
+        }
+
+        let mut numbers = std::collections::HashSet::new();
+        for installment in &request.installments {
+            if !numbers.insert(installment.installment_number) {
+                return Err(crate::error::TapsilatError::ValidationError(format!(
+                    "Installment {} is rescheduled more than once",
+                    installment.installment_number
+                )));
+            }
+            if NaiveDate::parse_from_str(&installment.due_date, "%Y-%m-%d").is_err() {
+                return Err(crate::error::TapsilatError::ValidationError(format!(
+                    "Invalid due date '{}' for installment {}, expected YYYY-MM-DD",
+                    installment.due_date, installment.installment_number
+                )));
+            }
+        }
+
+        Ok(())
+    }
 }
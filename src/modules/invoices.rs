@@ -0,0 +1,20 @@
+use crate::error::Result;
+use crate::types::Invoice;
+use std::sync::Arc;
+
+pub struct InvoiceModule {
+    client: Arc<crate::client::TapsilatClient>,
+}
+
+impl InvoiceModule {
+    pub fn new(client: Arc<crate::client::TapsilatClient>) -> Self {
+        Self { client }
+    }
+
+    /// Gets the e-invoice or e-archive document generated for an order
+    pub fn get_invoice(&self, reference_id: &str) -> Result<Invoice> {
+        let endpoint = format!("order/{}/invoice", reference_id);
+        let response = self.client.make_request::<()>("GET", &endpoint, None)?;
+        self.client.decode_response(response, "invoice response")
+    }
+}
@@ -0,0 +1,63 @@
+use crate::error::{Result, TapsilatError};
+use crate::modules::pagination::RowPage;
+use crate::secret::SecretString;
+use crate::types::{ApiKey, CreateApiKeyRequest, CreatedApiKey, KeyIdentity};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Creation response as sent by the API, before the secret is wrapped
+#[derive(Serialize, Deserialize)]
+struct CreatedKeyResponse {
+    #[serde(flatten)]
+    key: ApiKey,
+    secret: String,
+}
+
+pub struct KeyModule {
+    client: Arc<crate::client::TapsilatClient>,
+}
+
+impl KeyModule {
+    pub fn new(client: Arc<crate::client::TapsilatClient>) -> Self {
+        Self { client }
+    }
+
+    /// Lists the API keys of the account, including revoked ones
+    pub fn list(&self) -> Result<Vec<ApiKey>> {
+        let response = self.client.make_request::<()>("GET", "keys", None)?;
+        let rows = RowPage::from_value(&response).rows;
+        self.client
+            .parse_typed(serde_json::Value::Array(rows), "API key list response")
+    }
+
+    /// Creates an API key; the returned secret is not shown again
+    pub fn create(&self, request: CreateApiKeyRequest) -> Result<CreatedApiKey> {
+        if request.name.trim().is_empty() {
+            return Err(TapsilatError::ValidationError(
+                "API key name cannot be empty".to_string(),
+            ));
+        }
+
+        let response = self.client.make_request("POST", "keys", Some(&request))?;
+        let created: CreatedKeyResponse = self
+            .client
+            .decode_response(response, "API key creation response")?;
+        Ok(CreatedApiKey {
+            key: created.key,
+            secret: SecretString::new(created.secret),
+        })
+    }
+
+    /// Revokes an API key; requests made with it fail from then on
+    pub fn revoke(&self, key_id: &str) -> Result<serde_json::Value> {
+        let endpoint = format!("keys/{}", key_id);
+        self.client.make_request::<()>("DELETE", &endpoint, None)
+    }
+
+    /// Gets the merchant, organization and scopes of the key this client uses
+    pub fn whoami(&self) -> Result<KeyIdentity> {
+        let response = self.client.make_request::<()>("GET", "keys/whoami", None)?;
+        self.client
+            .decode_response(response, "key identity response")
+    }
+}
@@ -1,15 +1,77 @@
+#[cfg(feature = "http")]
+pub mod account;
+#[cfg(feature = "http")]
+pub mod batch;
+#[cfg(feature = "http")]
+pub mod buyers;
+#[cfg(feature = "http")]
+pub mod cards;
+#[cfg(feature = "http")]
+pub mod compliance;
+#[cfg(feature = "http")]
+pub mod coupons;
+#[cfg(feature = "http")]
+pub mod events;
+#[cfg(feature = "http")]
+pub mod fx;
+#[cfg(feature = "http")]
 pub mod installments;
+#[cfg(feature = "http")]
+pub mod invoices;
+#[cfg(feature = "http")]
+pub mod keys;
+#[cfg(feature = "http")]
+pub mod onboarding;
+#[cfg(feature = "http")]
 pub mod orders;
+#[cfg(feature = "http")]
 pub mod organization;
+#[cfg(feature = "http")]
+pub(crate) mod pagination;
+#[cfg(feature = "http")]
 pub mod payments;
+#[cfg(feature = "http")]
+pub mod reports;
+#[cfg(feature = "async")]
+pub mod subscription_stream;
+#[cfg(feature = "http")]
 pub mod subscriptions;
 pub mod validators;
 pub mod webhooks;
 
+#[cfg(feature = "http")]
+pub use account::AccountModule;
+#[cfg(feature = "http")]
+pub use buyers::BuyerModule;
+#[cfg(feature = "http")]
+pub use cards::CardModule;
+#[cfg(feature = "http")]
+pub use compliance::ComplianceModule;
+#[cfg(feature = "http")]
+pub use coupons::CouponModule;
+#[cfg(feature = "http")]
+pub use events::EventModule;
+#[cfg(feature = "http")]
+pub use fx::FxModule;
+#[cfg(feature = "http")]
 pub use installments::InstallmentModule;
+#[cfg(feature = "http")]
+pub use invoices::InvoiceModule;
+#[cfg(feature = "http")]
+pub use keys::KeyModule;
+#[cfg(feature = "http")]
+pub use onboarding::OnboardingModule;
+#[cfg(feature = "http")]
 pub use orders::OrderModule;
+#[cfg(feature = "http")]
 pub use organization::OrganizationModule;
+#[cfg(feature = "http")]
 pub use payments::PaymentModule;
+#[cfg(feature = "http")]
+pub use reports::ReportModule;
+#[cfg(feature = "async")]
+pub use subscription_stream::SubscriptionStream;
+#[cfg(feature = "http")]
 pub use subscriptions::SubscriptionModule;
-pub use validators::Validators;
+pub use validators::{PhoneValidation, Validators};
 pub use webhooks::WebhookModule;
@@ -0,0 +1,97 @@
+use crate::error::{Result, TapsilatError};
+use crate::modules::pagination::RowPage;
+use crate::types::{DocumentUpload, OnboardingStatus, RequiredDocument, UploadedDocument};
+use base64::Engine;
+use std::sync::Arc;
+
+/// Largest document file the API accepts
+const MAX_DOCUMENT_SIZE: usize = 10 * 1024 * 1024;
+
+pub struct OnboardingModule {
+    client: Arc<crate::client::TapsilatClient>,
+}
+
+impl OnboardingModule {
+    pub fn new(client: Arc<crate::client::TapsilatClient>) -> Self {
+        Self { client }
+    }
+
+    /// Gets the KYC status and document checklist of a sub-organization
+    pub fn get_status(&self, sub_organization_id: &str) -> Result<OnboardingStatus> {
+        let endpoint = format!(
+            "organization/suborganizations/{}/onboarding",
+            sub_organization_id
+        );
+        let response = self.client.make_request::<()>("GET", &endpoint, None)?;
+        self.client
+            .decode_response(response, "onboarding status response")
+    }
+
+    /// Lists the documents a sub-organization must provide, with their review state
+    pub fn list_required_documents(
+        &self,
+        sub_organization_id: &str,
+    ) -> Result<Vec<RequiredDocument>> {
+        let endpoint = format!(
+            "organization/suborganizations/{}/onboarding/documents",
+            sub_organization_id
+        );
+        let response = self.client.make_request::<()>("GET", &endpoint, None)?;
+        let rows = RowPage::from_value(&response).rows;
+        self.client.parse_typed(
+            serde_json::Value::Array(rows),
+            "onboarding document list response",
+        )
+    }
+
+    /// Uploads a verification document; the file is sent base64-encoded
+    pub fn upload_document(
+        &self,
+        sub_organization_id: &str,
+        upload: DocumentUpload,
+    ) -> Result<UploadedDocument> {
+        if upload.content.is_empty() {
+            return Err(TapsilatError::ValidationError(
+                "Document content cannot be empty".to_string(),
+            ));
+        }
+        if upload.content.len() > MAX_DOCUMENT_SIZE {
+            return Err(TapsilatError::ValidationError(format!(
+                "Document exceeds the {} byte limit",
+                MAX_DOCUMENT_SIZE
+            )));
+        }
+        if upload.file_name.trim().is_empty() {
+            return Err(TapsilatError::ValidationError(
+                "Document file name cannot be empty".to_string(),
+            ));
+        }
+
+        let endpoint = format!(
+            "organization/suborganizations/{}/onboarding/documents",
+            sub_organization_id
+        );
+        let payload = serde_json::json!({
+            "document_type": upload.document_type,
+            "file_name": upload.file_name,
+            "content_type": upload.content_type,
+            "content": base64::engine::general_purpose::STANDARD.encode(&upload.content),
+        });
+        let response = self
+            .client
+            .make_request("POST", &endpoint, Some(&payload))?;
+        self.client
+            .decode_response(response, "onboarding document response")
+    }
+
+    /// Submits the uploaded documents for review
+    pub fn submit(&self, sub_organization_id: &str) -> Result<OnboardingStatus> {
+        let endpoint = format!(
+            "organization/suborganizations/{}/onboarding/submit",
+            sub_organization_id
+        );
+        let response = self.client.make_request::<()>("POST", &endpoint, None)?;
+        self.client
+            .decode_response(response, "onboarding status response")
+    }
+}
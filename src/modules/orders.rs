@@ -1,7 +1,17 @@
+use crate::config::RequestOptions;
 use crate::error::Result;
+use crate::modules::batch::{run_bounded, DEFAULT_BATCH_CONCURRENCY};
+use crate::modules::pagination::{encode_query_value, RowPage};
+use crate::modules::validators::Validators;
 use crate::types::{
-    ApiResponse, CreateOrderRequest, CreateOrderResponse, Order, RefundOrderRequest,
+    BankTransferInfo, CancelOrderRequest, CancelReason, CancelResponse, CardPaymentRequest,
+    CheckoutToken, CreateOrderRequest, CreateOrderResponse, Detailed, InvoiceType,
+    ManualCallbackRequest, ManualCallbackResponse, ManualPaymentRequest, Order, OrderNote,
+    OrderResponse, OrderStatus, PaymentResponse, PaymentTerm, PollOptions, Receipt, Refund,
+    RefundOrderRequest, ShippingUpdate, ShortCheckoutUrl, TerminateResponse, ThreeDSPreference,
+    WalletPaymentRequest,
 };
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 pub struct OrderModule {
@@ -15,41 +25,274 @@ impl OrderModule {
 
     /// Creates a new order
     pub fn create(&self, request: CreateOrderRequest) -> Result<CreateOrderResponse> {
-        // Validation logic removed to simplify synchronization; rely on API or add later if needed.
-        let response = self
+        self.create_with(request, &RequestOptions::default())
+    }
+
+    /// Creates a new order with per-request options such as a tighter timeout
+    /// or an idempotency key
+    pub fn create_with(
+        &self,
+        request: CreateOrderRequest,
+        options: &RequestOptions,
+    ) -> Result<CreateOrderResponse> {
+        let response = self.send_create(request, options)?;
+        self.client
+            .decode_response(response, "create order response")
+    }
+
+    /// Creates a new order, also returning the raw response, status and headers
+    pub fn create_detailed(
+        &self,
+        request: CreateOrderRequest,
+    ) -> Result<Detailed<CreateOrderResponse>> {
+        let response = self.send_create(request, &RequestOptions::default())?;
+        self.client
+            .decode_detailed(response, "create order response")
+    }
+
+    fn send_create(
+        &self,
+        mut request: CreateOrderRequest,
+        options: &RequestOptions,
+    ) -> Result<serde_json::Value> {
+        self.client
+            .config()
+            .apply_default_currency(&mut request.currency)?;
+        self.client
+            .config()
+            .apply_default_locale(&mut request.locale);
+        self.fill_tax_amount(&mut request)?;
+        self.validate_create_request(&request)?;
+        if options.unique_external_reference {
+            self.check_external_reference(&request)?;
+        }
+        self.client
+            .make_request_with("POST", "order/create", Some(&request), options)
+    }
+
+    /// Creates an order, or returns the existing one if its `conversation_id` was already used
+    ///
+    /// Gives at-most-once order creation for clients that retry after timeouts or
+    /// dropped connections. The request must carry a `conversation_id`.
+    pub fn create_idempotent(&self, request: CreateOrderRequest) -> Result<CreateOrderResponse> {
+        let conversation_id = request.conversation_id.clone().ok_or_else(|| {
+            crate::error::TapsilatError::ValidationError(
+                "conversation_id is required for idempotent order creation".to_string(),
+            )
+        })?;
+
+        match self.create(request) {
+            Err(e) if Self::is_duplicate_conversation(&e) => {
+                // If the existing order cannot be fetched, the create error explains more
+                let existing = self
+                    .get_by_conversation_id(&conversation_id)
+                    .map_err(|_| e)?;
+                Ok(CreateOrderResponse {
+                    order_id: existing.order_id,
+                    reference_id: existing.reference_id,
+                    checkout_url: existing.checkout_url,
+                })
+            }
+            result => result,
+        }
+    }
+
+    /// Retrieves an order by the conversation ID it was created with
+    pub fn get_by_conversation_id(&self, conversation_id: &str) -> Result<OrderResponse> {
+        let endpoint = format!("order/conversation/{}", conversation_id);
+        let response = self.client.make_request::<()>("GET", &endpoint, None)?;
+        self.client.decode_response(response, "order response")
+    }
+
+    /// Finds the order created with `external_reference_id`, if any
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TapsilatError::InvalidResponse`](crate::error::TapsilatError::InvalidResponse)
+    /// if the list holds only other orders, which means the API ignored the filter and
+    /// the absence of a match proves nothing.
+    pub fn find_by_external_reference(&self, external_reference_id: &str) -> Result<Option<Order>> {
+        if external_reference_id.trim().is_empty() {
+            return Err(crate::error::TapsilatError::ValidationError(
+                "External reference ID cannot be empty".to_string(),
+            ));
+        }
+
+        let endpoint = format!(
+            "order/list?page=1&per_page=10&external_reference_id={}",
+            encode_query_value(external_reference_id)
+        );
+        let response = self.client.make_request::<()>("GET", &endpoint, None)?;
+        let rows = RowPage::from_value(&response).rows;
+        let orders: Vec<Order> = self
             .client
-            .make_request("POST", "order/create", Some(&request))?;
-        serde_json::from_value(response).map_err(|e| {
-            crate::error::TapsilatError::ConfigError(format!(
-                "Failed to parse create order response: {}",
-                e
-            ))
-        })
+            .parse_typed(serde_json::Value::Array(rows), "order list response")?;
+        if orders.is_empty() {
+            return Ok(None);
+        }
+        match orders
+            .into_iter()
+            .find(|order| order.external_reference_id.as_deref() == Some(external_reference_id))
+        {
+            Some(order) => Ok(Some(order)),
+            None => Err(crate::error::TapsilatError::InvalidResponse(format!(
+                "Order list ignored the external_reference_id filter for '{}'",
+                external_reference_id
+            ))),
+        }
+    }
+
+    fn check_external_reference(&self, request: &CreateOrderRequest) -> Result<()> {
+        let external_reference_id = request.external_reference_id.as_deref().ok_or_else(|| {
+            crate::error::TapsilatError::ValidationError(
+                "external_reference_id is required to check for duplicate orders".to_string(),
+            )
+        })?;
+
+        match self.find_by_external_reference(external_reference_id)? {
+            Some(existing) => Err(crate::error::TapsilatError::DuplicateOrder {
+                external_reference_id: external_reference_id.to_string(),
+                reference_id: existing.reference_id,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns whether `error` rejects a reused `conversation_id`
+    ///
+    /// A 409 alone is not enough, since other fields such as `external_reference_id`
+    /// conflict too; the message or a field error must name the conversation ID.
+    fn is_duplicate_conversation(error: &crate::error::TapsilatError) -> bool {
+        use crate::error::TapsilatError;
+
+        let is_duplicate = |message: &str| {
+            let message = message.to_ascii_lowercase();
+            ["already", "duplicate", "exist", "unique"]
+                .iter()
+                .any(|word| message.contains(word))
+        };
+        let mentions_duplicate = |message: &str| {
+            message.to_ascii_lowercase().contains("conversation") && is_duplicate(message)
+        };
+
+        match error {
+            TapsilatError::ApiError { message, .. } => mentions_duplicate(message),
+            TapsilatError::ValidationFailed {
+                message, fields, ..
+            } => {
+                mentions_duplicate(message)
+                    || fields
+                        .iter()
+                        .any(|f| f.field == "conversation_id" && is_duplicate(&f.message))
+            }
+            _ => false,
+        }
     }
 
     /// Retrieves an order by ID
     pub fn get(&self, reference_id: &str) -> Result<Order> {
         let endpoint = format!("order/{}", reference_id);
         let response = self.client.make_request::<()>("GET", &endpoint, None)?;
-        let api_response: ApiResponse<Order> = serde_json::from_value(response).map_err(|e| {
-            crate::error::TapsilatError::ConfigError(format!(
-                "Failed to parse order response: {}",
-                e
-            ))
-        })?;
+        self.client.decode_response(response, "order response")
+    }
 
-        match api_response.data {
-            Some(order) => Ok(order),
-            None => Err(crate::error::TapsilatError::InvalidResponse(
-                api_response.message.unwrap_or("No data".to_string()),
-            )),
-        }
+    /// Retrieves an order by ID, also returning the raw response, status and headers
+    pub fn get_detailed(&self, reference_id: &str) -> Result<Detailed<Order>> {
+        let endpoint = format!("order/{}", reference_id);
+        let response = self.client.make_request::<()>("GET", &endpoint, None)?;
+        self.client.decode_detailed(response, "order response")
     }
 
     /// Gets order status by ID
     pub fn get_status(&self, reference_id: &str) -> Result<serde_json::Value> {
+        self.get_status_with(reference_id, &RequestOptions::default())
+    }
+
+    /// Gets order status by ID with per-request options
+    pub fn get_status_with(
+        &self,
+        reference_id: &str,
+        options: &RequestOptions,
+    ) -> Result<serde_json::Value> {
         let endpoint = format!("order/{}/status", reference_id);
-        self.client.make_request::<()>("GET", &endpoint, None)
+        self.client
+            .make_request_with::<()>("GET", &endpoint, None, options)
+    }
+
+    /// Polls the status of an order until it reaches a terminal state
+    ///
+    /// Unrecognised statuses are treated as still in progress. Fails with
+    /// [`TapsilatError::PollTimeout`](crate::error::TapsilatError::PollTimeout) once
+    /// `options.timeout` elapses.
+    pub fn wait_for_completion(
+        &self,
+        reference_id: &str,
+        options: PollOptions,
+    ) -> Result<OrderStatus> {
+        let deadline = std::time::Instant::now() + options.timeout;
+        let mut interval = options.interval;
+        let mut last_status = None;
+
+        loop {
+            let response = self.get_status(reference_id)?;
+            let status = response
+                .get("status")
+                .or_else(|| response.get("data").and_then(|data| data.get("status")))
+                .and_then(serde_json::Value::as_str)
+                .map(str::to_string);
+
+            if let Some(parsed) = status.as_deref().and_then(OrderStatus::parse) {
+                if parsed.is_terminal() {
+                    return Ok(parsed);
+                }
+            }
+            last_status = status.or(last_status);
+
+            let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+            if remaining.is_zero() {
+                return Err(crate::error::TapsilatError::PollTimeout {
+                    reference_id: reference_id.to_string(),
+                    last_status,
+                });
+            }
+            std::thread::sleep(interval.min(remaining));
+            interval = (interval * 2).min(options.max_interval.max(options.interval));
+        }
+    }
+
+    /// Gets the status of many orders concurrently, keyed by reference ID
+    ///
+    /// Requests are spread over a bounded pool of [`DEFAULT_BATCH_CONCURRENCY`] workers.
+    /// A failed lookup only affects its own entry in the returned map.
+    pub fn get_statuses(
+        &self,
+        reference_ids: &[&str],
+    ) -> HashMap<String, Result<serde_json::Value>> {
+        self.get_statuses_with_concurrency(reference_ids, DEFAULT_BATCH_CONCURRENCY)
+    }
+
+    /// Gets the status of many orders using at most `concurrency` parallel requests
+    pub fn get_statuses_with_concurrency(
+        &self,
+        reference_ids: &[&str],
+        concurrency: usize,
+    ) -> HashMap<String, Result<serde_json::Value>> {
+        let mut seen = HashSet::new();
+        let unique: Vec<&str> = reference_ids
+            .iter()
+            .copied()
+            .filter(|reference_id| seen.insert(*reference_id))
+            .collect();
+
+        let statuses = run_bounded(&unique, concurrency, |reference_id| {
+            self.get_status(reference_id)
+        });
+
+        unique
+            .into_iter()
+            .map(str::to_string)
+            .zip(statuses)
+            .collect()
     }
 
     /// Lists orders with optional pagination
@@ -75,28 +318,195 @@ impl OrderModule {
         self.client.make_request::<()>("GET", &endpoint, None)
     }
 
+    /// Decodes a cancel or terminate response, treating an empty body or a missing
+    /// `data` as an outcome without details
+    fn decode_outcome<T>(
+        client: &crate::client::TapsilatClient,
+        response: serde_json::Value,
+        context: &str,
+    ) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned + serde::Serialize + Default,
+    {
+        client
+            .decode_response::<Option<T>>(response, context)
+            .map(Option::unwrap_or_default)
+    }
+
     /// Cancels an order
-    pub fn cancel(&self, reference_id: &str) -> Result<serde_json::Value> {
+    pub fn cancel(&self, reference_id: &str) -> Result<CancelResponse> {
         let endpoint = "order/cancel";
         let payload = serde_json::json!({ "reference_id": reference_id });
-        self.client.make_request("POST", endpoint, Some(&payload))
+        let response = self.client.make_request("POST", endpoint, Some(&payload))?;
+        Self::decode_outcome(&self.client, response, "cancel response")
+    }
+
+    /// Cancels an order, recording why it was cancelled
+    pub fn cancel_with_reason(
+        &self,
+        reference_id: &str,
+        reason: CancelReason,
+    ) -> Result<CancelResponse> {
+        if reference_id.trim().is_empty() {
+            return Err(crate::error::TapsilatError::ValidationError(
+                "Reference ID cannot be empty".to_string(),
+            ));
+        }
+
+        let endpoint = "order/cancel";
+        let request = CancelOrderRequest {
+            reference_id: reference_id.to_string(),
+            reason: Some(reason),
+        };
+        let response = self.client.make_request("POST", endpoint, Some(&request))?;
+        Self::decode_outcome(&self.client, response, "cancel response")
     }
 
     /// Refunds an order (full or partial)
     pub fn refund(&self, request: RefundOrderRequest) -> Result<serde_json::Value> {
+        self.refund_with(request, &RequestOptions::default())
+    }
+
+    /// Refunds an order with per-request options
+    pub fn refund_with(
+        &self,
+        request: RefundOrderRequest,
+        options: &RequestOptions,
+    ) -> Result<serde_json::Value> {
+        let response = self.send_refund(request, options)?;
+        self.client.decode_response(response, "refund response")
+    }
+
+    /// Refunds an order, also returning the raw response, status and headers
+    pub fn refund_detailed(
+        &self,
+        request: RefundOrderRequest,
+    ) -> Result<Detailed<serde_json::Value>> {
+        let response = self.send_refund(request, &RequestOptions::default())?;
+        self.client.decode_detailed(response, "refund response")
+    }
+
+    /// Gets a refund and its processing state
+    pub fn get_refund(&self, refund_id: &str) -> Result<Refund> {
+        let response = self.fetch_refund(refund_id)?;
+        self.client.decode_response(response, "refund response")
+    }
+
+    /// Polls a refund until it completes or fails
+    ///
+    /// A `Retry-After` header on the status response, in seconds, replaces the backoff
+    /// delay for the next attempt. Fails with
+    /// [`TapsilatError::PollTimeout`](crate::error::TapsilatError::PollTimeout) once
+    /// `options.timeout` elapses.
+    pub fn wait_for_refund(&self, refund_id: &str, options: PollOptions) -> Result<Refund> {
+        let deadline = std::time::Instant::now() + options.timeout;
+        let mut interval = options.interval;
+
+        loop {
+            let response = self.fetch_refund(refund_id)?;
+            let refund: Detailed<Refund> =
+                self.client.decode_detailed(response, "refund response")?;
+            if refund.value.status.is_final() {
+                return Ok(refund.into_inner());
+            }
+
+            let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+            if remaining.is_zero() {
+                return Err(crate::error::TapsilatError::PollTimeout {
+                    reference_id: refund_id.to_string(),
+                    last_status: serde_json::to_value(refund.value.status)
+                        .ok()
+                        .and_then(|status| status.as_str().map(str::to_string)),
+                });
+            }
+            let delay = refund
+                .header("Retry-After")
+                .and_then(|seconds| seconds.trim().parse().ok())
+                .map(std::time::Duration::from_secs)
+                .unwrap_or(interval);
+            std::thread::sleep(delay.min(remaining));
+            interval = (interval * 2).min(options.max_interval.max(options.interval));
+        }
+    }
+
+    fn fetch_refund(&self, refund_id: &str) -> Result<serde_json::Value> {
+        if refund_id.trim().is_empty() {
+            return Err(crate::error::TapsilatError::ValidationError(
+                "Refund ID cannot be empty".to_string(),
+            ));
+        }
+
+        let endpoint = format!("order/refund/{}", refund_id);
+        self.client.make_request::<()>("GET", &endpoint, None)
+    }
+
+    fn send_refund(
+        &self,
+        request: RefundOrderRequest,
+        options: &RequestOptions,
+    ) -> Result<serde_json::Value> {
+        if self.client.config().refund_guard {
+            let order = self.get(&request.reference_id)?;
+            Self::check_refundable(&request, &order)?;
+        }
+
+        let endpoint = "order/refund";
+        self.client
+            .make_request_with("POST", endpoint, Some(&request), options)
+    }
+
+    /// Executes many refunds concurrently, e.g. for a cancelled event
+    ///
+    /// Refunds are spread over a bounded pool of [`DEFAULT_BATCH_CONCURRENCY`] workers.
+    /// Results are returned in the order of `requests`, and a failed refund only
+    /// affects its own entry.
+    pub fn refund_batch(
+        &self,
+        requests: Vec<RefundOrderRequest>,
+    ) -> Vec<Result<serde_json::Value>> {
+        self.refund_batch_with_concurrency(requests, DEFAULT_BATCH_CONCURRENCY)
+    }
+
+    /// Executes many refunds using at most `concurrency` parallel requests
+    pub fn refund_batch_with_concurrency(
+        &self,
+        requests: Vec<RefundOrderRequest>,
+        concurrency: usize,
+    ) -> Vec<Result<serde_json::Value>> {
+        run_bounded(&requests, concurrency, |request| {
+            self.refund(request.clone())
+        })
+    }
+
+    /// Refunds an order after checking the amount against an already fetched order
+    pub fn refund_against(
+        &self,
+        request: RefundOrderRequest,
+        order: &Order,
+    ) -> Result<serde_json::Value> {
+        Self::check_refundable(&request, order)?;
         let endpoint = "order/refund";
         let response = self.client.make_request("POST", endpoint, Some(&request))?;
-        let api_response: ApiResponse<serde_json::Value> = serde_json::from_value(response)
-            .map_err(|e| {
-                crate::error::TapsilatError::ConfigError(format!(
-                    "Failed to parse refund response: {}",
-                    e
-                ))
-            })?;
+        self.client.decode_response(response, "refund response")
+    }
+
+    fn check_refundable(request: &RefundOrderRequest, order: &Order) -> Result<()> {
+        if let Some(lifecycle) = order.lifecycle() {
+            if !lifecycle.can_refund() {
+                return Err(crate::error::TapsilatError::ValidationError(format!(
+                    "Order {} cannot be refunded in status {:?}",
+                    request.reference_id,
+                    lifecycle.status()
+                )));
+            }
+        }
 
-        match api_response.data {
-            Some(v) => Ok(v),
-            None => Ok(serde_json::Value::Null),
+        match order.refundable_amount() {
+            Some(refundable) => Validators::validate_refund_amount(request.amount, refundable),
+            None => Err(crate::error::TapsilatError::ValidationError(format!(
+                "Cannot check refund for order {}: paid amount is unknown",
+                request.reference_id
+            ))),
         }
     }
 
@@ -115,6 +525,208 @@ impl OrderModule {
         })
     }
 
+    /// Gets a token for embedded (iframe) checkout of an order
+    pub fn get_checkout_token(&self, reference_id: &str) -> Result<CheckoutToken> {
+        let endpoint = format!("order/{}/checkout-token", reference_id);
+        let response = self.client.make_request::<()>("GET", &endpoint, None)?;
+        self.client
+            .decode_response(response, "checkout token response")
+    }
+
+    /// Gets a short checkout link for an order, e.g. for SMS campaigns
+    ///
+    /// With `domain` set, the link is served from that branded domain, which must
+    /// already be configured for the merchant; otherwise the default short domain is
+    /// used.
+    pub fn get_short_checkout_url(
+        &self,
+        reference_id: &str,
+        domain: Option<&str>,
+    ) -> Result<ShortCheckoutUrl> {
+        if let Some(domain) = domain {
+            let labels_valid = domain.split('.').all(|label| {
+                !label.is_empty()
+                    && !label.starts_with('-')
+                    && !label.ends_with('-')
+                    && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+            });
+            if !domain.contains('.') || !labels_valid {
+                return Err(crate::error::TapsilatError::ValidationError(format!(
+                    "Checkout domain must be a bare host name such as pay.example.com: {}",
+                    domain
+                )));
+            }
+        }
+
+        let endpoint = format!("order/{}/short-url", reference_id);
+        let payload = match domain {
+            Some(domain) => serde_json::json!({ "domain": domain }),
+            None => serde_json::json!({}),
+        };
+        let response = self
+            .client
+            .make_request("POST", &endpoint, Some(&payload))?;
+        self.client
+            .decode_response(response, "short checkout url response")
+    }
+
+    /// Fetches the bank transfer instructions of an order whose payment options include bank transfer
+    pub fn get_bank_transfer_info(&self, reference_id: &str) -> Result<BankTransferInfo> {
+        let endpoint = format!("order/{}/bank-transfer", reference_id);
+        let response = self.client.make_request::<()>("GET", &endpoint, None)?;
+        self.client
+            .decode_response(response, "bank transfer info response")
+    }
+
+    /// Extends the checkout link of an order so an abandoned cart can be resumed
+    pub fn extend_checkout(
+        &self,
+        reference_id: &str,
+        duration: std::time::Duration,
+    ) -> Result<serde_json::Value> {
+        if duration.as_secs() == 0 {
+            return Err(crate::error::TapsilatError::ValidationError(
+                "Checkout extension must be at least one second".to_string(),
+            ));
+        }
+
+        let endpoint = "order/checkout/extend";
+        let payload = serde_json::json!({
+            "reference_id": reference_id,
+            "checkout_expires_in": duration.as_secs(),
+        });
+        self.client.make_request("POST", endpoint, Some(&payload))
+    }
+
+    /// Records a cash or bank transfer payment made outside Tapsilat against an order
+    pub fn mark_paid_external(
+        &self,
+        reference_id: &str,
+        request: ManualPaymentRequest,
+    ) -> Result<serde_json::Value> {
+        Validators::validate_amount(request.amount)?;
+        chrono::NaiveDate::parse_from_str(&request.payment_date, "%Y-%m-%d").map_err(|_| {
+            crate::error::TapsilatError::ValidationError(format!(
+                "Payment date must be formatted as YYYY-MM-DD: {}",
+                request.payment_date
+            ))
+        })?;
+
+        let endpoint = "order/manual-payment";
+        let mut payload = serde_json::to_value(&request)?;
+        payload["reference_id"] = serde_json::Value::String(reference_id.to_string());
+        self.client.make_request("POST", endpoint, Some(&payload))
+    }
+
+    /// Pays an order with a tokenized card
+    ///
+    /// Recurring and merchant-initiated charges must carry a [`RecurringIndicator`]
+    /// so issuers can tell them apart from customer-present payments.
+    ///
+    /// [`RecurringIndicator`]: crate::types::RecurringIndicator
+    pub fn pay_with_card(
+        &self,
+        reference_id: &str,
+        request: CardPaymentRequest,
+    ) -> Result<PaymentResponse> {
+        if request.card_token.trim().is_empty() {
+            return Err(crate::error::TapsilatError::ValidationError(
+                "Card token cannot be empty".to_string(),
+            ));
+        }
+        if let Some(recurring) = &request.recurring {
+            Validators::validate_recurring_indicator(recurring)?;
+        }
+
+        let endpoint = "order/pay/card";
+        let mut payload = serde_json::to_value(&request)?;
+        payload["reference_id"] = serde_json::Value::String(reference_id.to_string());
+        let response = self.client.make_request("POST", endpoint, Some(&payload))?;
+        self.client
+            .decode_response(response, "card payment response")
+    }
+
+    /// Pays an order with an Apple Pay or Google Pay token, skipping the hosted checkout
+    pub fn pay_with_wallet(
+        &self,
+        reference_id: &str,
+        request: WalletPaymentRequest,
+    ) -> Result<PaymentResponse> {
+        if request.token().trim().is_empty() {
+            return Err(crate::error::TapsilatError::ValidationError(
+                "Wallet payment token cannot be empty".to_string(),
+            ));
+        }
+
+        let endpoint = "order/pay/wallet";
+        let mut payload = serde_json::to_value(&request)?;
+        payload["reference_id"] = serde_json::Value::String(reference_id.to_string());
+        let response = self.client.make_request("POST", endpoint, Some(&payload))?;
+        self.client
+            .decode_response(response, "wallet payment response")
+    }
+
+    /// Adds an internal note to an order; notes are never shown to the buyer
+    pub fn add_note(&self, reference_id: &str, text: &str) -> Result<OrderNote> {
+        if text.trim().is_empty() {
+            return Err(crate::error::TapsilatError::ValidationError(
+                "Note text cannot be empty".to_string(),
+            ));
+        }
+
+        let endpoint = format!("order/{}/notes", reference_id);
+        let payload = serde_json::json!({ "text": text });
+        let response = self
+            .client
+            .make_request("POST", &endpoint, Some(&payload))?;
+        self.client.decode_response(response, "order note response")
+    }
+
+    /// Lists the internal notes of an order
+    pub fn list_notes(&self, reference_id: &str) -> Result<Vec<OrderNote>> {
+        let endpoint = format!("order/{}/notes", reference_id);
+        let response = self.client.make_request::<()>("GET", &endpoint, None)?;
+        let rows = RowPage::from_value(&response).rows;
+        self.client
+            .parse_typed(serde_json::Value::Array(rows), "order note list response")
+    }
+
+    /// Gets the payment receipt of a paid order
+    pub fn get_receipt(&self, reference_id: &str) -> Result<Receipt> {
+        let endpoint = format!("order/{}/receipt", reference_id);
+        let response = self.client.make_request::<()>("GET", &endpoint, None)?;
+        self.client.decode_response(response, "receipt response")
+    }
+
+    /// Streams the PDF receipt of a paid order to `writer`, e.g. to attach it to a
+    /// confirmation email, and returns the number of bytes written
+    pub fn download_receipt<W: std::io::Write>(
+        &self,
+        reference_id: &str,
+        writer: &mut W,
+    ) -> Result<u64> {
+        let endpoint = format!("order/{}/receipt/pdf", reference_id);
+        self.client.download(&endpoint, "application/pdf", writer)
+    }
+
+    /// Updates the shipment tracking details of a paid order
+    pub fn update_shipping(
+        &self,
+        reference_id: &str,
+        update: ShippingUpdate,
+    ) -> Result<serde_json::Value> {
+        if update.tracking_code.trim().is_empty() {
+            return Err(crate::error::TapsilatError::ValidationError(
+                "Tracking code cannot be empty".to_string(),
+            ));
+        }
+
+        let endpoint = "order/shipping";
+        let mut payload = serde_json::to_value(&update)?;
+        payload["reference_id"] = serde_json::Value::String(reference_id.to_string());
+        self.client.make_request("PATCH", endpoint, Some(&payload))
+    }
+
     pub fn create_term(
         &self,
         request: crate::types::OrderPaymentTermCreateDTO,
@@ -154,7 +766,7 @@ impl OrderModule {
         &self,
         term_reference_id: &str,
         reason: Option<String>,
-    ) -> Result<serde_json::Value> {
+    ) -> Result<TerminateResponse> {
         let endpoint = "order/term/terminate";
         let mut payload = serde_json::Map::new();
         payload.insert(
@@ -164,7 +776,18 @@ impl OrderModule {
         if let Some(r) = reason {
             payload.insert("reason".to_string(), serde_json::Value::String(r));
         }
-        self.client.make_request("POST", endpoint, Some(&payload))
+        let response = self.client.make_request("POST", endpoint, Some(&payload))?;
+        Self::decode_outcome(&self.client, response, "terminate term response")
+    }
+
+    /// Lists the payment terms of an order
+    pub fn list_terms(&self, order_reference_id: &str) -> Result<Vec<PaymentTerm>> {
+        let mut terms = self
+            .get(order_reference_id)?
+            .payment_terms
+            .unwrap_or_default();
+        terms.sort_by_key(|term| term.term_sequence);
+        Ok(terms)
     }
 
     pub fn get_term(&self, term_reference_id: &str) -> Result<serde_json::Value> {
@@ -172,10 +795,11 @@ impl OrderModule {
         self.client.make_request::<()>("GET", &endpoint, None)
     }
 
-    pub fn terminate(&self, reference_id: &str) -> Result<serde_json::Value> {
+    pub fn terminate(&self, reference_id: &str) -> Result<TerminateResponse> {
         let endpoint = "order/terminate";
         let payload = serde_json::json!({ "reference_id": reference_id });
-        self.client.make_request("POST", endpoint, Some(&payload))
+        let response = self.client.make_request("POST", endpoint, Some(&payload))?;
+        Self::decode_outcome(&self.client, response, "terminate response")
     }
 
     pub fn manual_callback(
@@ -184,18 +808,31 @@ impl OrderModule {
         conversation_id: Option<String>,
     ) -> Result<serde_json::Value> {
         let endpoint = "order/callback";
-        let mut payload = serde_json::Map::new();
-        payload.insert(
-            "reference_id".to_string(),
-            serde_json::Value::String(reference_id.to_string()),
-        );
-        if let Some(cid) = conversation_id {
-            payload.insert(
-                "conversation_id".to_string(),
-                serde_json::Value::String(cid),
-            );
+        let request = ManualCallbackRequest {
+            conversation_id,
+            ..ManualCallbackRequest::new(reference_id)
+        };
+        self.client.make_request("POST", endpoint, Some(&request))
+    }
+
+    /// Simulates a payment callback for an order, e.g. to complete payments in staging
+    pub fn simulate_callback(
+        &self,
+        request: ManualCallbackRequest,
+    ) -> Result<ManualCallbackResponse> {
+        if request.reference_id.trim().is_empty() {
+            return Err(crate::error::TapsilatError::ValidationError(
+                "Reference ID cannot be empty".to_string(),
+            ));
         }
-        self.client.make_request("POST", endpoint, Some(&payload))
+        if let Some(amount) = request.amount {
+            Validators::validate_amount(amount)?;
+        }
+
+        let endpoint = "order/callback";
+        let response = self.client.make_request("POST", endpoint, Some(&request))?;
+        self.client
+            .decode_response(response, "manual callback response")
     }
 
     pub fn related_update(
@@ -242,11 +879,119 @@ impl OrderModule {
         self.client.make_request("POST", endpoint, Some(&request))
     }
 
+    /// Captures a pre-authorized order, optionally split across basket items
+    ///
+    /// When `items` is set the order is fetched first, and each item capture is checked
+    /// against the item's total. Amounts captured by earlier consignments are not known
+    /// to the SDK and are left to server-side validation.
     pub fn postauth(
         &self,
         request: crate::types::OrderPostAuthRequest,
     ) -> Result<serde_json::Value> {
+        if let Some(items) = &request.items {
+            let order = self.get(&request.reference_id)?;
+            let basket_items = order.basket_items.as_deref().unwrap_or_default();
+            Validators::validate_item_captures(items, basket_items, request.amount)?;
+        }
+
         let endpoint = "order/postauth";
         self.client.make_request("POST", endpoint, Some(&request))
     }
+
+    /// Computes `tax_amount` from the basket when [`Config::auto_tax`](crate::Config::auto_tax)
+    /// is set and the request has none
+    fn fill_tax_amount(&self, request: &mut CreateOrderRequest) -> Result<()> {
+        let Some(mode) = self.client.config().auto_tax else {
+            return Ok(());
+        };
+        let has_rates = request
+            .basket_items
+            .as_deref()
+            .is_some_and(|items| !items.is_empty() && items.iter().all(|i| i.vat_rate.is_some()));
+        if request.tax_amount.is_none() && has_rates {
+            crate::tax::apply_tax(request, mode)?;
+        }
+        Ok(())
+    }
+
+    /// Validates create order request
+    ///
+    /// Only checks combinations the API would reject outright; everything else is
+    /// left to server-side validation.
+    fn validate_create_request(&self, request: &CreateOrderRequest) -> Result<()> {
+        if let Some(mode) = request.payment_mode.as_deref() {
+            let mode = mode.to_ascii_uppercase().replace(['-', ' '], "_");
+            let non_3ds_mode = matches!(mode.as_str(), "NON_3D" | "NON3D" | "2D");
+            let three_ds_mode = !non_3ds_mode && (mode.contains("3D") || mode.contains("THREE_D"));
+
+            if non_3ds_mode && request.three_ds == ThreeDSPreference::Force {
+                return Err(crate::error::TapsilatError::ValidationError(format!(
+                    "3DS cannot be forced with payment mode {}",
+                    mode
+                )));
+            }
+            if three_ds_mode && request.three_ds == ThreeDSPreference::Disable {
+                return Err(crate::error::TapsilatError::ValidationError(format!(
+                    "3DS cannot be disabled with payment mode {}",
+                    mode
+                )));
+            }
+        }
+
+        if let (Some(mode), Some(gsm)) = (
+            self.client.config().phone_validation,
+            &request.buyer.gsm_number,
+        ) {
+            Validators::validate_phone(gsm, mode)?;
+        }
+
+        if self.client.config().identity_validation {
+            Validators::validate_buyer_identity(&request.buyer)?;
+        }
+
+        if let Some(ip) = &request.buyer.ip {
+            Validators::validate_ip(ip)?;
+        }
+
+        let require_https = self.client.config().require_https_urls;
+        for url in [
+            &request.payment_success_url,
+            &request.payment_failure_url,
+            &request.redirect_success_url,
+            &request.redirect_failure_url,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            Validators::validate_url_with(url, require_https)?;
+        }
+
+        if let Some(cards) = &request.order_cards {
+            Validators::validate_card_split(cards, request.amount)?;
+        }
+
+        if let Some(invoice) = &request.invoice {
+            match invoice.invoice_type {
+                Some(InvoiceType::EInvoice)
+                    if invoice.tax_number.is_none() || invoice.tax_office.is_none() =>
+                {
+                    return Err(crate::error::TapsilatError::ValidationError(
+                        "e-Invoices require a tax number and tax office".to_string(),
+                    ));
+                }
+                Some(InvoiceType::EArchive) => {
+                    if let Some(email) = &invoice.e_archive_email {
+                        Validators::validate_email(email)?;
+                    } else {
+                        return Err(crate::error::TapsilatError::ValidationError(
+                            "e-Archive invoices require an e-archive email".to_string(),
+                        ));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,117 @@
+//! Helpers for walking list endpoints whose page envelopes vary between endpoints.
+
+use serde_json::Value;
+
+/// A single page of raw rows returned by a list endpoint.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RowPage {
+    pub(crate) rows: Vec<Value>,
+    pub(crate) total_pages: Option<u32>,
+}
+
+impl RowPage {
+    /// Extracts rows from a list response.
+    ///
+    /// Accepts bare arrays, `{rows|items|events|data: [...]}` objects and the same shapes
+    /// nested inside a `{success, data}` envelope.
+    pub(crate) fn from_value(value: &Value) -> Self {
+        if let Value::Array(rows) = value {
+            return Self {
+                rows: rows.clone(),
+                total_pages: None,
+            };
+        }
+
+        for key in ["rows", "items", "events", "data"] {
+            match value.get(key) {
+                Some(Value::Array(rows)) => {
+                    return Self {
+                        rows: rows.clone(),
+                        total_pages: Self::total_pages(value),
+                    }
+                }
+                Some(nested @ Value::Object(_)) if key == "data" => {
+                    let mut page = Self::from_value(nested);
+                    page.total_pages = page.total_pages.or_else(|| Self::total_pages(value));
+                    return page;
+                }
+                _ => {}
+            }
+        }
+
+        Self::default()
+    }
+
+    /// Returns whether another page should be requested after `page`.
+    pub(crate) fn has_more(&self, page: u32, per_page: u32) -> bool {
+        match self.total_pages {
+            Some(total_pages) => page < total_pages,
+            None => !self.rows.is_empty() && self.rows.len() as u32 >= per_page,
+        }
+    }
+
+    fn total_pages(value: &Value) -> Option<u32> {
+        value
+            .get("total_pages")
+            .or_else(|| value.get("pagination").and_then(|p| p.get("total_pages")))
+            .and_then(Value::as_u64)
+            .map(|pages| pages as u32)
+    }
+}
+
+/// Percent-encodes a query string value, leaving only RFC 3986 unreserved characters as is.
+pub(crate) fn encode_query_value(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_row_page_shapes() {
+        let bare = RowPage::from_value(&json!([{ "id": 1 }]));
+        assert_eq!(bare.rows.len(), 1);
+
+        let rows =
+            RowPage::from_value(&json!({ "rows": [{ "id": 1 }, { "id": 2 }], "total_pages": 3 }));
+        assert_eq!(rows.rows.len(), 2);
+        assert!(rows.has_more(2, 2));
+        assert!(!rows.has_more(3, 2));
+
+        let enveloped = RowPage::from_value(&json!({
+            "success": true,
+            "data": { "data": [{ "id": 1 }], "pagination": { "total_pages": 1 } }
+        }));
+        assert_eq!(enveloped.rows.len(), 1);
+        assert_eq!(enveloped.total_pages, Some(1));
+    }
+
+    #[test]
+    fn test_row_page_without_totals() {
+        let page = RowPage::from_value(&json!({ "rows": [{ "id": 1 }, { "id": 2 }] }));
+        assert!(page.has_more(1, 2));
+        assert!(!page.has_more(1, 10));
+    }
+
+    #[test]
+    fn test_encode_query_value() {
+        assert_eq!(encode_query_value("c2_abc-1.x~"), "c2_abc-1.x~");
+        assert_eq!(encode_query_value("a&b=c d"), "a%26b%3Dc%20d");
+        assert_eq!(
+            encode_query_value("2024-01-01T00:00:00+03:00"),
+            "2024-01-01T00%3A00%3A00%2B03%3A00"
+        );
+        assert_eq!(encode_query_value("şube"), "%C5%9Fube");
+    }
+}
@@ -1,8 +1,8 @@
 use crate::error::Result;
 use crate::modules::validators::Validators;
 use crate::types::{
-    ApiResponse, CreatePaymentRequest, PaginatedResponse, PaginationParams, Payment,
-    PaymentResponse,
+    CreatePaymentRequest, PaginatedResponse, PaginationParams, Payment, PaymentCapture,
+    PaymentListFilter, PaymentRefund, PaymentResponse,
 };
 use std::sync::Arc;
 
@@ -15,23 +15,17 @@ impl PaymentModule {
         Self { client }
     }
 
-    pub fn create(&self, request: CreatePaymentRequest) -> Result<PaymentResponse> {
+    pub fn create(&self, mut request: CreatePaymentRequest) -> Result<PaymentResponse> {
         // Validate request
         Validators::validate_amount(request.amount)?;
+        self.client
+            .config()
+            .apply_default_currency(&mut request.currency)?;
 
         let response = self
             .client
             .make_request("POST", "payments", Some(&request))?;
-        let api_response: ApiResponse<PaymentResponse> = serde_json::from_value(response)?;
-
-        match api_response.data {
-            Some(payment_response) => Ok(payment_response),
-            None => Err(crate::error::TapsilatError::InvalidResponse(
-                api_response
-                    .message
-                    .unwrap_or("No payment data in response".to_string()),
-            )),
-        }
+        self.client.decode_response(response, "payment response")
     }
 
     pub fn get(&self, payment_id: &str) -> Result<Payment> {
@@ -43,51 +37,54 @@ impl PaymentModule {
 
         let endpoint = format!("payments/{}", payment_id);
         let response = self.client.make_request::<()>("GET", &endpoint, None)?;
-        let api_response: ApiResponse<Payment> = serde_json::from_value(response)?;
-
-        match api_response.data {
-            Some(payment) => Ok(payment),
-            None => Err(crate::error::TapsilatError::InvalidResponse(
-                api_response
-                    .message
-                    .unwrap_or("No payment data in response".to_string()),
-            )),
-        }
+        self.client.decode_response(response, "payment response")
     }
 
     pub fn list(&self, pagination: Option<PaginationParams>) -> Result<PaginatedResponse<Payment>> {
-        let mut endpoint = "payments".to_string();
-
-        // Add pagination parameters
-        if let Some(params) = pagination {
-            let mut query_params = Vec::new();
-
-            if let Some(page) = params.page {
-                query_params.push(format!("page={}", page));
-            }
+        let pagination = pagination.unwrap_or(PaginationParams {
+            page: None,
+            per_page: None,
+        });
+        self.list_filtered(&PaymentListFilter {
+            page: pagination.page,
+            per_page: pagination.per_page,
+            ..Default::default()
+        })
+    }
 
-            if let Some(per_page) = params.per_page {
-                query_params.push(format!("per_page={}", per_page));
+    /// Lists payments matching `filter`
+    pub fn list_filtered(&self, filter: &PaymentListFilter) -> Result<PaginatedResponse<Payment>> {
+        let mut query_params = Vec::new();
+        if let Some(status) = &filter.status {
+            let status = serde_json::to_value(status)?;
+            if let Some(status) = status.as_str() {
+                query_params.push(format!("status={}", status));
             }
+        }
+        if let Some(start_date) = &filter.start_date {
+            query_params.push(format!("start_date={}", start_date));
+        }
+        if let Some(end_date) = &filter.end_date {
+            query_params.push(format!("end_date={}", end_date));
+        }
+        if let Some(customer_id) = &filter.customer_id {
+            query_params.push(format!("customer_id={}", customer_id));
+        }
+        if let Some(page) = filter.page {
+            query_params.push(format!("page={}", page));
+        }
+        if let Some(per_page) = filter.per_page {
+            query_params.push(format!("per_page={}", per_page));
+        }
 
-            if !query_params.is_empty() {
-                endpoint.push('?');
-                endpoint.push_str(&query_params.join("&"));
-            }
+        let mut endpoint = "payments".to_string();
+        if !query_params.is_empty() {
+            endpoint.push('?');
+            endpoint.push_str(&query_params.join("&"));
         }
 
         let response = self.client.make_request::<()>("GET", &endpoint, None)?;
-        let api_response: ApiResponse<PaginatedResponse<Payment>> =
-            serde_json::from_value(response)?;
-
-        match api_response.data {
-            Some(paginated_payments) => Ok(paginated_payments),
-            None => Err(crate::error::TapsilatError::InvalidResponse(
-                api_response
-                    .message
-                    .unwrap_or("No payments data in response".to_string()),
-            )),
-        }
+        self.client.decode_response(response, "payment response")
     }
 
     pub fn cancel(&self, payment_id: &str) -> Result<Payment> {
@@ -99,15 +96,46 @@ impl PaymentModule {
 
         let endpoint = format!("payments/{}/cancel", payment_id);
         let response = self.client.make_request::<()>("POST", &endpoint, None)?;
-        let api_response: ApiResponse<Payment> = serde_json::from_value(response)?;
-
-        match api_response.data {
-            Some(payment) => Ok(payment),
-            None => Err(crate::error::TapsilatError::InvalidResponse(
-                api_response
-                    .message
-                    .unwrap_or("No payment data in response".to_string()),
-            )),
+        self.client.decode_response(response, "payment response")
+    }
+
+    /// Refunds a payment, fully when `amount` is `None`
+    pub fn refund(&self, payment_id: &str, amount: Option<f64>) -> Result<PaymentRefund> {
+        if payment_id.is_empty() {
+            return Err(crate::error::TapsilatError::ValidationError(
+                "Payment ID cannot be empty".to_string(),
+            ));
+        }
+        if let Some(amount) = amount {
+            Validators::validate_amount(amount)?;
+        }
+
+        let endpoint = format!("payments/{}/refund", payment_id);
+        let payload = serde_json::json!({ "amount": amount });
+        let response = self
+            .client
+            .make_request("POST", &endpoint, Some(&payload))?;
+        self.client
+            .decode_response(response, "payment refund response")
+    }
+
+    /// Captures an authorized payment, fully when `amount` is `None`
+    pub fn capture(&self, payment_id: &str, amount: Option<f64>) -> Result<PaymentCapture> {
+        if payment_id.is_empty() {
+            return Err(crate::error::TapsilatError::ValidationError(
+                "Payment ID cannot be empty".to_string(),
+            ));
         }
+        if let Some(amount) = amount {
+            Validators::validate_amount(amount)?;
+        }
+
+        let endpoint = format!("payments/{}/capture", payment_id);
+        let payload = serde_json::json!({ "amount": amount });
+        let response = self
+            .client
+            .make_request("POST", &endpoint, Some(&payload))?;
+        self.client
+            .decode_response(response, "payment capture response")
     }
 }
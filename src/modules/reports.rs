@@ -0,0 +1,256 @@
+use crate::error::{Result, TapsilatError};
+use crate::modules::pagination::{encode_query_value, RowPage};
+use crate::types::{ExportFormat, LedgerEntry, LedgerPage, OrderExportFilter};
+use chrono::NaiveDate;
+use serde_json::Value;
+use std::io::Write;
+use std::sync::Arc;
+
+/// Columns written for order CSV exports, as `(header, JSON path)` pairs
+const ORDER_COLUMNS: &[(&str, &str)] = &[
+    ("reference_id", "reference_id"),
+    ("conversation_id", "conversation_id"),
+    ("status", "status_enum"),
+    ("amount", "amount"),
+    ("paid_amount", "paid_amount"),
+    ("refunded_amount", "refunded_amount"),
+    ("currency", "currency"),
+    ("buyer_name", "buyer.name"),
+    ("buyer_surname", "buyer.surname"),
+    ("buyer_email", "buyer.email"),
+    ("created_at", "created_at"),
+];
+
+pub struct ReportModule {
+    client: Arc<crate::client::TapsilatClient>,
+}
+
+impl ReportModule {
+    pub fn new(client: Arc<crate::client::TapsilatClient>) -> Self {
+        Self { client }
+    }
+
+    /// Streams every order matching `filter` to `writer`, returning the number of rows written
+    pub fn export_orders<W: Write>(
+        &self,
+        filter: &OrderExportFilter,
+        format: ExportFormat,
+        writer: &mut W,
+    ) -> Result<usize> {
+        let per_page = filter.per_page.max(1);
+        let columns: Vec<String> = ORDER_COLUMNS.iter().map(|(h, _)| h.to_string()).collect();
+        let mut export = RowWriter::new(writer, format, columns)?;
+        let mut page = 1;
+
+        loop {
+            let response = self.client.make_request::<()>(
+                "GET",
+                &Self::order_list_endpoint(filter, page, per_page),
+                None,
+            )?;
+            let rows = RowPage::from_value(&response);
+
+            for row in rows.rows.iter().filter(|row| Self::in_range(row, filter)) {
+                let cells = ORDER_COLUMNS
+                    .iter()
+                    .map(|(_, path)| Self::lookup(row, path))
+                    .collect::<Vec<_>>();
+                export.write_row(row, &cells)?;
+            }
+
+            if !rows.has_more(page, per_page) {
+                break;
+            }
+            page += 1;
+        }
+
+        export.finish()
+    }
+
+    /// Streams the transactions of an order to `writer`, returning the number of rows written
+    ///
+    /// CSV columns are taken from the keys of the first transaction.
+    pub fn export_transactions<W: Write>(
+        &self,
+        reference_id: &str,
+        format: ExportFormat,
+        writer: &mut W,
+    ) -> Result<usize> {
+        let endpoint = format!("order/{}/transactions", reference_id);
+        let response = self.client.make_request::<()>("GET", &endpoint, None)?;
+        let rows = RowPage::from_value(&response).rows;
+
+        let columns: Vec<String> = match rows.first() {
+            Some(Value::Object(first)) => first.keys().cloned().collect(),
+            _ => Vec::new(),
+        };
+        let mut export = RowWriter::new(writer, format, columns.clone())?;
+        for row in &rows {
+            let cells = columns
+                .iter()
+                .map(|column| Self::lookup(row, column))
+                .collect::<Vec<_>>();
+            export.write_row(row, &cells)?;
+        }
+
+        export.finish()
+    }
+
+    /// Lists the account's debit and credit entries booked from `from` to `to`, inclusive
+    ///
+    /// Pass the previous page's `next_cursor` to continue; `None` starts at the first
+    /// entry of the period.
+    pub fn ledger_entries(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        cursor: Option<&str>,
+    ) -> Result<LedgerPage> {
+        if from > to {
+            return Err(TapsilatError::ValidationError(format!(
+                "Ledger period start {} is after its end {}",
+                from, to
+            )));
+        }
+
+        let mut endpoint = format!(
+            "account/ledger?from={}&to={}",
+            encode_query_value(&from.format("%Y-%m-%d").to_string()),
+            encode_query_value(&to.format("%Y-%m-%d").to_string())
+        );
+        if let Some(cursor) = cursor {
+            endpoint.push_str(&format!("&cursor={}", encode_query_value(cursor)));
+        }
+        let response = self.client.make_request::<()>("GET", &endpoint, None)?;
+
+        let rows = RowPage::from_value(&response).rows;
+        let entries: Vec<LedgerEntry> = self
+            .client
+            .parse_typed(Value::Array(rows), "ledger entries response")?;
+
+        let body = match response.get("data") {
+            Some(data @ Value::Object(_)) => data,
+            _ => &response,
+        };
+        let next_cursor = body
+            .get("next_cursor")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        let has_more = body
+            .get("has_more")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+
+        Ok(LedgerPage {
+            entries,
+            next_cursor,
+            has_more,
+        })
+    }
+
+    fn order_list_endpoint(filter: &OrderExportFilter, page: u32, per_page: u32) -> String {
+        let mut params = vec![format!("page={}", page), format!("per_page={}", per_page)];
+        if let Some(buyer_id) = &filter.buyer_id {
+            params.push(format!("buyer_id={}", encode_query_value(buyer_id)));
+        }
+        if let Some(start_date) = &filter.start_date {
+            params.push(format!("start_date={}", encode_query_value(start_date)));
+        }
+        if let Some(end_date) = &filter.end_date {
+            params.push(format!("end_date={}", encode_query_value(end_date)));
+        }
+        format!("order/list?{}", params.join("&"))
+    }
+
+    // The date range is re-checked locally so exports stay correct even if the
+    // list endpoint ignores the date parameters.
+    fn in_range(row: &Value, filter: &OrderExportFilter) -> bool {
+        let created_at = match row.get("created_at").and_then(Value::as_str) {
+            Some(created_at) => created_at,
+            None => return filter.start_date.is_none() && filter.end_date.is_none(),
+        };
+        filter
+            .start_date
+            .as_deref()
+            .is_none_or(|start| created_at >= start)
+            && filter
+                .end_date
+                .as_deref()
+                .is_none_or(|end| created_at < end)
+    }
+
+    fn lookup(row: &Value, path: &str) -> String {
+        let value = path
+            .split('.')
+            .try_fold(row, |value, key| value.get(key))
+            .unwrap_or(&Value::Null);
+        match value {
+            Value::Null => String::new(),
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        }
+    }
+}
+
+/// Writes rows incrementally as CSV lines or as elements of a JSON array
+struct RowWriter<'a, W: Write> {
+    writer: &'a mut W,
+    format: ExportFormat,
+    count: usize,
+}
+
+impl<'a, W: Write> RowWriter<'a, W> {
+    fn new(writer: &'a mut W, format: ExportFormat, columns: Vec<String>) -> Result<Self> {
+        match format {
+            ExportFormat::Csv => {
+                if !columns.is_empty() {
+                    write_csv_line(writer, &columns)?;
+                }
+            }
+            ExportFormat::Json => writer.write_all(b"[")?,
+        }
+        Ok(Self {
+            writer,
+            format,
+            count: 0,
+        })
+    }
+
+    fn write_row(&mut self, row: &Value, cells: &[String]) -> Result<()> {
+        match self.format {
+            ExportFormat::Csv => write_csv_line(self.writer, cells)?,
+            ExportFormat::Json => {
+                if self.count > 0 {
+                    self.writer.write_all(b",")?;
+                }
+                self.writer.write_all(b"\n")?;
+                serde_json::to_writer(&mut *self.writer, row)?;
+            }
+        }
+        self.count += 1;
+        Ok(())
+    }
+
+    fn finish(self) -> Result<usize> {
+        if self.format == ExportFormat::Json {
+            self.writer.write_all(b"\n]\n")?;
+        }
+        self.writer.flush()?;
+        Ok(self.count)
+    }
+}
+
+fn write_csv_line<W: Write>(writer: &mut W, cells: &[String]) -> std::io::Result<()> {
+    let line = cells
+        .iter()
+        .map(|cell| {
+            if cell.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", cell.replace('"', "\"\""))
+            } else {
+                cell.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    writeln!(writer, "{}", line)
+}
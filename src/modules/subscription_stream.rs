@@ -0,0 +1,184 @@
+//! Lazily paginated subscription listing, enabled with the `async` feature.
+
+use crate::client::TapsilatClient;
+use crate::error::{Result, TapsilatError};
+use crate::modules::pagination::RowPage;
+use crate::types::SubscriptionListItem;
+use futures_core::Stream;
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+const DEFAULT_PER_PAGE: u32 = 50;
+
+/// Stream of every subscription, created by
+/// [`SubscriptionModule::stream`](crate::modules::SubscriptionModule::stream)
+///
+/// Pages are requested one at a time as the stream is polled, on a background thread so
+/// the executor is never blocked. If a page does not arrive within the page timeout (the
+/// client timeout unless set with [`page_timeout`](Self::page_timeout)), the stream
+/// yields [`TapsilatError::Timeout`] and ends; so does any other error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use tapsilat::{Config, TapsilatClient};
+///
+/// let client = TapsilatClient::new(Config::new("api-key")).unwrap();
+/// let subscriptions = client
+///     .subscriptions()
+///     .stream()
+///     .per_page(100)
+///     .page_timeout(Duration::from_secs(10));
+/// // Drive it with e.g. `futures::StreamExt::next` or `tokio_stream::StreamExt::next`
+/// ```
+pub struct SubscriptionStream {
+    client: Arc<TapsilatClient>,
+    per_page: u32,
+    page_timeout: Duration,
+    next_page: u32,
+    items: VecDeque<SubscriptionListItem>,
+    pending: Option<Arc<Mutex<PendingPage>>>,
+    finished: bool,
+}
+
+#[derive(Default)]
+struct PendingPage {
+    result: Option<Result<Value>>,
+    waker: Option<Waker>,
+}
+
+impl SubscriptionStream {
+    pub(crate) fn new(client: Arc<TapsilatClient>) -> Self {
+        let page_timeout = Duration::from_secs(client.config().timeout);
+        Self {
+            client,
+            per_page: DEFAULT_PER_PAGE,
+            page_timeout,
+            next_page: 1,
+            items: VecDeque::new(),
+            pending: None,
+            finished: false,
+        }
+    }
+
+    /// Sets how many subscriptions are requested per page (default: 50)
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = per_page.max(1);
+        self
+    }
+
+    /// Sets how long to wait for each page before the stream fails
+    pub fn page_timeout(mut self, timeout: Duration) -> Self {
+        self.page_timeout = timeout;
+        self
+    }
+
+    fn fetch_page(&self) -> Arc<Mutex<PendingPage>> {
+        let endpoint = format!(
+            "subscription/list?page={}&per_page={}",
+            self.next_page, self.per_page
+        );
+        let url = format!(
+            "{}/{}",
+            self.client.config().base_url.trim_end_matches('/'),
+            endpoint
+        );
+        let pending = Arc::new(Mutex::new(PendingPage::default()));
+
+        let (sender, receiver) = mpsc::channel();
+        let client = Arc::clone(&self.client);
+        std::thread::spawn(move || {
+            let _ = sender.send(client.make_request::<()>("GET", &endpoint, None));
+        });
+
+        let slot = Arc::clone(&pending);
+        let timeout = self.page_timeout;
+        std::thread::spawn(move || {
+            let result = match receiver.recv_timeout(timeout) {
+                Ok(result) => result,
+                Err(RecvTimeoutError::Timeout) => Err(TapsilatError::Timeout { url }),
+                Err(RecvTimeoutError::Disconnected) => Err(TapsilatError::InvalidResponse(
+                    "Subscription page request ended without a response".to_string(),
+                )),
+            };
+            if let Ok(mut slot) = slot.lock() {
+                slot.result = Some(result);
+                if let Some(waker) = slot.waker.take() {
+                    waker.wake();
+                }
+            }
+        });
+        pending
+    }
+
+    fn take_page(&mut self, response: Value) -> Result<()> {
+        let page = RowPage::from_value(&response);
+        if !page.has_more(self.next_page, self.per_page) {
+            self.finished = true;
+        }
+        self.next_page += 1;
+        let items: Vec<SubscriptionListItem> = self
+            .client
+            .parse_typed(Value::Array(page.rows), "subscription list")?;
+        self.items.extend(items);
+        Ok(())
+    }
+}
+
+impl Stream for SubscriptionStream {
+    type Item = Result<SubscriptionListItem>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(item) = this.items.pop_front() {
+                return Poll::Ready(Some(Ok(item)));
+            }
+            if this.finished {
+                return Poll::Ready(None);
+            }
+
+            let pending = match &this.pending {
+                Some(pending) => Arc::clone(pending),
+                None => {
+                    let pending = this.fetch_page();
+                    this.pending = Some(Arc::clone(&pending));
+                    pending
+                }
+            };
+            let result = {
+                let Ok(mut slot) = pending.lock() else {
+                    this.finished = true;
+                    return Poll::Ready(None);
+                };
+                match slot.result.take() {
+                    Some(result) => result,
+                    None => {
+                        slot.waker = Some(cx.waker().clone());
+                        return Poll::Pending;
+                    }
+                }
+            };
+            this.pending = None;
+
+            if let Err(error) = result.and_then(|response| this.take_page(response)) {
+                this.finished = true;
+                return Poll::Ready(Some(Err(error)));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (self.items.len(), Some(self.items.len()))
+        } else {
+            (self.items.len(), None)
+        }
+    }
+}
@@ -1,11 +1,19 @@
-use crate::error::Result;
+use crate::error::{Result, TapsilatError};
+use crate::modules::batch::{run_bounded, DEFAULT_BATCH_CONCURRENCY};
+use crate::modules::validators::Validators;
 use crate::types::{
+    Detailed, SubscriptionBatchEntry, SubscriptionBatchOutcome, SubscriptionBatchReport,
     SubscriptionCancelRequest, SubscriptionCreateRequest, SubscriptionCreateResponse,
-    SubscriptionDetail, SubscriptionGetRequest, SubscriptionRedirectRequest,
-    SubscriptionRedirectResponse,
+    SubscriptionDetail, SubscriptionGetRequest, SubscriptionOrder, SubscriptionRedirectRequest,
+    SubscriptionRedirectResponse, SubscriptionRetryPaymentResponse, SubscriptionUpdateCardResponse,
+    UpcomingInvoice,
 };
+use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Longest free trial accepted when creating a subscription
+const MAX_TRIAL_DAYS: i32 = 365;
+
 pub struct SubscriptionModule {
     client: Arc<crate::client::TapsilatClient>,
 }
@@ -17,26 +25,95 @@ impl SubscriptionModule {
 
     /// Creates a new subscription
     pub fn create(&self, request: SubscriptionCreateRequest) -> Result<SubscriptionCreateResponse> {
+        self.validate_create_request(&request)?;
         let endpoint = "subscription/create";
         let response = self.client.make_request("POST", endpoint, Some(&request))?;
-        serde_json::from_value(response).map_err(|e| {
-            crate::error::TapsilatError::ConfigError(format!(
-                "Failed to parse subscription create response: {}",
-                e
-            ))
-        })
+        self.client
+            .decode_response(response, "subscription create response")
+    }
+
+    /// Creates many subscriptions concurrently, e.g. when migrating from another provider
+    ///
+    /// Requests are spread over a bounded pool of [`DEFAULT_BATCH_CONCURRENCY`] workers.
+    /// A request repeating an earlier `external_reference_id` in the batch is not sent,
+    /// and one the API rejects as already existing is reported as a duplicate rather
+    /// than a failure.
+    pub fn create_batch(
+        &self,
+        requests: Vec<SubscriptionCreateRequest>,
+    ) -> SubscriptionBatchReport {
+        self.create_batch_with_concurrency(requests, DEFAULT_BATCH_CONCURRENCY)
+    }
+
+    /// Creates many subscriptions using at most `concurrency` parallel requests
+    pub fn create_batch_with_concurrency(
+        &self,
+        requests: Vec<SubscriptionCreateRequest>,
+        concurrency: usize,
+    ) -> SubscriptionBatchReport {
+        let mut first_seen: HashMap<&str, usize> = HashMap::new();
+        let earlier: Vec<Option<usize>> = requests
+            .iter()
+            .enumerate()
+            .map(|(index, request)| {
+                let external_reference_id = request.external_reference_id.as_deref()?;
+                match first_seen.get(external_reference_id) {
+                    Some(first) => Some(*first),
+                    None => {
+                        first_seen.insert(external_reference_id, index);
+                        None
+                    }
+                }
+            })
+            .collect();
+
+        let jobs: Vec<(&SubscriptionCreateRequest, Option<usize>)> =
+            requests.iter().zip(earlier).collect();
+        let outcomes = run_bounded(&jobs, concurrency, |(request, earlier)| {
+            if let Some(first_index) = earlier {
+                return SubscriptionBatchOutcome::Duplicate {
+                    first_index: Some(*first_index),
+                };
+            }
+            match self.create((*request).clone()) {
+                Ok(response) => SubscriptionBatchOutcome::Created(response),
+                Err(e) if Self::is_duplicate_subscription(&e) => {
+                    SubscriptionBatchOutcome::Duplicate { first_index: None }
+                }
+                Err(e) => SubscriptionBatchOutcome::Failed(e),
+            }
+        });
+
+        let entries = requests
+            .iter()
+            .zip(outcomes)
+            .enumerate()
+            .map(|(index, (request, outcome))| SubscriptionBatchEntry {
+                index,
+                external_reference_id: request.external_reference_id.clone(),
+                outcome,
+            })
+            .collect();
+        SubscriptionBatchReport { entries }
     }
 
     /// Gets subscription details
     pub fn get(&self, request: SubscriptionGetRequest) -> Result<SubscriptionDetail> {
         let endpoint = "subscription";
         let response = self.client.make_request("POST", endpoint, Some(&request))?;
-        serde_json::from_value(response).map_err(|e| {
-            crate::error::TapsilatError::ConfigError(format!(
-                "Failed to parse subscription detail response: {}",
-                e
-            ))
-        })
+        self.client
+            .decode_response(response, "subscription detail response")
+    }
+
+    /// Gets a subscription, also returning the raw response, status and headers
+    pub fn get_detailed(
+        &self,
+        request: SubscriptionGetRequest,
+    ) -> Result<Detailed<SubscriptionDetail>> {
+        let endpoint = "subscription";
+        let response = self.client.make_request("POST", endpoint, Some(&request))?;
+        self.client
+            .decode_detailed(response, "subscription detail response")
     }
 
     /// Cancels a subscription
@@ -52,6 +129,15 @@ impl SubscriptionModule {
         self.client.make_request::<()>("GET", &endpoint, None)
     }
 
+    /// Streams all subscriptions, fetching each page only when the previous one is used up
+    ///
+    /// See [`SubscriptionStream`](crate::modules::SubscriptionStream) for the page size and
+    /// per-page deadline.
+    #[cfg(feature = "async")]
+    pub fn stream(&self) -> crate::modules::SubscriptionStream {
+        crate::modules::SubscriptionStream::new(Arc::clone(&self.client))
+    }
+
     /// Gets redirect URL for a subscription
     pub fn redirect(
         &self,
@@ -59,11 +145,164 @@ impl SubscriptionModule {
     ) -> Result<SubscriptionRedirectResponse> {
         let endpoint = "subscription/redirect";
         let response = self.client.make_request("POST", endpoint, Some(&request))?;
-        serde_json::from_value(response).map_err(|e| {
-            crate::error::TapsilatError::ConfigError(format!(
-                "Failed to parse subscription redirect response: {}",
-                e
-            ))
-        })
+        self.client
+            .decode_response(response, "subscription redirect response")
+    }
+
+    /// Retries the payment of a subscription's failed renewal
+    pub fn retry_payment(&self, reference_id: &str) -> Result<SubscriptionRetryPaymentResponse> {
+        let endpoint = "subscription/retry-payment";
+        let payload = serde_json::json!({ "reference_id": reference_id });
+        let response = self.client.make_request("POST", endpoint, Some(&payload))?;
+        self.client
+            .decode_response(response, "subscription retry payment response")
+    }
+
+    /// Returns the renewal orders of a subscription whose payment failed
+    pub fn get_failed_orders(&self, reference_id: &str) -> Result<Vec<SubscriptionOrder>> {
+        let detail = self.get(SubscriptionGetRequest {
+            external_reference_id: None,
+            reference_id: Some(reference_id.to_string()),
+        })?;
+
+        Ok(detail
+            .orders
+            .unwrap_or_default()
+            .into_iter()
+            .filter(SubscriptionOrder::is_failed)
+            .collect())
+    }
+
+    /// Previews the next charge of a subscription
+    ///
+    /// Use [`UpcomingInvoice::prorate`] on the result to show what a plan change
+    /// would cost before applying it.
+    pub fn preview_upcoming(&self, reference_id: &str) -> Result<UpcomingInvoice> {
+        if reference_id.is_empty() {
+            return Err(TapsilatError::ValidationError(
+                "Subscription reference ID cannot be empty".to_string(),
+            ));
+        }
+
+        let endpoint = "subscription/upcoming";
+        let payload = serde_json::json!({ "reference_id": reference_id });
+        let response = self.client.make_request("POST", endpoint, Some(&payload))?;
+        self.client
+            .decode_response(response, "subscription upcoming invoice response")
+    }
+
+    /// Replaces the card charged for an active subscription with a saved card
+    pub fn update_card(
+        &self,
+        reference_id: &str,
+        card_id: &str,
+    ) -> Result<SubscriptionUpdateCardResponse> {
+        if reference_id.is_empty() || card_id.is_empty() {
+            return Err(crate::error::TapsilatError::ValidationError(
+                "Subscription reference ID and card ID cannot be empty".to_string(),
+            ));
+        }
+
+        let endpoint = "subscription/update-card";
+        let payload = serde_json::json!({ "reference_id": reference_id, "card_id": card_id });
+        let response = self.client.make_request("POST", endpoint, Some(&payload))?;
+        self.client
+            .decode_response(response, "subscription update card response")
+    }
+
+    /// Gets a hosted page URL where the customer can enter a new card for a subscription
+    pub fn card_update_url(&self, reference_id: &str) -> Result<SubscriptionRedirectResponse> {
+        let endpoint = "subscription/card-update-url";
+        let payload = serde_json::json!({ "reference_id": reference_id });
+        let response = self.client.make_request("POST", endpoint, Some(&payload))?;
+        self.client
+            .decode_response(response, "subscription card update url response")
+    }
+
+    /// Returns whether `error` rejects a subscription whose external reference is taken
+    ///
+    /// Only a 409 Conflict or a uniqueness error on the `external_reference_id` field
+    /// counts; other messages that merely mention an existing resource do not.
+    fn is_duplicate_subscription(error: &TapsilatError) -> bool {
+        match error {
+            TapsilatError::ApiError {
+                status_code: 409, ..
+            } => true,
+            TapsilatError::ValidationFailed { fields, .. } => fields.iter().any(|f| {
+                let message = f.message.to_ascii_lowercase();
+                f.field == "external_reference_id"
+                    && ["already", "duplicate", "exist", "unique", "taken"]
+                        .iter()
+                        .any(|word| message.contains(word))
+            }),
+            _ => false,
+        }
+    }
+
+    /// Returns the current date in Turkey, which has stayed on UTC+3 all year since 2016
+    fn today_in_turkey() -> chrono::NaiveDate {
+        let offset = chrono::FixedOffset::east_opt(3 * 3600).expect("UTC+3 is a valid offset");
+        chrono::Utc::now().with_timezone(&offset).date_naive()
+    }
+
+    /// Validates create subscription request
+    fn validate_create_request(&self, request: &SubscriptionCreateRequest) -> Result<()> {
+        if let Some(recurring) = &request.recurring {
+            Validators::validate_recurring_indicator(recurring)?;
+        }
+
+        let require_https = self.client.config().require_https_urls;
+        for url in [&request.success_url, &request.failure_url]
+            .into_iter()
+            .flatten()
+        {
+            Validators::validate_url_with(url, require_https)?;
+        }
+
+        if request.trial_days.is_some() && request.trial_end_date.is_some() {
+            return Err(TapsilatError::ValidationError(
+                "Set either trial_days or trial_end_date, not both".to_string(),
+            ));
+        }
+
+        if let Some(days) = request.trial_days {
+            if !(1..=MAX_TRIAL_DAYS).contains(&days) {
+                return Err(TapsilatError::ValidationError(format!(
+                    "Trial days must be between 1 and {}",
+                    MAX_TRIAL_DAYS
+                )));
+            }
+        }
+
+        // Trial dates count from today's date in Turkey (UTC+3), not from UTC, so
+        // "tomorrow" means the next calendar day for a Turkish merchant
+        let today = Self::today_in_turkey();
+        if let Some(end_date) = &request.trial_end_date {
+            let end_date =
+                chrono::NaiveDate::parse_from_str(end_date, "%Y-%m-%d").map_err(|_| {
+                    TapsilatError::ValidationError(format!(
+                        "Trial end date must be formatted as YYYY-MM-DD: {}",
+                        end_date
+                    ))
+                })?;
+            let days_until_end = (end_date - today).num_days();
+            if !(1..=MAX_TRIAL_DAYS as i64).contains(&days_until_end) {
+                return Err(TapsilatError::ValidationError(format!(
+                    "Trial end date must be between tomorrow and {} days from today",
+                    MAX_TRIAL_DAYS
+                )));
+            }
+        }
+
+        // With a trial the first charge is the first payment day after the trial ends,
+        // which only exists if the payment date is a day of the month
+        let has_trial = request.trial_days.is_some() || request.trial_end_date.is_some();
+        if has_trial && request.first_charge_date(today).is_none() {
+            return Err(TapsilatError::ValidationError(
+                "Payment date must be a day of the month between 1 and 31".to_string(),
+            ));
+        }
+
+        Ok(())
     }
 }
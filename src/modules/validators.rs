@@ -1,5 +1,36 @@
 use crate::error::{Result, TapsilatError};
+use crate::geo;
+use crate::types::{
+    from_minor_units, to_minor_units, BasketItemDTO, CreateBuyerRequest, ItemCapture, OrderCardDTO,
+    PaymentTermDTO, RecurringIndicator, RecurringSequence,
+};
+use chrono::Datelike;
 use regex::Regex;
+use std::net::IpAddr;
+
+/// How buyer phone numbers are checked before an order is sent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhoneValidation {
+    /// Only Turkish mobile numbers, see [`Validators::validate_gsm`]
+    TurkishGsm,
+    /// Any number in international format, see [`Validators::validate_phone_e164`]
+    E164,
+}
+
+/// Assigned ITU-T E.164 country calling codes
+const COUNTRY_CALLING_CODES: &[u16] = &[
+    1, 7, 20, 27, 30, 31, 32, 33, 34, 36, 39, 40, 41, 43, 44, 45, 46, 47, 48, 49, 51, 52, 53, 54,
+    55, 56, 57, 58, 60, 61, 62, 63, 64, 65, 66, 81, 82, 84, 86, 90, 91, 92, 93, 94, 95, 98, 211,
+    212, 213, 216, 218, 220, 221, 222, 223, 224, 225, 226, 227, 228, 229, 230, 231, 232, 233, 234,
+    235, 236, 237, 238, 239, 240, 241, 242, 243, 244, 245, 246, 247, 248, 249, 250, 251, 252, 253,
+    254, 255, 256, 257, 258, 260, 261, 262, 263, 264, 265, 266, 267, 268, 269, 290, 291, 297, 298,
+    299, 350, 351, 352, 353, 354, 355, 356, 357, 358, 359, 370, 371, 372, 373, 374, 375, 376, 377,
+    378, 380, 381, 382, 383, 385, 386, 387, 389, 420, 421, 423, 500, 501, 502, 503, 504, 505, 506,
+    507, 508, 509, 590, 591, 592, 593, 594, 595, 596, 597, 598, 599, 670, 672, 673, 674, 675, 676,
+    677, 678, 679, 680, 681, 682, 683, 685, 686, 687, 688, 689, 690, 691, 692, 850, 852, 853, 855,
+    856, 880, 886, 960, 961, 962, 963, 964, 965, 966, 967, 968, 970, 971, 972, 973, 974, 975, 976,
+    977, 992, 993, 994, 995, 996, 998,
+];
 
 pub struct Validators;
 
@@ -49,6 +80,59 @@ impl Validators {
         Ok(format!("90{}", normalized))
     }
 
+    /// Validates phone numbers in international E.164 format
+    /// Accepts a leading `+` or `00` and ignores spaces, dashes, dots and parentheses.
+    /// The country calling code must be assigned, and Turkish (`+90`) numbers must have
+    /// a 10 digit national number. Returns the number as `+` followed by digits.
+    pub fn validate_phone_e164(phone: &str) -> Result<String> {
+        let phone = phone.trim().replace([' ', '-', '.', '(', ')'], "");
+        let digits = phone
+            .strip_prefix('+')
+            .or_else(|| phone.strip_prefix("00"))
+            .ok_or_else(|| {
+                TapsilatError::ValidationError(
+                    "Phone number must start with + and a country code".to_string(),
+                )
+            })?;
+
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(TapsilatError::ValidationError(
+                "Phone number must contain only digits".to_string(),
+            ));
+        }
+
+        if !(8..=15).contains(&digits.len()) {
+            return Err(TapsilatError::ValidationError(
+                "Phone number must be 8 to 15 digits long including the country code".to_string(),
+            ));
+        }
+
+        let country_code = (1..=3)
+            .filter_map(|len| digits[..len].parse::<u16>().ok())
+            .find(|code| COUNTRY_CALLING_CODES.contains(code))
+            .ok_or_else(|| {
+                TapsilatError::ValidationError(format!("Unknown country calling code in {}", phone))
+            })?;
+
+        let national = &digits[country_code.to_string().len()..];
+        if country_code == 90 && national.len() != 10 {
+            return Err(TapsilatError::ValidationError(
+                "Turkish phone numbers must have 10 digits after +90".to_string(),
+            ));
+        }
+
+        Ok(format!("+{}", digits))
+    }
+
+    /// Validates a buyer phone number with the given strategy
+    /// Returns the normalized number of the chosen validator
+    pub fn validate_phone(phone: &str, mode: PhoneValidation) -> Result<String> {
+        match mode {
+            PhoneValidation::TurkishGsm => Self::validate_gsm(phone),
+            PhoneValidation::E164 => Self::validate_phone_e164(phone),
+        }
+    }
+
     /// Validates installment count
     pub fn validate_installments(installments: u8) -> Result<()> {
         const VALID_INSTALLMENTS: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
@@ -77,6 +161,123 @@ impl Validators {
         Ok(())
     }
 
+    /// Validates a Turkish province name, plate code or common alias
+    /// Returns the official name, e.g. `İstanbul` for "istanbul"
+    pub fn validate_city(city: &str) -> Result<&'static str> {
+        geo::find_province(city)
+            .map(|province| province.name)
+            .ok_or_else(|| {
+                TapsilatError::ValidationError(format!("Unknown Turkish province: {}", city))
+            })
+    }
+
+    /// Validates that a district belongs to a Turkish province
+    /// Returns the official district name
+    pub fn validate_district(city: &str, district: &str) -> Result<&'static str> {
+        let province = geo::find_province(city).ok_or_else(|| {
+            TapsilatError::ValidationError(format!("Unknown Turkish province: {}", city))
+        })?;
+        province.find_district(district).ok_or_else(|| {
+            TapsilatError::ValidationError(format!(
+                "{} is not a district of {}",
+                district, province.name
+            ))
+        })
+    }
+
+    /// Validates an IPv4 or IPv6 address
+    /// Surrounding whitespace is ignored; returns the address in canonical form
+    pub fn validate_ip(ip: &str) -> Result<String> {
+        ip.trim()
+            .parse::<IpAddr>()
+            .map(|ip| ip.to_canonical().to_string())
+            .map_err(|_| TapsilatError::ValidationError(format!("Invalid IP address: {}", ip)))
+    }
+
+    /// Validates a callback or redirect URL
+    ///
+    /// The URL must be absolute with an `http` or `https` scheme and a host, and may
+    /// not contain whitespace or a `#fragment`, which the API would drop when
+    /// redirecting.
+    pub fn validate_url(url: &str) -> Result<()> {
+        Self::validate_url_with(url, false)
+    }
+
+    /// Validates a callback or redirect URL, optionally rejecting plain `http`
+    pub fn validate_url_with(url: &str, require_https: bool) -> Result<()> {
+        let invalid = |reason: &str| {
+            TapsilatError::ValidationError(format!("Invalid URL {}: {}", url, reason))
+        };
+
+        if url.chars().any(|c| c.is_whitespace() || c.is_control()) {
+            return Err(invalid("contains whitespace"));
+        }
+        let (scheme, rest) = url
+            .split_once("://")
+            .ok_or_else(|| invalid("must be absolute"))?;
+        match scheme.to_ascii_lowercase().as_str() {
+            "https" => {}
+            "http" if !require_https => {}
+            "http" => return Err(invalid("must use https")),
+            _ => return Err(invalid("scheme must be http or https")),
+        }
+        if url.contains('#') {
+            return Err(invalid("must not contain a fragment"));
+        }
+
+        let authority = rest.split(['/', '?']).next().unwrap_or_default();
+        if authority.contains('@') {
+            return Err(invalid("must not contain credentials"));
+        }
+        let host = match authority.strip_prefix('[') {
+            Some(ipv6) => ipv6
+                .split_once(']')
+                .map(|(host, _)| host)
+                .unwrap_or_default(),
+            None => match authority.rsplit_once(':') {
+                Some((host, port))
+                    if !port.is_empty() && port.bytes().all(|b| b.is_ascii_digit()) =>
+                {
+                    host
+                }
+                Some(_) => return Err(invalid("has an invalid port")),
+                None => authority,
+            },
+        };
+        let host_valid = if authority.starts_with('[') {
+            host.parse::<std::net::Ipv6Addr>().is_ok()
+        } else {
+            host.split('.').all(|label| {
+                !label.is_empty() && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+            })
+        };
+        if !host_valid {
+            return Err(invalid("has no valid host"));
+        }
+
+        Ok(())
+    }
+
+    /// Validates conversation IDs (1-64 characters of letters, digits, `-`, `_` and `.`)
+    pub fn validate_conversation_id(conversation_id: &str) -> Result<()> {
+        if conversation_id.is_empty() || conversation_id.len() > 64 {
+            return Err(TapsilatError::ValidationError(
+                "Conversation ID must be between 1 and 64 characters".to_string(),
+            ));
+        }
+
+        if !conversation_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        {
+            return Err(TapsilatError::ValidationError(
+                "Conversation ID may only contain letters, digits, '-', '_' and '.'".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Validates Turkish identity number (TC Kimlik No)
     pub fn validate_identity_number(identity: &str) -> Result<()> {
         let identity = identity.trim();
@@ -127,6 +328,17 @@ impl Validators {
         Ok(())
     }
 
+    /// Validates a buyer's identity number with the validator for their citizenship
+    /// See [`identity::validator_for`](crate::identity::validator_for); buyers without an
+    /// identity number pass.
+    pub fn validate_buyer_identity(buyer: &CreateBuyerRequest) -> Result<()> {
+        match &buyer.identity_number {
+            Some(identity_number) => crate::identity::validator_for(buyer.citizenship.as_deref())
+                .validate(identity_number),
+            None => Ok(()),
+        }
+    }
+
     /// Validates amount (must be positive and have max 2 decimal places)
     pub fn validate_amount(amount: f64) -> Result<()> {
         if amount <= 0.0 {
@@ -151,6 +363,294 @@ impl Validators {
 
         Ok(())
     }
+
+    /// Validates a refund amount against the remaining refundable balance
+    pub fn validate_refund_amount(amount: f64, refundable: f64) -> Result<()> {
+        Self::validate_amount(amount)?;
+
+        if to_minor_units(amount) > to_minor_units(refundable) {
+            return Err(TapsilatError::ValidationError(format!(
+                "Refund amount {:.2} exceeds the refundable balance of {:.2}",
+                amount, refundable
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Validates that discounted basket item totals add up to the order amount
+    pub fn validate_basket_total(items: &[BasketItemDTO], order_amount: f64) -> Result<()> {
+        let mut total = 0;
+        for item in items {
+            let discount = to_minor_units(item.coupon_discount.unwrap_or(0.0));
+            if discount < 0 || discount > to_minor_units(item.line_total()) {
+                return Err(TapsilatError::ValidationError(format!(
+                    "Coupon discount on item {} is out of range",
+                    item.id.as_deref().or(item.name.as_deref()).unwrap_or("?")
+                )));
+            }
+            total += to_minor_units(item.discounted_total());
+        }
+
+        let expected = to_minor_units(order_amount);
+        if total != expected {
+            return Err(TapsilatError::ValidationError(format!(
+                "Basket items total {:.2} after discounts but the order amount is {:.2}",
+                from_minor_units(total),
+                from_minor_units(expected)
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Validates a card number with the Luhn checksum
+    /// Spaces and dashes are ignored; returns the bare digits
+    pub fn validate_card_number(card_number: &str) -> Result<String> {
+        let digits = card_number.trim().replace([' ', '-'], "");
+
+        if !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(TapsilatError::ValidationError(
+                "Card number must contain only digits".to_string(),
+            ));
+        }
+
+        if !(12..=19).contains(&digits.len()) {
+            return Err(TapsilatError::ValidationError(
+                "Card number must be 12 to 19 digits long".to_string(),
+            ));
+        }
+
+        let checksum: u32 = digits
+            .bytes()
+            .rev()
+            .enumerate()
+            .map(|(index, byte)| {
+                let digit = u32::from(byte - b'0');
+                match (index % 2 == 1, digit * 2) {
+                    (false, _) => digit,
+                    (true, doubled) if doubled > 9 => doubled - 9,
+                    (true, doubled) => doubled,
+                }
+            })
+            .sum();
+        if !checksum.is_multiple_of(10) {
+            return Err(TapsilatError::ValidationError(
+                "Card number failed the Luhn check".to_string(),
+            ));
+        }
+
+        Ok(digits)
+    }
+
+    /// Validates an IBAN with the ISO 13616 mod-97 check digits
+    /// Spaces are ignored and letters upper-cased; returns the compact IBAN.
+    /// Turkish (`TR`) IBANs must be 26 characters long.
+    pub fn validate_iban(iban: &str) -> Result<String> {
+        let iban: String = iban
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+
+        if !iban.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(TapsilatError::ValidationError(
+                "IBAN must contain only letters and digits".to_string(),
+            ));
+        }
+
+        let bytes = iban.as_bytes();
+        if !(15..=34).contains(&bytes.len())
+            || !bytes[..2].iter().all(u8::is_ascii_uppercase)
+            || !bytes[2..4].iter().all(u8::is_ascii_digit)
+        {
+            return Err(TapsilatError::ValidationError(
+                "IBAN must start with a country code and two check digits and be 15 to 34 characters long"
+                    .to_string(),
+            ));
+        }
+
+        if iban.starts_with("TR") && iban.len() != 26 {
+            return Err(TapsilatError::ValidationError(
+                "Turkish IBAN must be 26 characters long".to_string(),
+            ));
+        }
+
+        let remainder = iban[4..]
+            .chars()
+            .chain(iban[..4].chars())
+            .fold(0u32, |remainder, c| {
+                let value = c.to_digit(36).unwrap_or(0);
+                if value < 10 {
+                    (remainder * 10 + value) % 97
+                } else {
+                    (remainder * 100 + value) % 97
+                }
+            });
+        if remainder != 1 {
+            return Err(TapsilatError::ValidationError(
+                "IBAN check digits are invalid".to_string(),
+            ));
+        }
+
+        Ok(iban)
+    }
+
+    /// Validates a card expiry date (month 1-12, not before the current month)
+    pub fn validate_card_expiry(month: u8, year: u16) -> Result<()> {
+        if !(1..=12).contains(&month) {
+            return Err(TapsilatError::ValidationError(format!(
+                "Invalid expiry month: {}. Valid values are 1-12",
+                month
+            )));
+        }
+
+        let today = chrono::Utc::now().date_naive();
+        let current = (today.year(), today.month());
+        if (i32::from(year), u32::from(month)) < current {
+            return Err(TapsilatError::ValidationError(format!(
+                "Card expired in {:02}/{}",
+                month, year
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Validates stored-credential indicators (subsequent charges reference the initial
+    /// one, initial charges are customer-initiated)
+    pub fn validate_recurring_indicator(indicator: &RecurringIndicator) -> Result<()> {
+        let has_reference = indicator
+            .original_transaction_reference
+            .as_deref()
+            .is_some_and(|reference| !reference.trim().is_empty());
+
+        match indicator.sequence {
+            RecurringSequence::Initial if indicator.merchant_initiated => {
+                Err(TapsilatError::ValidationError(
+                    "The initial recurring charge must be customer-initiated".to_string(),
+                ))
+            }
+            RecurringSequence::Initial if has_reference => Err(TapsilatError::ValidationError(
+                "The initial recurring charge cannot reference an original transaction".to_string(),
+            )),
+            RecurringSequence::Subsequent if !has_reference => Err(TapsilatError::ValidationError(
+                "Subsequent recurring charges need the original transaction reference".to_string(),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Validates a split of an order across cards (unique sequences, amounts summing to the order amount)
+    pub fn validate_card_split(cards: &[OrderCardDTO], order_amount: f64) -> Result<()> {
+        let mut sequences = std::collections::HashSet::new();
+        if let Some(card) = cards.iter().find(|c| !sequences.insert(c.card_sequence)) {
+            return Err(TapsilatError::ValidationError(format!(
+                "Card sequence {} is used more than once",
+                card.card_sequence
+            )));
+        }
+
+        if cards.len() == 1 && cards[0].amount.is_none() {
+            return Ok(());
+        }
+
+        let mut total = 0;
+        for card in cards {
+            let amount = card.amount.ok_or_else(|| {
+                TapsilatError::ValidationError(format!(
+                    "Card {} needs an amount when an order is split across cards",
+                    card.card_sequence
+                ))
+            })?;
+            Self::validate_amount(amount)?;
+            total += to_minor_units(amount);
+        }
+
+        let expected = to_minor_units(order_amount);
+        if total != expected {
+            return Err(TapsilatError::ValidationError(format!(
+                "Card amounts sum to {:.2} but the order amount is {:.2}",
+                from_minor_units(total),
+                from_minor_units(expected)
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Validates a per-item capture (known, distinct items, each within its basket total,
+    /// summing to the capture amount)
+    pub fn validate_item_captures(
+        captures: &[ItemCapture],
+        basket_items: &[BasketItemDTO],
+        capture_amount: f64,
+    ) -> Result<()> {
+        let mut seen = std::collections::HashSet::new();
+        let mut total = 0;
+        for capture in captures {
+            if !seen.insert(capture.basket_item_id.as_str()) {
+                return Err(TapsilatError::ValidationError(format!(
+                    "Basket item {} is captured more than once",
+                    capture.basket_item_id
+                )));
+            }
+
+            let item = basket_items
+                .iter()
+                .find(|item| item.id.as_deref() == Some(capture.basket_item_id.as_str()))
+                .ok_or_else(|| {
+                    TapsilatError::ValidationError(format!(
+                        "Basket item {} is not part of the order",
+                        capture.basket_item_id
+                    ))
+                })?;
+
+            Self::validate_amount(capture.amount)?;
+            let item_total = item.discounted_total();
+            if to_minor_units(capture.amount) > to_minor_units(item_total) {
+                return Err(TapsilatError::ValidationError(format!(
+                    "Capture of {:.2} for basket item {} exceeds its total of {:.2}",
+                    capture.amount, capture.basket_item_id, item_total
+                )));
+            }
+            total += to_minor_units(capture.amount);
+        }
+
+        let expected = to_minor_units(capture_amount);
+        if total != expected {
+            return Err(TapsilatError::ValidationError(format!(
+                "Item captures sum to {:.2} but the capture amount is {:.2}",
+                from_minor_units(total),
+                from_minor_units(expected)
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Validates payment terms (every term has a positive amount and the terms sum to the order amount)
+    pub fn validate_payment_terms(terms: &[PaymentTermDTO], order_amount: f64) -> Result<()> {
+        let mut total = 0;
+        for (index, term) in terms.iter().enumerate() {
+            let amount = term.amount.ok_or_else(|| {
+                TapsilatError::ValidationError(format!("Payment term {} has no amount", index + 1))
+            })?;
+            Self::validate_amount(amount)?;
+            total += to_minor_units(amount);
+        }
+
+        let expected = to_minor_units(order_amount);
+        if total != expected {
+            return Err(TapsilatError::ValidationError(format!(
+                "Payment terms sum to {:.2} but the order amount is {:.2}",
+                from_minor_units(total),
+                from_minor_units(expected)
+            )));
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -168,6 +668,141 @@ mod tests {
         assert!(Validators::validate_gsm("4551234567").is_err()); // Doesn't start with 5
     }
 
+    #[test]
+    fn test_card_validation() {
+        assert_eq!(
+            Validators::validate_card_number("4111 1111 1111 1111").unwrap(),
+            "4111111111111111"
+        );
+        assert!(Validators::validate_card_number("4111111111111112").is_err()); // Bad checksum
+        assert!(Validators::validate_card_number("4111-abcd").is_err());
+
+        assert!(Validators::validate_card_expiry(12, 2099).is_ok());
+        assert!(Validators::validate_card_expiry(13, 2099).is_err());
+        assert!(Validators::validate_card_expiry(1, 2020).is_err());
+    }
+
+    #[test]
+    fn test_iban_validation() {
+        assert_eq!(
+            Validators::validate_iban("tr33 0006 1005 1978 6457 8413 26").unwrap(),
+            "TR330006100519786457841326"
+        );
+        assert!(Validators::validate_iban("GB82 WEST 1234 5698 7654 32").is_ok());
+        assert!(Validators::validate_iban("TR340006100519786457841326").is_err()); // Bad check digits
+        assert!(Validators::validate_iban("TR3300061005197864578413").is_err()); // Too short for TR
+        assert!(Validators::validate_iban("TR33-0006-1005").is_err());
+    }
+
+    #[test]
+    fn test_city_validation() {
+        assert_eq!(geo::PROVINCES.len(), 81);
+        assert_eq!(
+            geo::PROVINCES
+                .iter()
+                .map(|province| province.districts.len())
+                .sum::<usize>(),
+            973
+        );
+
+        assert_eq!(Validators::validate_city("ISTANBUL").unwrap(), "İstanbul");
+        assert_eq!(Validators::validate_city("Içel").unwrap(), "Mersin");
+        assert_eq!(Validators::validate_city("06").unwrap(), "Ankara");
+        assert!(Validators::validate_city("Atlantis").is_err());
+        assert!(Validators::validate_city("82").is_err());
+
+        assert_eq!(
+            Validators::validate_district("izmir", "karsiyaka").unwrap(),
+            "Karşıyaka"
+        );
+        assert_eq!(
+            Validators::validate_district("Samsun", "19 Mayıs").unwrap(),
+            "19 Mayıs"
+        );
+        assert!(Validators::validate_district("Ankara", "Kadıköy").is_err());
+        assert!(geo::is_turkey("Türkiye"));
+        assert!(!geo::is_turkey("Germany"));
+    }
+
+    #[test]
+    fn test_ip_validation() {
+        assert_eq!(
+            Validators::validate_ip(" 192.168.1.10 ").unwrap(),
+            "192.168.1.10"
+        );
+        assert_eq!(
+            Validators::validate_ip("2001:0db8:0000:0000:0000:0000:0000:0001").unwrap(),
+            "2001:db8::1"
+        );
+        assert_eq!(
+            Validators::validate_ip("::ffff:10.0.0.1").unwrap(),
+            "10.0.0.1"
+        );
+        assert!(Validators::validate_ip("256.1.1.1").is_err());
+        assert!(Validators::validate_ip("10.0.0.1:8080").is_err());
+        assert!(Validators::validate_ip("").is_err());
+    }
+
+    #[test]
+    fn test_url_validation() {
+        assert!(Validators::validate_url("https://shop.example.com/checkout/success?id=1").is_ok());
+        assert!(Validators::validate_url("http://localhost:8080/callback").is_ok());
+        assert!(Validators::validate_url("https://[::1]:8443/ok").is_ok());
+
+        assert!(Validators::validate_url("shop.example.com/success").is_err()); // Relative
+        assert!(Validators::validate_url("ftp://shop.example.com/").is_err());
+        assert!(Validators::validate_url("https://shop.example.com/#done").is_err());
+        assert!(Validators::validate_url("https://shop example.com/").is_err());
+        assert!(Validators::validate_url("https://user:pw@shop.example.com/").is_err());
+        assert!(Validators::validate_url("https://shop.example.com:80a/").is_err());
+        assert!(Validators::validate_url("https:///success").is_err()); // No host
+
+        assert!(Validators::validate_url_with("http://shop.example.com/", true).is_err());
+        assert!(Validators::validate_url_with("HTTPS://shop.example.com/", true).is_ok());
+    }
+
+    #[test]
+    fn test_phone_e164_validation() {
+        assert_eq!(
+            Validators::validate_phone_e164("+90 555 123 45 67").unwrap(),
+            "+905551234567"
+        );
+        assert_eq!(
+            Validators::validate_phone_e164("0049 (30) 1234-5678").unwrap(),
+            "+493012345678"
+        );
+        assert_eq!(
+            Validators::validate_phone_e164("+1 212.555.0100").unwrap(),
+            "+12125550100"
+        );
+        assert!(Validators::validate_phone_e164("05551234567").is_err()); // No country code
+        assert!(Validators::validate_phone_e164("+90555123456").is_err()); // TR too short
+        assert!(Validators::validate_phone_e164("+2891234567").is_err()); // Unassigned code
+        assert!(Validators::validate_phone_e164("+4412345678901234").is_err()); // Too long
+
+        assert!(Validators::validate_phone("+447911123456", PhoneValidation::TurkishGsm).is_err());
+        assert!(Validators::validate_phone("+447911123456", PhoneValidation::E164).is_ok());
+    }
+
+    #[test]
+    fn test_buyer_identity_validation() {
+        let mut buyer = CreateBuyerRequest {
+            identity_number: Some("10000000146".to_string()),
+            ..Default::default()
+        };
+        assert!(Validators::validate_buyer_identity(&buyer).is_ok());
+
+        buyer.identity_number = Some("U12345678".to_string());
+        assert!(Validators::validate_buyer_identity(&buyer).is_err());
+        buyer.citizenship = Some("GB".to_string());
+        assert!(Validators::validate_buyer_identity(&buyer).is_ok());
+        buyer.identity_number = Some("U123-45".to_string());
+        assert!(Validators::validate_buyer_identity(&buyer).is_err());
+
+        buyer.identity_number = None;
+        assert!(Validators::validate_buyer_identity(&buyer).is_ok());
+    }
+
     #[test]
     fn test_installment_validation() {
         assert!(Validators::validate_installments(1).is_ok());
@@ -191,4 +826,46 @@ mod tests {
         assert!(Validators::validate_amount(0.0).is_err());
         assert!(Validators::validate_amount(10.555).is_err()); // Too many decimals
     }
+
+    #[test]
+    fn test_payment_terms_validation() {
+        let term = |amount: Option<f64>| PaymentTermDTO {
+            amount,
+            data: None,
+            due_date: None,
+            paid_date: None,
+            required: None,
+            status: None,
+            term_reference_id: None,
+            term_sequence: None,
+        };
+
+        let terms = [term(Some(33.34)), term(Some(33.33)), term(Some(33.33))];
+        assert!(Validators::validate_payment_terms(&terms, 100.0).is_ok());
+        assert!(Validators::validate_payment_terms(&terms, 100.01).is_err());
+        assert!(Validators::validate_payment_terms(&[term(None)], 100.0).is_err());
+        assert!(Validators::validate_payment_terms(&[term(Some(0.0))], 0.0).is_err());
+    }
+
+    #[test]
+    fn test_conversation_id_validation() {
+        assert!(Validators::validate_conversation_id("order_1").is_ok());
+        assert!(Validators::validate_conversation_id(&"a".repeat(64)).is_ok());
+        assert!(Validators::validate_conversation_id("").is_err());
+        assert!(Validators::validate_conversation_id("order 1").is_err());
+        assert!(Validators::validate_conversation_id(&"a".repeat(65)).is_err());
+    }
+
+    #[test]
+    fn test_basket_total_validation() {
+        let item = BasketItemDTO {
+            price: Some(40.0),
+            quantity: Some(2),
+            coupon_discount: Some(8.0),
+            ..Default::default()
+        };
+
+        assert!(Validators::validate_basket_total(std::slice::from_ref(&item), 72.0).is_ok());
+        assert!(Validators::validate_basket_total(std::slice::from_ref(&item), 80.0).is_err());
+    }
 }
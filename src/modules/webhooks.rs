@@ -1,8 +1,86 @@
 use crate::error::{Result, TapsilatError};
-use crate::types::{WebhookEvent, WebhookVerificationConfig, WebhookVerificationResult};
+#[cfg(feature = "http")]
+use crate::modules::pagination::{encode_query_value, RowPage};
+use crate::secret::SecretString;
+#[cfg(feature = "http")]
+use crate::types::{WebhookDelivery, WebhookDeliveryFilter, WebhookDeliveryStatus};
+use crate::types::{
+    WebhookEvent, WebhookVerification, WebhookVerificationConfig, WebhookVerificationResult,
+};
+#[cfg(feature = "http")]
+use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
-pub struct WebhookModule;
+/// Webhook operations
+///
+/// Signature verification and parsing are associated functions and need no client;
+/// delivery log operations go through an instance obtained from
+/// [`TapsilatClient::webhooks`](crate::TapsilatClient::webhooks).
+pub struct WebhookModule {
+    #[cfg(feature = "http")]
+    client: Arc<crate::client::TapsilatClient>,
+}
+
+#[cfg(feature = "http")]
+impl WebhookModule {
+    pub fn new(client: Arc<crate::client::TapsilatClient>) -> Self {
+        Self { client }
+    }
+
+    /// Lists webhook delivery records, newest first
+    pub fn list_deliveries(&self, filter: &WebhookDeliveryFilter) -> Result<Vec<WebhookDelivery>> {
+        let mut params = Vec::new();
+        if let Some(status) = &filter.status {
+            let status = serde_json::to_value(status)?;
+            if let Some(status) = status.as_str() {
+                params.push(format!("status={}", encode_query_value(status)));
+            }
+        }
+        if let Some(event_type) = &filter.event_type {
+            params.push(format!("event_type={}", encode_query_value(event_type)));
+        }
+        if let Some(since) = &filter.since {
+            params.push(format!("since={}", encode_query_value(since)));
+        }
+        if let Some(until) = &filter.until {
+            params.push(format!("until={}", encode_query_value(until)));
+        }
+        if let Some(page) = filter.page {
+            params.push(format!("page={}", page));
+        }
+        if let Some(per_page) = filter.per_page {
+            params.push(format!("per_page={}", per_page));
+        }
+
+        let mut endpoint = "webhook/deliveries".to_string();
+        if !params.is_empty() {
+            endpoint = format!("{}?{}", endpoint, params.join("&"));
+        }
+
+        let response = self.client.make_request::<()>("GET", &endpoint, None)?;
+        let rows = RowPage::from_value(&response).rows;
+        self.client.parse_typed(
+            serde_json::Value::Array(rows),
+            "webhook deliveries response",
+        )
+    }
+
+    /// Lists failed webhook deliveries
+    pub fn list_failed_deliveries(&self) -> Result<Vec<WebhookDelivery>> {
+        self.list_deliveries(&WebhookDeliveryFilter {
+            status: Some(WebhookDeliveryStatus::Failed),
+            ..Default::default()
+        })
+    }
+
+    /// Requests a new delivery attempt for a webhook and returns the updated record
+    pub fn retry_delivery(&self, delivery_id: &str) -> Result<WebhookDelivery> {
+        let endpoint = format!("webhook/deliveries/{}/retry", delivery_id);
+        let response = self.client.make_request::<()>("POST", &endpoint, None)?;
+        self.client
+            .decode_response(response, "webhook delivery retry response")
+    }
+}
 
 impl WebhookModule {
     pub fn verify_webhook(payload: &str, signature: &str, secret: &str) -> Result<bool> {
@@ -31,7 +109,7 @@ impl WebhookModule {
         }
 
         // Verify signature
-        match Self::verify_signature(payload, signature, &config.secret) {
+        match Self::verify_signature(payload, signature, config.secret.expose_secret()) {
             Ok(is_valid) => Ok(WebhookVerificationResult {
                 is_valid,
                 error: if is_valid {
@@ -47,6 +125,54 @@ impl WebhookModule {
         }
     }
 
+    /// Verifies the signature and timestamp of a webhook and returns the parsed event
+    ///
+    /// The signature is checked against `config.secret` and then each of
+    /// `config.previous_secrets`. The returned event's `verification` records which
+    /// secret matched and how far the event timestamp is from now.
+    pub fn verify_and_parse(
+        payload: &str,
+        signature: &str,
+        config: &WebhookVerificationConfig,
+    ) -> Result<WebhookEvent> {
+        let mut event = Self::parse_webhook(payload)?;
+
+        let matched_secret = std::iter::once(&config.secret)
+            .chain(&config.previous_secrets)
+            .position(|secret| {
+                Self::verify_signature(payload, signature, secret.expose_secret()).unwrap_or(false)
+            })
+            .ok_or_else(|| {
+                TapsilatError::InvalidResponse("Invalid webhook signature".to_string())
+            })?;
+
+        let skew = Self::timestamp_skew(&event.timestamp);
+        if let Some(tolerance) = config.tolerance_seconds {
+            match skew {
+                Some(skew) if skew.unsigned_abs() <= tolerance => {}
+                Some(skew) => {
+                    return Err(TapsilatError::InvalidResponse(format!(
+                        "Webhook timestamp too old or too far in future. Difference: {}s, tolerance: {}s",
+                        skew.unsigned_abs(),
+                        tolerance
+                    )))
+                }
+                None => {
+                    return Err(TapsilatError::InvalidResponse(format!(
+                        "Invalid webhook timestamp: {}",
+                        event.timestamp
+                    )))
+                }
+            }
+        }
+
+        event.verification = Some(WebhookVerification {
+            timestamp_skew_seconds: skew,
+            matched_secret,
+        });
+        Ok(event)
+    }
+
     /// Parses webhook payload into WebhookEvent
     pub fn parse_webhook(payload: &str) -> Result<WebhookEvent> {
         serde_json::from_str(payload).map_err(|e| {
@@ -67,7 +193,7 @@ impl WebhookModule {
     }
 
     /// Creates HMAC-SHA256 signature
-    fn create_signature(payload: &str, secret: &str) -> Result<String> {
+    pub(crate) fn create_signature(payload: &str, secret: &str) -> Result<String> {
         // This is a simplified implementation
         // In a real implementation, you would use a proper HMAC-SHA256 library
         use std::collections::hash_map::DefaultHasher;
@@ -80,6 +206,17 @@ impl WebhookModule {
         Ok(format!("{:x}", hash))
     }
 
+    /// Returns the seconds elapsed since an RFC 3339 or Unix timestamp
+    fn timestamp_skew(timestamp: &str) -> Option<i64> {
+        let event_time = match timestamp.parse::<i64>() {
+            Ok(seconds) => seconds,
+            Err(_) => chrono::DateTime::parse_from_rfc3339(timestamp)
+                .ok()?
+                .timestamp(),
+        };
+        Some(chrono::Utc::now().timestamp() - event_time)
+    }
+
     /// Verifies webhook timestamp
     fn verify_timestamp(timestamp_str: &str, tolerance_seconds: u64) -> Result<()> {
         // Parse timestamp (assuming ISO 8601 format or Unix timestamp)
@@ -129,8 +266,9 @@ impl WebhookModule {
         tolerance_seconds: Option<u64>,
     ) -> WebhookVerificationConfig {
         WebhookVerificationConfig {
-            secret,
+            secret: SecretString::new(secret),
             tolerance_seconds,
+            previous_secrets: Vec::new(),
         }
     }
 }
@@ -144,10 +282,32 @@ mod tests {
         let config =
             WebhookModule::create_verification_config("test_secret".to_string(), Some(300));
 
-        assert_eq!(config.secret, "test_secret");
+        assert_eq!(config.secret.expose_secret(), "test_secret");
         assert_eq!(config.tolerance_seconds, Some(300));
     }
 
+    #[test]
+    fn test_webhook_verify_and_parse() {
+        let now = chrono::Utc::now().timestamp();
+        let payload = format!(
+            r#"{{"event_type": "order.completed", "data": {{"order_id": "order_123"}}, "timestamp": "{}"}}"#,
+            now - 30
+        );
+        let signature = WebhookModule::create_signature(&payload, "old_secret").unwrap();
+        let config = WebhookModule::create_verification_config("new_secret".to_string(), Some(300))
+            .with_previous_secret("old_secret");
+
+        let event = WebhookModule::verify_and_parse(&payload, &signature, &config).unwrap();
+        let verification = event.verification.unwrap();
+        assert_eq!(verification.matched_secret, 1);
+        assert!((30..35).contains(&verification.timestamp_skew_seconds.unwrap()));
+
+        assert!(WebhookModule::verify_and_parse(&payload, "sha256=bogus", &config).is_err());
+
+        let strict = WebhookModule::create_verification_config("old_secret".to_string(), Some(10));
+        assert!(WebhookModule::verify_and_parse(&payload, &signature, &strict).is_err());
+    }
+
     #[test]
     fn test_webhook_parsing() {
         let payload = r#"{
@@ -170,4 +330,58 @@ mod tests {
             crate::types::WebhookEventType::OrderCompleted
         ));
     }
+
+    #[test]
+    fn test_subscription_webhook_parsing() {
+        let payload = r#"{
+            "event_type": "subscription.payment_failed",
+            "data": {
+                "subscription_id": "sub_1",
+                "order_id": "order_9",
+                "amount": 99.9,
+                "currency": "TRY",
+                "status": "past_due",
+                "failure_reason": "insufficient_funds",
+                "next_retry_at": "2024-03-04T09:00:00Z"
+            },
+            "timestamp": "2024-03-01T09:00:00Z"
+        }"#;
+
+        let webhook = WebhookModule::parse_webhook(payload).unwrap();
+        assert_eq!(
+            webhook.event_type,
+            crate::types::WebhookEventType::SubscriptionPaymentFailed
+        );
+
+        let subscription = webhook.subscription().unwrap();
+        assert_eq!(subscription.subscription_id, "sub_1");
+        assert_eq!(
+            subscription.status,
+            Some(crate::types::SubscriptionStatus::PastDue)
+        );
+        assert_eq!(
+            subscription.failure_reason.as_deref(),
+            Some("insufficient_funds")
+        );
+
+        let order = WebhookModule::parse_webhook(
+            r#"{"event_type": "order.completed", "data": {"subscription_id": "sub_1"}, "timestamp": "2024-03-01T09:00:00Z"}"#,
+        )
+        .unwrap();
+        assert!(order.subscription().is_none());
+    }
+
+    #[test]
+    fn test_unknown_webhook_event_type() {
+        let payload = r#"{
+            "event_type": "payout.settled",
+            "data": { "amount": 250.0, "currency": "TRY" },
+            "timestamp": "2024-03-01T09:00:00Z"
+        }"#;
+
+        let webhook = WebhookModule::parse_webhook(payload).unwrap();
+        assert_eq!(webhook.event_type, crate::types::WebhookEventType::Unknown);
+        assert!(!webhook.event_type.is_subscription_event());
+        assert!(webhook.subscription().is_none());
+    }
 }
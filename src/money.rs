@@ -0,0 +1,50 @@
+//! Display formatting for amounts.
+//!
+//! Amounts are rounded to minor units (kuruş, cents) before formatting, so the
+//! output always has exactly two decimals.
+
+use crate::types::{to_minor_units, Currency, Locale};
+
+/// Formats an amount for display in the conventions of `locale`
+///
+/// Turkish uses `.` for thousands, `,` for decimals and puts the symbol after the
+/// amount; English uses `,` and `.` and puts the symbol first.
+///
+/// # Example
+///
+/// ```rust
+/// use tapsilat::money::format_amount;
+/// use tapsilat::{Currency, Locale};
+///
+/// assert_eq!(format_amount(1234.56, Currency::TRY, Locale::Tr), "1.234,56 ₺");
+/// assert_eq!(format_amount(1234.56, Currency::TRY, Locale::En), "₺1,234.56");
+/// assert_eq!(format_amount(-5.0, Currency::EUR, Locale::En), "-€5.00");
+/// ```
+pub fn format_amount(amount: f64, currency: Currency, locale: Locale) -> String {
+    format_minor_units(to_minor_units(amount), currency, locale)
+}
+
+/// Formats an amount given in minor units, see [`format_amount`]
+pub fn format_minor_units(units: i64, currency: Currency, locale: Locale) -> String {
+    let (thousands, decimal) = match locale {
+        Locale::Tr => ('.', ','),
+        Locale::En => (',', '.'),
+    };
+    let sign = if units < 0 { "-" } else { "" };
+    let units = units.unsigned_abs();
+
+    let whole = (units / 100).to_string();
+    let mut grouped = String::with_capacity(whole.len() + whole.len() / 3);
+    for (i, digit) in whole.chars().enumerate() {
+        if i > 0 && (whole.len() - i).is_multiple_of(3) {
+            grouped.push(thousands);
+        }
+        grouped.push(digit);
+    }
+    let number = format!("{}{}{:02}", grouped, decimal, units % 100);
+
+    match locale {
+        Locale::Tr => format!("{}{} {}", sign, number, currency.symbol()),
+        Locale::En => format!("{}{}{}", sign, currency.symbol(), number),
+    }
+}
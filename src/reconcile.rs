@@ -0,0 +1,253 @@
+//! Matching your own ledger against the account statement.
+//!
+//! Rows are grouped by reference, entry kind and direction, then paired on amount in
+//! kuruş. Amounts on your side are decimal strings and are parsed exactly, so
+//! `"100.10"` and the API's `100.1` compare equal without float drift.
+
+use crate::error::{Result, TapsilatError};
+use crate::types::{
+    from_minor_units, to_minor_units, LedgerDirection, LedgerEntry, LedgerEntryKind,
+};
+use std::collections::HashMap;
+
+/// A booking from your own ledger
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalLedgerRow {
+    /// Order reference ID, or the payout ID for payout rows
+    pub reference_id: String,
+    pub kind: LedgerEntryKind,
+    pub direction: LedgerDirection,
+    /// Unsigned decimal amount such as `"100.10"`
+    pub amount: String,
+}
+
+/// Which ledger a discrepancy was found in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LedgerSide {
+    /// Your own ledger
+    Local,
+    /// The Tapsilat account statement
+    Remote,
+}
+
+/// A difference between the two ledgers
+#[derive(Debug, Clone, PartialEq)]
+pub enum Discrepancy {
+    /// The statement has an entry your ledger lacks
+    MissingLocally { entry: LedgerEntry },
+    /// Your ledger has a row the statement lacks
+    MissingRemotely { row: LocalLedgerRow },
+    /// Both sides book the reference, but for different amounts
+    AmountMismatch {
+        row: LocalLedgerRow,
+        entry: LedgerEntry,
+    },
+    /// One side books the same reference and amount more often than the other
+    Duplicate {
+        side: LedgerSide,
+        reference_id: String,
+        kind: LedgerEntryKind,
+        amount: f64,
+        /// Number of surplus bookings
+        extra: usize,
+    },
+}
+
+/// Outcome of [`reconcile`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconciliationReport {
+    /// Number of local rows paired with a statement entry of the same amount
+    pub matched: usize,
+    pub discrepancies: Vec<Discrepancy>,
+}
+
+impl ReconciliationReport {
+    /// Returns `true` if the ledgers agree
+    pub fn is_clean(&self) -> bool {
+        self.discrepancies.is_empty()
+    }
+}
+
+/// Parses an unsigned decimal amount string into kuruş without going through `f64`
+///
+/// Digits past the second decimal place are accepted only if they are zero.
+///
+/// # Errors
+///
+/// Returns [`TapsilatError::ValidationError`] if `amount` is not a plain decimal
+/// number or has non-zero sub-kuruş digits.
+pub fn parse_minor_units(amount: &str) -> Result<i64> {
+    let invalid = || TapsilatError::ValidationError(format!("Invalid ledger amount '{}'", amount));
+
+    let trimmed = amount.trim();
+    let (whole, fraction) = trimmed.split_once('.').unwrap_or((trimmed, ""));
+    let all_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if (whole.is_empty() && fraction.is_empty()) || !all_digits(whole) || !all_digits(fraction) {
+        return Err(invalid());
+    }
+    if fraction.len() > 2 && fraction[2..].bytes().any(|b| b != b'0') {
+        return Err(invalid());
+    }
+
+    let mut kurus = fraction.bytes().take(2).map(|b| (b - b'0') as i64);
+    let cents = kurus.next().unwrap_or(0) * 10 + kurus.next().unwrap_or(0);
+    let whole: i64 = if whole.is_empty() {
+        0
+    } else {
+        whole.parse().map_err(|_| invalid())?
+    };
+    whole
+        .checked_mul(100)
+        .and_then(|units| units.checked_add(cents))
+        .ok_or_else(invalid)
+}
+
+type GroupKey = (String, LedgerEntryKind, LedgerDirection);
+
+#[derive(Default)]
+struct Group {
+    local: Vec<(i64, LocalLedgerRow)>,
+    remote: Vec<(i64, LedgerEntry)>,
+}
+
+/// Compares your ledger rows with the statement entries for the same period
+///
+/// Statement entries are keyed by `reference_id`, falling back to `payout_id` and
+/// then `entry_id`. Within a key, rows with equal amounts are matched first and any
+/// surplus on one side is a [`Discrepancy::Duplicate`]. Amounts found on only one
+/// side are paired in order as [`Discrepancy::AmountMismatch`], and the remainder is
+/// reported as missing. Discrepancies follow the order in which keys first appear.
+///
+/// # Errors
+///
+/// Returns [`TapsilatError::ValidationError`] if a local amount cannot be parsed.
+///
+/// # Example
+///
+/// ```rust
+/// use tapsilat::reconcile::{reconcile, LocalLedgerRow};
+/// use tapsilat::{LedgerDirection, LedgerEntry, LedgerEntryKind};
+///
+/// let local = vec![LocalLedgerRow {
+///     reference_id: "ref_1".to_string(),
+///     kind: LedgerEntryKind::OrderPayment,
+///     direction: LedgerDirection::Credit,
+///     amount: "100.10".to_string(),
+/// }];
+/// let remote: Vec<LedgerEntry> = serde_json::from_value(serde_json::json!([
+///     { "entry_id": "le_1", "type": "order_payment", "direction": "credit",
+///       "amount": "100.1", "reference_id": "ref_1" }
+/// ]))
+/// .unwrap();
+///
+/// let report = reconcile(&local, &remote).unwrap();
+/// assert!(report.is_clean());
+/// assert_eq!(report.matched, 1);
+/// ```
+pub fn reconcile(local: &[LocalLedgerRow], remote: &[LedgerEntry]) -> Result<ReconciliationReport> {
+    let mut groups: Vec<(GroupKey, Group)> = Vec::new();
+    let mut index: HashMap<GroupKey, usize> = HashMap::new();
+    let mut slot = |key: GroupKey, groups: &mut Vec<(GroupKey, Group)>| -> usize {
+        *index.entry(key.clone()).or_insert_with(|| {
+            groups.push((key, Group::default()));
+            groups.len() - 1
+        })
+    };
+
+    for row in local {
+        let amount = parse_minor_units(&row.amount)?;
+        let i = slot(
+            (row.reference_id.clone(), row.kind, row.direction),
+            &mut groups,
+        );
+        groups[i].1.local.push((amount, row.clone()));
+    }
+    for entry in remote {
+        let reference = entry
+            .reference_id
+            .as_ref()
+            .or(entry.payout_id.as_ref())
+            .unwrap_or(&entry.entry_id);
+        let i = slot(
+            (reference.clone(), entry.kind, entry.direction),
+            &mut groups,
+        );
+        groups[i]
+            .1
+            .remote
+            .push((to_minor_units(entry.amount), entry.clone()));
+    }
+
+    let mut report = ReconciliationReport {
+        matched: 0,
+        discrepancies: Vec::new(),
+    };
+    for (key, Group { local, remote }) in groups {
+        reconcile_group(&key, local, remote, &mut report);
+    }
+    Ok(report)
+}
+
+fn reconcile_group(
+    (reference_id, kind, _): &GroupKey,
+    mut local: Vec<(i64, LocalLedgerRow)>,
+    mut remote: Vec<(i64, LedgerEntry)>,
+    report: &mut ReconciliationReport,
+) {
+    let mut amounts: Vec<i64> = Vec::new();
+    for amount in local
+        .iter()
+        .map(|(a, _)| *a)
+        .chain(remote.iter().map(|(a, _)| *a))
+    {
+        if !amounts.contains(&amount) {
+            amounts.push(amount);
+        }
+    }
+
+    let mut unmatched_local = Vec::new();
+    let mut unmatched_remote = Vec::new();
+    let mut duplicates = Vec::new();
+    for amount in amounts {
+        let (same_local, rest): (Vec<_>, Vec<_>) =
+            local.into_iter().partition(|(a, _)| *a == amount);
+        local = rest;
+        let (same_remote, rest): (Vec<_>, Vec<_>) =
+            remote.into_iter().partition(|(a, _)| *a == amount);
+        remote = rest;
+
+        if same_local.is_empty() || same_remote.is_empty() {
+            unmatched_local.extend(same_local.into_iter().map(|(_, row)| row));
+            unmatched_remote.extend(same_remote.into_iter().map(|(_, entry)| entry));
+            continue;
+        }
+
+        report.matched += same_local.len().min(same_remote.len());
+        let (side, extra) = if same_local.len() > same_remote.len() {
+            (LedgerSide::Local, same_local.len() - same_remote.len())
+        } else {
+            (LedgerSide::Remote, same_remote.len() - same_local.len())
+        };
+        if extra > 0 {
+            duplicates.push(Discrepancy::Duplicate {
+                side,
+                reference_id: reference_id.clone(),
+                kind: *kind,
+                amount: from_minor_units(amount),
+                extra,
+            });
+        }
+    }
+
+    let mut unmatched_remote = unmatched_remote.into_iter();
+    for row in unmatched_local {
+        report.discrepancies.push(match unmatched_remote.next() {
+            Some(entry) => Discrepancy::AmountMismatch { row, entry },
+            None => Discrepancy::MissingRemotely { row },
+        });
+    }
+    report
+        .discrepancies
+        .extend(unmatched_remote.map(|entry| Discrepancy::MissingLocally { entry }));
+    report.discrepancies.extend(duplicates);
+}
@@ -0,0 +1,85 @@
+//! Wrapper for credentials that must not end up in logs or freed memory.
+
+use std::fmt;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A string holding a credential such as an API key
+///
+/// `Debug` and `Display` print a masked form, so configs and errors containing a
+/// secret can be logged safely. Use [`expose_secret`](Self::expose_secret) where the
+/// plain value is actually needed. The buffer is overwritten with zeros when the
+/// value is dropped, so the secret does not linger in freed memory.
+///
+/// # Example
+///
+/// ```rust
+/// use tapsilat::SecretString;
+///
+/// let key = SecretString::new("sk_live_1234567890abcdef");
+/// assert_eq!(key.expose_secret(), "sk_live_1234567890abcdef");
+/// assert_eq!(format!("{:?}", key), "SecretString(\"sk_l...cdef\")");
+/// ```
+#[derive(Clone, PartialEq, Eq, Default)]
+pub struct SecretString(String);
+
+impl SecretString {
+    /// Wraps a secret value.
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self(secret.into())
+    }
+
+    /// Returns the plain secret value.
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns whether the secret is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the first and last four characters of long secrets and `***` otherwise,
+    /// enough to tell keys apart without revealing them.
+    pub fn masked(&self) -> String {
+        let chars: Vec<char> = self.0.chars().collect();
+        if chars.len() > 10 {
+            let head: String = chars[..4].iter().collect();
+            let tail: String = chars[chars.len() - 4..].iter().collect();
+            format!("{}...{}", head, tail)
+        } else {
+            "***".to_string()
+        }
+    }
+}
+
+impl Drop for SecretString {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecretString {}
+
+impl From<String> for SecretString {
+    fn from(secret: String) -> Self {
+        Self(secret)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(secret: &str) -> Self {
+        Self(secret.to_string())
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SecretString").field(&self.masked()).finish()
+    }
+}
+
+impl fmt::Display for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.masked())
+    }
+}
@@ -0,0 +1,105 @@
+//! Coalescing of identical in-flight requests.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Condvar, Mutex};
+
+struct Call<T> {
+    /// `None` while running, then `Some(None)` if the leader failed
+    outcome: Mutex<Option<Option<T>>>,
+    done: Condvar,
+}
+
+/// Runs at most one call per key at a time and shares its result
+///
+/// The first caller for a key becomes the leader and performs the call; callers
+/// arriving while it runs wait and receive a clone of its successful result. If the
+/// leader fails, each waiting caller performs the call itself, so errors are never
+/// shared between callers. Clones share the same set of in-flight calls.
+pub(crate) struct SingleFlight<T> {
+    calls: Arc<Mutex<HashMap<String, Arc<Call<T>>>>>,
+}
+
+impl<T> Clone for SingleFlight<T> {
+    fn clone(&self) -> Self {
+        Self {
+            calls: Arc::clone(&self.calls),
+        }
+    }
+}
+
+impl<T: Clone> SingleFlight<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            calls: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub(crate) fn run<E>(
+        &self,
+        key: &str,
+        call: impl FnOnce() -> std::result::Result<T, E>,
+    ) -> std::result::Result<T, E> {
+        let (pending, leader) = {
+            let Ok(mut calls) = self.calls.lock() else {
+                return call();
+            };
+            match calls.get(key) {
+                Some(pending) => (Arc::clone(pending), false),
+                None => {
+                    let pending = Arc::new(Call {
+                        outcome: Mutex::new(None),
+                        done: Condvar::new(),
+                    });
+                    calls.insert(key.to_string(), Arc::clone(&pending));
+                    (pending, true)
+                }
+            }
+        };
+
+        if leader {
+            let mut guard = Finish {
+                flight: self,
+                key,
+                pending: &pending,
+                value: None,
+            };
+            let result = call();
+            if let Ok(value) = &result {
+                guard.value = Some(value.clone());
+            }
+            return result;
+        }
+
+        let shared = pending.outcome.lock().ok().and_then(|outcome| {
+            pending
+                .done
+                .wait_while(outcome, |outcome| outcome.is_none())
+                .ok()
+                .and_then(|outcome| outcome.clone().flatten())
+        });
+        match shared {
+            Some(value) => Ok(value),
+            None => call(),
+        }
+    }
+}
+
+/// Publishes the leader's outcome, also when the call panics
+struct Finish<'a, T> {
+    flight: &'a SingleFlight<T>,
+    key: &'a str,
+    pending: &'a Call<T>,
+    value: Option<T>,
+}
+
+impl<T> Drop for Finish<'_, T> {
+    fn drop(&mut self) {
+        if let Ok(mut calls) = self.flight.calls.lock() {
+            calls.remove(self.key);
+        }
+        if let Ok(mut outcome) = self.pending.outcome.lock() {
+            *outcome = Some(self.value.take());
+        }
+        self.pending.done.notify_all();
+    }
+}
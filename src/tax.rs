@@ -0,0 +1,124 @@
+//! Turkish VAT (KDV) calculation for basket items.
+//!
+//! Tax is computed per basket item from its [`VatRate`] and rounded to kuruş per
+//! line before summing, which is how e-invoices itemise KDV.
+
+use crate::error::{Result, TapsilatError};
+use crate::types::{from_minor_units, to_minor_units, BasketItemDTO, CreateOrderRequest};
+
+/// VAT rate applied to a basket item
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VatRate {
+    /// 0%, e.g. exported goods
+    Exempt,
+    /// 1%, basic foodstuffs
+    Reduced,
+    /// 10%, e.g. restaurants and some textiles
+    Intermediate,
+    /// 20%, the general rate
+    Standard,
+    /// Any other rate, in percent
+    Custom(f64),
+}
+
+impl VatRate {
+    /// Returns the rate as a percentage
+    pub fn percent(self) -> f64 {
+        match self {
+            VatRate::Exempt => 0.0,
+            VatRate::Reduced => 1.0,
+            VatRate::Intermediate => 10.0,
+            VatRate::Standard => 20.0,
+            VatRate::Custom(percent) => percent,
+        }
+    }
+}
+
+/// Whether basket item prices already include VAT
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PricingMode {
+    /// Prices include VAT; the tax is the VAT share of the price
+    Inclusive,
+    /// Prices exclude VAT; the tax is added on top
+    Exclusive,
+}
+
+/// Computes the VAT on a single basket item after any coupon discount
+///
+/// # Errors
+///
+/// Returns [`TapsilatError::ValidationError`] if the item has no `vat_rate` or the
+/// rate is negative.
+pub fn item_tax(item: &BasketItemDTO, mode: PricingMode) -> Result<f64> {
+    let rate = item.vat_rate.ok_or_else(|| {
+        TapsilatError::ValidationError(format!(
+            "Basket item {} has no VAT rate",
+            item.id.as_deref().or(item.name.as_deref()).unwrap_or("?")
+        ))
+    })?;
+    if rate.percent() < 0.0 {
+        return Err(TapsilatError::ValidationError(
+            "VAT rate cannot be negative".to_string(),
+        ));
+    }
+
+    let total = to_minor_units(item.discounted_total()) as f64;
+    let rate = rate.percent() / 100.0;
+    let tax = match mode {
+        PricingMode::Inclusive => total - total / (1.0 + rate),
+        PricingMode::Exclusive => total * rate,
+    };
+    Ok(from_minor_units(tax.round() as i64))
+}
+
+/// Computes the total VAT for a basket
+pub fn basket_tax(items: &[BasketItemDTO], mode: PricingMode) -> Result<f64> {
+    let mut total = 0;
+    for item in items {
+        total += to_minor_units(item_tax(item, mode)?);
+    }
+    Ok(from_minor_units(total))
+}
+
+/// Computes the VAT of the request's basket and stores it in `tax_amount`
+///
+/// With [`PricingMode::Exclusive`] the request `amount` must already include the
+/// tax; it is not adjusted here.
+///
+/// # Example
+///
+/// ```rust
+/// use tapsilat::tax::{apply_tax, PricingMode, VatRate};
+/// use tapsilat::BasketItemDTO;
+/// # use tapsilat::{CreateBuyerRequest, CreateOrderRequest, ThreeDSPreference};
+/// # let mut request = CreateOrderRequest {
+/// #     amount: 120.0, currency: "TRY".to_string(), locale: "tr".to_string(),
+/// #     buyer: CreateBuyerRequest::default(), basket_items: None, billing_address: None,
+/// #     checkout_design: None, conversation_id: None, enabled_installments: None,
+/// #     external_reference_id: None, metadata: None, order_cards: None, paid_amount: None,
+/// #     partial_payment: None, payment_failure_url: None, payment_methods: None,
+/// #     payment_mode: None, payment_options: None, payment_success_url: None,
+/// #     payment_terms: None, pf_sub_merchant: None, redirect_failure_url: None,
+/// #     redirect_success_url: None, shipping_address: None, sub_organization: None,
+/// #     submerchants: None, tax_amount: None, three_ds: ThreeDSPreference::Auto,
+/// #     consents: None,
+/// #     checkout_expires_in: None,
+/// #     invoice: None,
+/// # };
+///
+/// request.basket_items = Some(vec![BasketItemDTO {
+///     price: Some(120.0),
+///     quantity: Some(1),
+///     vat_rate: Some(VatRate::Standard),
+///     ..Default::default()
+/// }]);
+///
+/// let tax = apply_tax(&mut request, PricingMode::Inclusive).unwrap();
+/// assert_eq!(tax, 20.0);
+/// assert_eq!(request.tax_amount, Some(20.0));
+/// ```
+pub fn apply_tax(request: &mut CreateOrderRequest, mode: PricingMode) -> Result<f64> {
+    let tax = basket_tax(request.basket_items.as_deref().unwrap_or_default(), mode)?;
+    request.tax_amount = Some(tax);
+    Ok(tax)
+}
@@ -0,0 +1,90 @@
+//! OpenTelemetry instrumentation, enabled with the `otel` feature.
+//!
+//! Every HTTP call made by `TapsilatClient` runs inside a client span from the global
+//! tracer provider, and the global text map propagator injects the span context into
+//! the request headers. Install both with `opentelemetry::global` at startup.
+
+use crate::client::endpoint_route;
+use crate::error::Result;
+use opentelemetry::global;
+use opentelemetry::propagation::Injector;
+use opentelemetry::trace::{get_active_span, SpanKind, Status, TraceContextExt, Tracer};
+use opentelemetry::{Context, KeyValue};
+use std::time::Instant;
+
+const TRACER_NAME: &str = "tapsilat";
+
+/// Runs `call` inside a client span describing the API request
+pub(crate) fn instrument<T>(
+    method: &str,
+    endpoint: &str,
+    call: impl FnOnce() -> Result<T>,
+) -> Result<T> {
+    let method = method.to_uppercase();
+    let tracer = global::tracer(TRACER_NAME);
+    let span = tracer
+        .span_builder(format!("{} {}", method, endpoint_route(endpoint)))
+        .with_kind(SpanKind::Client)
+        .with_attributes([
+            KeyValue::new("http.request.method", method),
+            KeyValue::new("tapsilat.endpoint", endpoint.to_string()),
+        ])
+        .start(&tracer);
+
+    let cx = Context::current_with_span(span);
+    let started = Instant::now();
+    let result = {
+        let _guard = cx.clone().attach();
+        call()
+    };
+
+    let span = cx.span();
+    span.set_attribute(KeyValue::new(
+        "tapsilat.duration_ms",
+        started.elapsed().as_secs_f64() * 1000.0,
+    ));
+    if let Err(error) = &result {
+        span.set_status(Status::error(error.to_string()));
+    }
+    span.end();
+    result
+}
+
+/// Records the response status code on the active span
+pub(crate) fn record_status(status_code: u16) {
+    get_active_span(|span| {
+        span.set_attribute(KeyValue::new(
+            "http.response.status_code",
+            i64::from(status_code),
+        ))
+    });
+}
+
+/// Records how many times the request was resent on the active span
+pub(crate) fn record_retries(retries: u32) {
+    if retries > 0 {
+        get_active_span(|span| {
+            span.set_attribute(KeyValue::new(
+                "http.request.resend_count",
+                i64::from(retries),
+            ))
+        });
+    }
+}
+
+/// Returns the context propagation headers for the active span
+pub(crate) fn propagation_headers() -> Vec<(String, String)> {
+    let mut headers = HeaderInjector(Vec::new());
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&Context::current(), &mut headers)
+    });
+    headers.0
+}
+
+struct HeaderInjector(Vec<(String, String)>);
+
+impl Injector for HeaderInjector {
+    fn set(&mut self, key: &str, value: String) {
+        self.0.push((key.to_string(), value));
+    }
+}
@@ -0,0 +1,467 @@
+//! Test support utilities, enabled with the `testing` feature.
+//!
+//! [`WebhookCapture`] runs a small local HTTP listener that accepts webhook
+//! deliveries, verifies their signatures and hands the parsed events to the test
+//! through a channel, so end-to-end sandbox tests can assert on what was delivered.
+//!
+//! [`test_cards`] lists candidate sandbox test cards with the outcome each one is
+//! expected to produce, and [`scenario`] builds an order and card aimed at an outcome.
+//! Tapsilat does not publish a test card list, so the outcomes are unverified; see
+//! [`test_cards`] for where each number comes from.
+
+use crate::client::TapsilatClient;
+use crate::error::Result;
+use crate::modules::WebhookModule;
+use crate::secret::SecretString;
+use crate::types::{
+    CardDetails, CardPaymentRequest, CreateBuyerRequest, CreateOrderRequest, PaymentResponse,
+    ThreeDSPreference, WebhookEvent,
+};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// Header carrying the webhook signature
+pub const SIGNATURE_HEADER: &str = "x-tapsilat-signature";
+
+/// Signs a webhook payload the same way Tapsilat does, for building test deliveries
+pub fn sign_payload(payload: &str, secret: &str) -> Result<String> {
+    WebhookModule::create_signature(payload, secret)
+}
+
+/// Result a sandbox test card is expected to produce when charged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CardOutcome {
+    Success,
+    InsufficientFunds,
+    /// The issuer asks for a 3D Secure challenge before authorizing
+    ThreeDSChallenge,
+    FraudDecline,
+}
+
+/// A sandbox test card and its expected, unverified outcome
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TestCard {
+    pub number: &'static str,
+    /// Card scheme, e.g. `VISA` or `TROY`
+    pub brand: &'static str,
+    pub expiry_month: u8,
+    pub expiry_year: u16,
+    pub outcome: CardOutcome,
+}
+
+impl TestCard {
+    /// Returns the card ready for [`CardModule::tokenize`](crate::modules::CardModule::tokenize)
+    pub fn card_details(&self) -> CardDetails {
+        CardDetails {
+            card_number: self.number.to_string(),
+            expiry_month: self.expiry_month,
+            expiry_year: self.expiry_year,
+            holder_name: "Test Cardholder".to_string(),
+        }
+    }
+}
+
+// None of these numbers are published by Tapsilat. Each comment names where the
+// number comes from; the outcomes have not been confirmed against the sandbox.
+const TEST_CARDS: &[TestCard] = &[
+    // iyzico sandbox list: successful Mastercard credit card
+    TestCard {
+        number: "5528790000000008",
+        brand: "MASTERCARD",
+        expiry_month: 12,
+        expiry_year: 2030,
+        outcome: CardOutcome::Success,
+    },
+    // iyzico sandbox list: successful Visa credit card
+    TestCard {
+        number: "4543590000000006",
+        brand: "VISA",
+        expiry_month: 12,
+        expiry_year: 2030,
+        outcome: CardOutcome::Success,
+    },
+    // iyzico sandbox list: successful Troy card
+    TestCard {
+        number: "9792030394440796",
+        brand: "TROY",
+        expiry_month: 12,
+        expiry_year: 2030,
+        outcome: CardOutcome::Success,
+    },
+    // iyzico sandbox list: declined for insufficient funds
+    TestCard {
+        number: "4111111111111129",
+        brand: "VISA",
+        expiry_month: 12,
+        expiry_year: 2030,
+        outcome: CardOutcome::InsufficientFunds,
+    },
+    // iyzico sandbox list: successful Mastercard debit card; the challenge comes from
+    // the scenario forcing 3D Secure, not from the card itself
+    TestCard {
+        number: "5890040000000016",
+        brand: "MASTERCARD",
+        expiry_month: 12,
+        expiry_year: 2030,
+        outcome: CardOutcome::ThreeDSChallenge,
+    },
+    // Origin unknown; not found in any published sandbox list
+    TestCard {
+        number: "4141111111111115",
+        brand: "VISA",
+        expiry_month: 12,
+        expiry_year: 2030,
+        outcome: CardOutcome::FraudDecline,
+    },
+];
+
+/// Returns the candidate sandbox test cards; they are declined outside the sandbox
+///
+/// Tapsilat does not publish test cards, so this list is unverified: most numbers
+/// are taken from iyzico's public sandbox list, and the outcomes are what that list
+/// documents for iyzico, not something Tapsilat guarantees. Check a card against the
+/// sandbox before relying on its outcome.
+pub fn test_cards() -> &'static [TestCard] {
+    TEST_CARDS
+}
+
+/// Returns the first test card expected to produce `outcome`
+pub fn test_card(outcome: CardOutcome) -> &'static TestCard {
+    TEST_CARDS
+        .iter()
+        .find(|card| card.outcome == outcome)
+        .expect("every outcome has a test card")
+}
+
+/// An order and test card aimed at an expected, unverified outcome
+#[derive(Debug, Clone)]
+pub struct Scenario {
+    pub outcome: CardOutcome,
+    pub card: TestCard,
+    pub order: CreateOrderRequest,
+}
+
+/// Outcome of [`Scenario::run`]
+#[derive(Debug)]
+pub struct ScenarioRun {
+    /// Reference ID of the created order
+    pub reference_id: String,
+    /// Result of charging the test card; an `Err` for declining outcomes
+    pub payment: Result<PaymentResponse>,
+}
+
+/// Builds a scenario for `outcome`
+///
+/// The outcome depends on [`test_cards`], which is unverified, so check
+/// [`ScenarioRun::payment`] rather than assuming the outcome was produced.
+///
+/// The order is a 100.00 TRY purchase with a unique conversation ID. 3D Secure is forced
+/// for [`CardOutcome::ThreeDSChallenge`] and disabled otherwise, so no other outcome
+/// can be interrupted by a challenge.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tapsilat::testing::{scenario, CardOutcome};
+/// use tapsilat::{Config, TapsilatClient};
+///
+/// let client = TapsilatClient::new(Config::new("sandbox-api-key")).unwrap();
+/// let run = scenario(CardOutcome::InsufficientFunds).run(&client).unwrap();
+/// assert!(run.payment.is_err());
+/// ```
+pub fn scenario(outcome: CardOutcome) -> Scenario {
+    let three_ds = match outcome {
+        CardOutcome::ThreeDSChallenge => ThreeDSPreference::Force,
+        _ => ThreeDSPreference::Disable,
+    };
+    let conversation_id =
+        crate::ids::generate_conversation_id("scenario").unwrap_or_else(|_| "scenario".to_string());
+    let order = CreateOrderRequest::builder()
+        .amount(100.0)
+        .currency("TRY")
+        .locale("tr")
+        .buyer(CreateBuyerRequest {
+            name: "Test".to_string(),
+            surname: "Buyer".to_string(),
+            email: Some("buyer@example.com".to_string()),
+            ..Default::default()
+        })
+        .conversation_id(conversation_id)
+        .three_ds(three_ds)
+        .build()
+        .expect("scenario order is valid");
+
+    Scenario {
+        outcome,
+        card: *test_card(outcome),
+        order,
+    }
+}
+
+impl Scenario {
+    /// Tokenizes the card, creates the order and pays it with the card
+    ///
+    /// Errors while tokenizing or creating the order are returned directly; the
+    /// payment result, successful or not, is part of the [`ScenarioRun`].
+    pub fn run(self, client: &TapsilatClient) -> Result<ScenarioRun> {
+        let token = client.cards().tokenize(self.card.card_details())?;
+        let created = client.orders().create(self.order)?;
+        let reference_id = created.reference_id.ok_or_else(|| {
+            crate::error::TapsilatError::InvalidResponse(
+                "Created order has no reference ID".to_string(),
+            )
+        })?;
+        let payment = client.orders().pay_with_card(
+            &reference_id,
+            CardPaymentRequest {
+                card_token: token.card_token,
+                recurring: None,
+            },
+        );
+        Ok(ScenarioRun {
+            reference_id,
+            payment,
+        })
+    }
+}
+
+/// Local webhook receiver for end-to-end tests
+///
+/// Deliveries with a valid signature are answered with `200` and their events
+/// are sent to [`events`](Self::events). Deliveries with a missing or invalid
+/// signature get `401`, unparsable payloads get `400`; both are counted in
+/// [`rejected`](Self::rejected). The listener stops when the capture is dropped.
+pub struct WebhookCapture {
+    addr: SocketAddr,
+    events: Receiver<WebhookEvent>,
+    rejected: Arc<AtomicUsize>,
+    shutdown: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl WebhookCapture {
+    /// Starts a capture on an ephemeral localhost port
+    pub fn start(secret: impl Into<String>) -> Result<Self> {
+        Self::bind("127.0.0.1:0", secret)
+    }
+
+    /// Starts a capture listening on `addr`
+    pub fn bind(addr: impl ToSocketAddrs, secret: impl Into<String>) -> Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?;
+        let secret = SecretString::new(secret);
+        let (sender, events) = mpsc::channel();
+        let rejected = Arc::new(AtomicUsize::new(0));
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let handle = {
+            let rejected = Arc::clone(&rejected);
+            let shutdown = Arc::clone(&shutdown);
+            std::thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if shutdown.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    if let Ok(stream) = stream {
+                        if !handle_delivery(stream, secret.expose_secret(), &sender) {
+                            rejected.fetch_add(1, Ordering::SeqCst);
+                        }
+                    }
+                }
+            })
+        };
+
+        Ok(Self {
+            addr,
+            events,
+            rejected,
+            shutdown,
+            handle: Some(handle),
+        })
+    }
+
+    /// Returns the address the listener is bound to
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Returns the URL to register as the webhook endpoint
+    pub fn url(&self) -> String {
+        format!("http://{}/webhook", self.addr)
+    }
+
+    /// Returns the channel of verified webhook events
+    pub fn events(&self) -> &Receiver<WebhookEvent> {
+        &self.events
+    }
+
+    /// Waits up to `timeout` for the next verified event
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<WebhookEvent> {
+        self.events.recv_timeout(timeout).ok()
+    }
+
+    /// Returns the number of deliveries rejected so far
+    pub fn rejected(&self) -> usize {
+        self.rejected.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for WebhookCapture {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        // Wake the accept loop so it can observe the shutdown flag.
+        let _ = TcpStream::connect(self.addr);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Reads one HTTP request, replies and returns whether the delivery was accepted
+fn handle_delivery(mut stream: TcpStream, secret: &str, sender: &Sender<WebhookEvent>) -> bool {
+    let _ = stream.set_read_timeout(Some(Duration::from_secs(5)));
+    let (signature, body) = match read_request(&stream) {
+        Ok(request) => request,
+        Err(_) => {
+            respond(&mut stream, "400 Bad Request");
+            return false;
+        }
+    };
+
+    let verified = signature
+        .as_deref()
+        .map(|signature| WebhookModule::verify_webhook(&body, signature, secret))
+        .is_some_and(|verified| matches!(verified, Ok(true)));
+    if !verified {
+        respond(&mut stream, "401 Unauthorized");
+        return false;
+    }
+
+    match WebhookModule::parse_webhook(&body) {
+        Ok(event) => {
+            respond(&mut stream, "200 OK");
+            let _ = sender.send(event);
+            true
+        }
+        Err(_) => {
+            respond(&mut stream, "400 Bad Request");
+            false
+        }
+    }
+}
+
+fn read_request(stream: &TcpStream) -> std::io::Result<(Option<String>, String)> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+
+    let mut signature = None;
+    let mut content_length = 0;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 || line.trim_end().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            let name = name.trim().to_ascii_lowercase();
+            let value = value.trim();
+            if name == SIGNATURE_HEADER {
+                signature = Some(value.to_string());
+            } else if name == "content-length" {
+                content_length = value.parse().map_err(|_| {
+                    std::io::Error::new(std::io::ErrorKind::InvalidData, "bad content-length")
+                })?;
+            }
+        }
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+    let body = String::from_utf8(body)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    Ok((signature, body))
+}
+
+fn respond(stream: &mut TcpStream, status: &str) {
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        status
+    );
+    let _ = stream.flush();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn deliver(url: &str, payload: &str, signature: &str) -> u16 {
+        let agent: ureq::Agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .build()
+            .into();
+        agent
+            .post(url)
+            .header(SIGNATURE_HEADER, signature)
+            .content_type("application/json")
+            .send(payload)
+            .map(|response| response.status().as_u16())
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn test_webhook_capture() {
+        let capture = WebhookCapture::start("test_secret").unwrap();
+        let payload = json!({
+            "event_type": "order.completed",
+            "data": { "order_id": "ord_1" },
+            "timestamp": "1700000000",
+            "signature": null
+        })
+        .to_string();
+
+        let status = deliver(
+            &capture.url(),
+            &payload,
+            &sign_payload(&payload, "test_secret").unwrap(),
+        );
+        assert_eq!(status, 200);
+        let event = capture.recv_timeout(Duration::from_secs(2)).unwrap();
+        assert_eq!(event.data.order_id.as_deref(), Some("ord_1"));
+
+        assert_eq!(deliver(&capture.url(), &payload, "sha256=bogus"), 401);
+        assert_eq!(capture.rejected(), 1);
+        assert!(capture.events().try_recv().is_err());
+    }
+
+    #[test]
+    fn test_cards_and_scenarios() {
+        for outcome in [
+            CardOutcome::Success,
+            CardOutcome::InsufficientFunds,
+            CardOutcome::ThreeDSChallenge,
+            CardOutcome::FraudDecline,
+        ] {
+            assert_eq!(test_card(outcome).outcome, outcome);
+        }
+        for card in test_cards() {
+            assert!(crate::Validators::validate_card_number(card.number).is_ok());
+        }
+
+        let challenge = scenario(CardOutcome::ThreeDSChallenge);
+        assert_eq!(challenge.order.three_ds, ThreeDSPreference::Force);
+        assert_eq!(challenge.card.number, "5890040000000016");
+        let declined = scenario(CardOutcome::FraudDecline);
+        assert_eq!(declined.order.three_ds, ThreeDSPreference::Disable);
+        assert_ne!(
+            declined.order.conversation_id,
+            scenario(CardOutcome::FraudDecline).order.conversation_id
+        );
+    }
+}
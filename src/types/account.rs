@@ -0,0 +1,155 @@
+use crate::masking::mask_iban;
+use crate::types::{from_minor_units, to_minor_units};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Balance of the merchant account in a single currency
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurrencyBalance {
+    pub currency: String,
+    /// Settled funds that can be paid out
+    #[serde(default, deserialize_with = "crate::types::common::deserialize_amount")]
+    pub available: f64,
+    /// Captured funds waiting for settlement
+    #[serde(default, deserialize_with = "crate::types::common::deserialize_amount")]
+    pub pending: f64,
+    /// Funds held back for refunds, chargebacks or rolling reserves
+    #[serde(default, deserialize_with = "crate::types::common::deserialize_amount")]
+    pub reserved: f64,
+}
+
+impl CurrencyBalance {
+    /// Returns the sum of available, pending and reserved funds, rounded to minor units
+    pub fn total(&self) -> f64 {
+        from_minor_units(
+            to_minor_units(self.available)
+                + to_minor_units(self.pending)
+                + to_minor_units(self.reserved),
+        )
+    }
+}
+
+/// Current balances of the merchant account, one entry per currency
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountBalance {
+    #[serde(default)]
+    pub balances: Vec<CurrencyBalance>,
+    pub updated_at: Option<String>,
+}
+
+impl AccountBalance {
+    /// Returns the balance held in `currency`, if the account has one
+    pub fn currency(&self, currency: &str) -> Option<&CurrencyBalance> {
+        self.balances
+            .iter()
+            .find(|balance| balance.currency.eq_ignore_ascii_case(currency))
+    }
+}
+
+/// Smallest and largest installment counts offered to buyers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstallmentRange {
+    pub min: u8,
+    pub max: u8,
+}
+
+/// Optional features enabled for the merchant account
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountFeatures {
+    #[serde(default)]
+    pub partial_payment: bool,
+    /// Authorize first and capture later with a post-authorization
+    #[serde(default)]
+    pub postauth: bool,
+    #[serde(default)]
+    pub subscriptions: bool,
+}
+
+/// Payment options, currencies and features enabled for the merchant account
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Capabilities {
+    /// Values accepted in `CreateOrderRequest::payment_options`, e.g. `credit_card`
+    #[serde(default)]
+    pub payment_options: Vec<String>,
+    #[serde(default)]
+    pub currencies: Vec<String>,
+    /// Installment counts offered, if installments are enabled
+    pub installments: Option<InstallmentRange>,
+    #[serde(default)]
+    pub features: AccountFeatures,
+}
+
+impl Capabilities {
+    /// Returns whether `option` can be offered at checkout, ignoring case
+    pub fn supports_payment_option(&self, option: &str) -> bool {
+        self.payment_options
+            .iter()
+            .any(|enabled| enabled.eq_ignore_ascii_case(option))
+    }
+
+    /// Returns whether orders can be created in `currency`
+    pub fn supports_currency(&self, currency: &str) -> bool {
+        self.currencies
+            .iter()
+            .any(|enabled| enabled.eq_ignore_ascii_case(currency))
+    }
+
+    /// Returns whether a purchase can be split into `count` installments
+    pub fn allows_installments(&self, count: u8) -> bool {
+        self.installments
+            .is_some_and(|range| (range.min..=range.max).contains(&count))
+    }
+}
+
+/// Bank account the merchant's settlements are paid out to
+///
+/// The IBAN is masked in `Debug` output.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PayoutBankAccount {
+    pub id: String,
+    pub iban: String,
+    pub account_holder: Option<String>,
+    pub bank_name: Option<String>,
+    pub currency: Option<String>,
+    /// Whether settlements are paid out to this account
+    #[serde(default)]
+    pub is_default: bool,
+    pub created_at: Option<String>,
+}
+
+/// Bank account to add for settlements; the IBAN is masked in `Debug` output
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AddBankAccountRequest {
+    pub iban: String,
+    pub account_holder: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// Makes the new account the default settlement account
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub set_default: Option<bool>,
+}
+
+impl fmt::Debug for PayoutBankAccount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PayoutBankAccount")
+            .field("id", &self.id)
+            .field("iban", &mask_iban(&self.iban))
+            .field("account_holder", &self.account_holder)
+            .field("bank_name", &self.bank_name)
+            .field("currency", &self.currency)
+            .field("is_default", &self.is_default)
+            .field("created_at", &self.created_at)
+            .finish()
+    }
+}
+
+impl fmt::Debug for AddBankAccountRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AddBankAccountRequest")
+            .field("iban", &mask_iban(&self.iban))
+            .field("account_holder", &self.account_holder)
+            .field("currency", &self.currency)
+            .field("set_default", &self.set_default)
+            .finish()
+    }
+}
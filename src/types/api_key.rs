@@ -0,0 +1,65 @@
+use crate::secret::SecretString;
+use serde::{Deserialize, Serialize};
+
+/// API key of the merchant account; the secret itself is only returned on creation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiKey {
+    pub id: String,
+    pub name: Option<String>,
+    /// Leading characters of the key, enough to recognise it in the panel
+    pub prefix: Option<String>,
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    pub created_at: Option<String>,
+    pub last_used_at: Option<String>,
+    pub expires_at: Option<String>,
+    pub revoked_at: Option<String>,
+}
+
+impl ApiKey {
+    /// Returns whether the key has been revoked
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CreateApiKeyRequest {
+    pub name: String,
+    /// Scopes granted to the key; all scopes of the creating key when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<String>>,
+    /// RFC 3339 timestamp after which the key stops working
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+}
+
+/// Newly created API key together with its secret
+///
+/// The secret cannot be retrieved again, so store it right away, e.g. with
+/// [`Config::new`](crate::Config::new) for the next client.
+#[derive(Debug, Clone)]
+pub struct CreatedApiKey {
+    pub key: ApiKey,
+    pub secret: SecretString,
+}
+
+/// Merchant, organization and scopes the current API key acts for
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyIdentity {
+    pub key_id: String,
+    pub merchant_id: Option<String>,
+    pub organization_id: Option<String>,
+    pub organization_name: Option<String>,
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    /// `live` or `test`
+    pub environment: Option<String>,
+}
+
+impl KeyIdentity {
+    /// Returns whether the key was granted `scope`
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.iter().any(|granted| granted == scope)
+    }
+}
@@ -0,0 +1,340 @@
+use crate::error::{Result, TapsilatError};
+use crate::tax::{basket_tax, PricingMode, VatRate};
+use crate::types::{
+    from_minor_units, to_minor_units, BasketItemDTO, CreateOrderRequest, SubmerchantDTO,
+};
+use std::collections::HashMap;
+
+/// Basket items with the order totals computed from them
+///
+/// Produced by [`BasketBuilder::build`] and passed to
+/// [`CreateOrderRequestBuilder::basket`](crate::types::CreateOrderRequestBuilder::basket).
+#[derive(Debug, Clone)]
+pub struct Basket {
+    pub items: Vec<BasketItemDTO>,
+    /// Sum of all line totals, VAT included
+    pub amount: f64,
+    /// VAT contained in `amount`
+    pub tax_amount: f64,
+}
+
+/// Collects basket items and computes `amount`, `tax_amount` and each item's
+/// `paid_amount`
+///
+/// With [`PricingMode::Exclusive`], unit prices are converted to VAT-inclusive prices
+/// so the items add up to the order amount as the API requires.
+///
+/// # Example
+///
+/// ```rust
+/// use tapsilat::tax::VatRate;
+/// use tapsilat::BasketBuilder;
+///
+/// let basket = BasketBuilder::new()
+///     .item("T-shirt", 120.0, 2, VatRate::Standard, "Apparel")
+///     .item("Bread", 10.1, 1, VatRate::Reduced, "Food")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(basket.amount, 250.1);
+/// assert_eq!(basket.tax_amount, 40.1);
+/// assert_eq!(basket.items[0].paid_amount, Some(240.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct BasketBuilder {
+    items: Vec<BasketLine>,
+    pricing: PricingMode,
+}
+
+#[derive(Debug, Clone)]
+struct BasketLine {
+    id: Option<String>,
+    name: String,
+    unit_price: f64,
+    quantity: i32,
+    vat_rate: VatRate,
+    category: String,
+}
+
+impl Default for BasketBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BasketBuilder {
+    /// Creates an empty basket priced VAT-inclusive
+    pub fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            pricing: PricingMode::Inclusive,
+        }
+    }
+
+    /// Sets whether unit prices include VAT (defaults to [`PricingMode::Inclusive`])
+    pub fn pricing(mut self, pricing: PricingMode) -> Self {
+        self.pricing = pricing;
+        self
+    }
+
+    /// Adds an item; its ID defaults to its position in the basket, starting at 1
+    pub fn item(
+        mut self,
+        name: impl Into<String>,
+        unit_price: f64,
+        quantity: i32,
+        vat_rate: VatRate,
+        category: impl Into<String>,
+    ) -> Self {
+        self.items.push(BasketLine {
+            id: None,
+            name: name.into(),
+            unit_price,
+            quantity,
+            vat_rate,
+            category: category.into(),
+        });
+        self
+    }
+
+    /// Adds an item with a caller-chosen ID, e.g. a SKU
+    pub fn item_with_id(
+        mut self,
+        id: impl Into<String>,
+        name: impl Into<String>,
+        unit_price: f64,
+        quantity: i32,
+        vat_rate: VatRate,
+        category: impl Into<String>,
+    ) -> Self {
+        self = self.item(name, unit_price, quantity, vat_rate, category);
+        if let Some(line) = self.items.last_mut() {
+            line.id = Some(id.into());
+        }
+        self
+    }
+
+    /// Validates the items and computes the basket totals
+    pub fn build(self) -> Result<Basket> {
+        if self.items.is_empty() {
+            return Err(TapsilatError::ValidationError(
+                "Basket must contain at least one item".to_string(),
+            ));
+        }
+
+        let mut ids = std::collections::HashSet::new();
+        let mut items = Vec::with_capacity(self.items.len());
+        for (index, line) in self.items.into_iter().enumerate() {
+            let id = line.id.unwrap_or_else(|| (index + 1).to_string());
+            if line.name.trim().is_empty() {
+                return Err(TapsilatError::ValidationError(format!(
+                    "Basket item {} has no name",
+                    id
+                )));
+            }
+            if line.category.trim().is_empty() {
+                return Err(TapsilatError::ValidationError(format!(
+                    "Basket item {} has no category",
+                    id
+                )));
+            }
+            if line.quantity < 1 {
+                return Err(TapsilatError::ValidationError(format!(
+                    "Basket item {} must have a quantity of at least 1",
+                    id
+                )));
+            }
+            if line.vat_rate.percent() < 0.0 {
+                return Err(TapsilatError::ValidationError(format!(
+                    "Basket item {} has a negative VAT rate",
+                    id
+                )));
+            }
+            crate::modules::Validators::validate_amount(line.unit_price)?;
+            if !ids.insert(id.clone()) {
+                return Err(TapsilatError::ValidationError(format!(
+                    "Basket item ID {} is used more than once",
+                    id
+                )));
+            }
+
+            let price = match self.pricing {
+                PricingMode::Inclusive => line.unit_price,
+                PricingMode::Exclusive => from_minor_units(
+                    (to_minor_units(line.unit_price) as f64
+                        * (1.0 + line.vat_rate.percent() / 100.0))
+                        .round() as i64,
+                ),
+            };
+            let mut item = BasketItemDTO {
+                id: Some(id),
+                name: Some(line.name),
+                category1: Some(line.category),
+                price: Some(price),
+                quantity: Some(line.quantity),
+                vat_rate: Some(line.vat_rate),
+                ..Default::default()
+            };
+            item.paid_amount = Some(item.line_total());
+            items.push(item);
+        }
+
+        let amount = from_minor_units(
+            items
+                .iter()
+                .map(|item| to_minor_units(item.line_total()))
+                .sum(),
+        );
+        let tax_amount = basket_tax(&items, PricingMode::Inclusive)?;
+        Ok(Basket {
+            items,
+            amount,
+            tax_amount,
+        })
+    }
+}
+
+/// Marketplace commission kept from a submerchant's basket item
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Commission {
+    /// Percentage of the item total after discounts
+    Percent(f64),
+    /// Fixed amount per basket item
+    Fixed(f64),
+}
+
+impl Commission {
+    fn minor_units(self, item_total: i64) -> i64 {
+        match self {
+            Commission::Percent(percent) => (item_total as f64 * percent / 100.0).round() as i64,
+            Commission::Fixed(amount) => to_minor_units(amount),
+        }
+    }
+}
+
+/// Allocates basket items to submerchants by their `sub_merchant_key`
+///
+/// Each item with a key pays its total after discounts, less commission, to that
+/// submerchant; items without a key belong to the marketplace itself. The commission
+/// is written to the item's `commission_amount`, the submerchant's share to
+/// `sub_merchant_price`, and one [`SubmerchantDTO`] is produced per item.
+///
+/// # Example
+///
+/// ```rust
+/// use tapsilat::{BasketItemDTO, Commission, SubmerchantSplitBuilder};
+///
+/// let mut items = vec![
+///     BasketItemDTO {
+///         id: Some("1".to_string()),
+///         price: Some(200.0),
+///         quantity: Some(1),
+///         sub_merchant_key: Some("seller_a".to_string()),
+///         ..Default::default()
+///     },
+///     BasketItemDTO {
+///         id: Some("2".to_string()),
+///         price: Some(50.0),
+///         quantity: Some(1),
+///         sub_merchant_key: Some("seller_b".to_string()),
+///         ..Default::default()
+///     },
+/// ];
+///
+/// let submerchants = SubmerchantSplitBuilder::new(Commission::Percent(10.0))
+///     .commission_for("seller_b", Commission::Fixed(2.5))
+///     .allocate(&mut items)
+///     .unwrap();
+///
+/// assert_eq!(submerchants[0].amount, Some(180.0));
+/// assert_eq!(submerchants[1].amount, Some(47.5));
+/// assert_eq!(items[1].commission_amount, Some(2.5));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SubmerchantSplitBuilder {
+    commission: Commission,
+    overrides: HashMap<String, Commission>,
+}
+
+impl SubmerchantSplitBuilder {
+    /// Starts a split charging `commission` on every submerchant item
+    pub fn new(commission: Commission) -> Self {
+        Self {
+            commission,
+            overrides: HashMap::new(),
+        }
+    }
+
+    /// Charges a different commission on the items of one submerchant
+    pub fn commission_for(
+        mut self,
+        sub_merchant_key: impl Into<String>,
+        commission: Commission,
+    ) -> Self {
+        self.overrides.insert(sub_merchant_key.into(), commission);
+        self
+    }
+
+    /// Computes the allocation and writes the commission and share into each item
+    ///
+    /// Items are only modified if the whole allocation is valid.
+    pub fn allocate(&self, items: &mut [BasketItemDTO]) -> Result<Vec<SubmerchantDTO>> {
+        let mut allocations = Vec::new();
+        for (index, item) in items.iter().enumerate() {
+            let Some(key) = item.sub_merchant_key.as_deref() else {
+                continue;
+            };
+            let total = to_minor_units(item.discounted_total());
+            let id = item.id.clone().ok_or_else(|| {
+                TapsilatError::ValidationError(format!(
+                    "Basket item for submerchant {} has no ID",
+                    key
+                ))
+            })?;
+
+            let commission = self.overrides.get(key).copied().unwrap_or(self.commission);
+            let commission = commission.minor_units(total);
+            if commission < 0 || commission > total {
+                return Err(TapsilatError::ValidationError(format!(
+                    "Commission {:.2} on basket item {} is outside 0 to {:.2}",
+                    from_minor_units(commission),
+                    id,
+                    from_minor_units(total)
+                )));
+            }
+            allocations.push((index, key.to_string(), id, commission, total - commission));
+        }
+
+        if allocations.is_empty() {
+            return Err(TapsilatError::ValidationError(
+                "No basket item has a sub_merchant_key".to_string(),
+            ));
+        }
+
+        Ok(allocations
+            .into_iter()
+            .map(|(index, key, id, commission, share)| {
+                let item = &mut items[index];
+                item.commission_amount = Some(from_minor_units(commission));
+                item.sub_merchant_price = Some(format!("{:.2}", from_minor_units(share)));
+                SubmerchantDTO {
+                    amount: Some(from_minor_units(share)),
+                    merchant_reference_id: Some(key),
+                    order_basket_item_id: Some(id),
+                }
+            })
+            .collect())
+    }
+
+    /// Allocates the request's basket and stores the result in `submerchants`
+    ///
+    /// Also checks that the basket adds up to the order amount.
+    pub fn apply(&self, request: &mut CreateOrderRequest) -> Result<()> {
+        let items = request.basket_items.as_mut().ok_or_else(|| {
+            TapsilatError::ValidationError("Order has no basket items to split".to_string())
+        })?;
+        crate::modules::Validators::validate_basket_total(items, request.amount)?;
+        request.submerchants = Some(self.allocate(items)?);
+        Ok(())
+    }
+}
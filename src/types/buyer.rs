@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, SocketAddr};
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Buyer {
@@ -58,6 +59,22 @@ pub struct CreateBuyerRequest {
     pub country: Option<String>,
     #[serde(rename = "zip_code")]
     pub zip_code: Option<String>,
+    /// Country of citizenship, e.g. `TR`; selects how `identity_number` is validated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub citizenship: Option<String>,
+}
+
+impl CreateBuyerRequest {
+    /// Sets `ip` from the buyer's address; IPv4-mapped IPv6 addresses are sent as IPv4
+    pub fn with_ip(mut self, ip: IpAddr) -> Self {
+        self.ip = Some(ip.to_canonical().to_string());
+        self
+    }
+
+    /// Sets `ip` from the remote address of the buyer's connection, dropping the port
+    pub fn with_remote_addr(self, addr: SocketAddr) -> Self {
+        self.with_ip(addr.ip())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -71,3 +88,42 @@ pub struct CreateAddressRequest {
     #[serde(rename = "contact_name")]
     pub contact_name: Option<String>,
 }
+
+/// Kind of event in a buyer's payment history
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentHistoryKind {
+    Order,
+    Payment,
+    Refund,
+    #[serde(other)]
+    Unknown,
+}
+
+/// One order, payment or refund in a buyer's payment history
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PaymentHistoryEntry {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: PaymentHistoryKind,
+    /// Reference ID of the order the entry belongs to
+    pub reference_id: Option<String>,
+    #[serde(default, deserialize_with = "crate::types::common::deserialize_amount")]
+    pub amount: f64,
+    pub currency: Option<String>,
+    pub status: Option<String>,
+    pub description: Option<String>,
+    pub occurred_at: Option<String>,
+}
+
+/// Filters for a buyer's payment history; unset fields are not sent
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PaymentHistoryFilter {
+    pub kind: Option<PaymentHistoryKind>,
+    /// Only entries on or after this date (`YYYY-MM-DD`)
+    pub start_date: Option<String>,
+    /// Only entries before this date (`YYYY-MM-DD`)
+    pub end_date: Option<String>,
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+}
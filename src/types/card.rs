@@ -0,0 +1,38 @@
+use crate::masking::{mask_card_number, Redacted};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Card to tokenize, for merchants with their own PCI DSS scope
+///
+/// The card number is masked and the holder name hidden in `Debug` output.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CardDetails {
+    pub card_number: String,
+    pub expiry_month: u8,
+    /// Four-digit expiry year
+    pub expiry_year: u16,
+    pub holder_name: String,
+}
+
+impl fmt::Debug for CardDetails {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CardDetails")
+            .field("card_number", &mask_card_number(&self.card_number))
+            .field("expiry_month", &self.expiry_month)
+            .field("expiry_year", &self.expiry_year)
+            .field("holder_name", &Redacted::new(&self.holder_name))
+            .finish()
+    }
+}
+
+/// Stored card returned by tokenization, usable in place of the card number
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CardToken {
+    pub card_token: String,
+    pub card_id: Option<String>,
+    /// Card number with all but the BIN and last four digits masked
+    pub masked_card_number: Option<String>,
+    /// Card scheme, e.g. `VISA` or `TROY`
+    pub card_brand: Option<String>,
+    pub expires_at: Option<String>,
+}
@@ -1,3 +1,4 @@
+use crate::error::TapsilatError;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,3 +28,183 @@ pub struct PaginationInfo {
     pub total: u32,
     pub total_pages: u32,
 }
+
+/// Strips a `{success, data, message}` envelope from a response body
+///
+/// Bare bodies are returned unchanged. A successful envelope with `data: null` yields
+/// `null`, while one without a `data` key is returned whole, so that fields such as
+/// `message` remain readable by the caller. An envelope with `success: false` becomes an [`TapsilatError::ApiError`] carrying the
+/// HTTP status the request succeeded with, so callers can treat it like any other
+/// API rejection.
+#[cfg_attr(not(any(feature = "http", feature = "wasm")), allow(dead_code))]
+pub(crate) fn unwrap_envelope(
+    value: serde_json::Value,
+    context: &str,
+    trace_id: Option<String>,
+) -> crate::error::Result<serde_json::Value> {
+    use serde_json::Value;
+
+    let success = match value.get("success") {
+        Some(Value::Bool(success)) => *success,
+        _ => return Ok(value),
+    };
+    if success && value.get("data").is_none() {
+        return Ok(value);
+    }
+
+    if !success {
+        let errors = value
+            .get("errors")
+            .and_then(Value::as_array)
+            .map(|errors| {
+                errors
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            })
+            .filter(|errors| !errors.is_empty());
+        return Err(TapsilatError::ApiError {
+            status_code: 200,
+            message: value
+                .get("message")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .or(errors)
+                .unwrap_or_else(|| format!("Unsuccessful {}", context)),
+            trace_id,
+        });
+    }
+
+    Ok(value.get("data").cloned().unwrap_or(Value::Null))
+}
+
+/// Decodes an empty response, which only succeeds for types that accept `null`
+#[cfg_attr(not(any(feature = "http", feature = "wasm")), allow(dead_code))]
+pub(crate) fn decode_null<T: serde::de::DeserializeOwned>(
+    context: &str,
+) -> crate::error::Result<T> {
+    serde_json::from_value(serde_json::Value::Null)
+        .map_err(|_| TapsilatError::InvalidResponse(format!("No data in {}", context)))
+}
+
+/// Typed response together with the raw body, status and headers it was decoded from
+///
+/// Returned by the `*_detailed` variants of the main calls, e.g.
+/// [`OrderModule::get_detailed`](crate::OrderModule::get_detailed), for keeping the exact
+/// API response in audit logs while working with the typed value.
+#[derive(Debug, Clone)]
+pub struct Detailed<T> {
+    pub value: T,
+    /// Response body as received, including any `{success, data, message}` envelope
+    pub raw: serde_json::Value,
+    /// HTTP status code; `None` for dry runs
+    pub status: Option<u16>,
+    /// Response headers with lower-case names
+    pub headers: Vec<(String, String)>,
+}
+
+impl<T> Detailed<T> {
+    /// Returns the value of a response header, matching the name case-insensitively
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Discards the raw response and returns the typed value
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+/// Converts a decimal amount to integer minor units (kuruş, cents) for exact arithmetic
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(tapsilat::to_minor_units(19.99), 1999);
+/// assert_eq!(tapsilat::from_minor_units(1999), 19.99);
+/// ```
+pub fn to_minor_units(amount: f64) -> i64 {
+    (amount * 100.0).round() as i64
+}
+
+/// Converts integer minor units back to a decimal amount
+pub fn from_minor_units(units: i64) -> f64 {
+    units as f64 / 100.0
+}
+
+/// Deserializes an optional amount sent either as a JSON number or a numeric string
+pub(crate) fn deserialize_optional_amount<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    match Option::<serde_json::Value>::deserialize(deserializer)? {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::Number(n)) => Ok(n.as_f64()),
+        Some(serde_json::Value::String(s)) if s.trim().is_empty() => Ok(None),
+        Some(serde_json::Value::String(s)) => s
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|_| serde::de::Error::custom(format!("invalid amount: {}", s))),
+        Some(other) => Err(serde::de::Error::custom(format!(
+            "invalid amount: {}",
+            other
+        ))),
+    }
+}
+
+/// Deserializes an amount sent either as a JSON number or a numeric string, treating
+/// a missing or empty value as zero
+pub(crate) fn deserialize_amount<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    deserialize_optional_amount(deserializer).map(|amount| amount.unwrap_or(0.0))
+}
+
+/// Serializes an optional `Duration` as whole seconds
+pub(crate) mod optional_duration_secs {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(
+        duration: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match duration {
+            Some(duration) => serializer.serialize_u64(duration.as_secs()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        Ok(Option::<u64>::deserialize(deserializer)?.map(Duration::from_secs))
+    }
+}
+
+/// Controls how long and how often a helper polls the API for a final state
+///
+/// The delay between attempts starts at `interval` and doubles after every attempt
+/// up to `max_interval`; polling gives up once `timeout` has elapsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PollOptions {
+    pub interval: std::time::Duration,
+    pub max_interval: std::time::Duration,
+    pub timeout: std::time::Duration,
+}
+
+impl Default for PollOptions {
+    fn default() -> Self {
+        Self {
+            interval: std::time::Duration::from_secs(2),
+            max_interval: std::time::Duration::from_secs(30),
+            timeout: std::time::Duration::from_secs(600),
+        }
+    }
+}
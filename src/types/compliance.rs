@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+/// Processing state of a data erasure request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErasureStatus {
+    /// Received and waiting to be processed
+    Pending,
+    InProgress,
+    /// Personal data has been erased or anonymized
+    Completed,
+    /// Refused, e.g. because records must be kept for legal retention periods
+    Rejected,
+    #[serde(other)]
+    Unknown,
+}
+
+impl ErasureStatus {
+    /// Returns whether the request will not change status anymore
+    pub fn is_final(&self) -> bool {
+        matches!(self, ErasureStatus::Completed | ErasureStatus::Rejected)
+    }
+}
+
+/// KVKK/GDPR request to erase a buyer's personal data
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErasureRequest {
+    pub request_id: String,
+    pub buyer_id: String,
+    pub status: ErasureStatus,
+    /// Explanation given when the request is rejected or only partly completed
+    pub reason: Option<String>,
+    pub requested_at: Option<String>,
+    pub completed_at: Option<String>,
+}
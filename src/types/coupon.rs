@@ -0,0 +1,88 @@
+use crate::types::{from_minor_units, to_minor_units};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CouponDiscountType {
+    #[serde(rename = "percentage")]
+    Percentage,
+    #[serde(rename = "fixed_amount")]
+    FixedAmount,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateCouponRequest {
+    pub code: String,
+    pub discount_type: CouponDiscountType,
+    /// Percentage (0-100) or fixed amount, depending on `discount_type`
+    pub value: f64,
+    pub currency: Option<String>,
+    pub max_redemptions: Option<u32>,
+    /// RFC 3339 timestamp after which the coupon can no longer be used
+    pub expires_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Coupon {
+    pub id: Option<String>,
+    pub code: String,
+    pub discount_type: CouponDiscountType,
+    pub value: f64,
+    pub currency: Option<String>,
+    pub max_redemptions: Option<u32>,
+    pub times_redeemed: Option<u32>,
+    pub expires_at: Option<String>,
+    #[serde(default = "default_active")]
+    pub active: bool,
+    pub created_at: Option<String>,
+}
+
+fn default_active() -> bool {
+    true
+}
+
+impl Coupon {
+    /// Returns the discount this coupon gives on `amount`, rounded to two decimals
+    /// and never more than `amount` itself
+    pub fn discount_for(&self, amount: f64) -> f64 {
+        let amount = to_minor_units(amount).max(0);
+        let discount = match self.discount_type {
+            CouponDiscountType::Percentage => {
+                (amount as f64 * self.value.clamp(0.0, 100.0) / 100.0).round() as i64
+            }
+            CouponDiscountType::FixedAmount => to_minor_units(self.value).max(0),
+        };
+        from_minor_units(discount.min(amount))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::BasketItemDTO;
+
+    #[test]
+    fn test_basket_item_coupon() {
+        let mut item = BasketItemDTO {
+            price: Some(40.0),
+            quantity: Some(2),
+            ..Default::default()
+        };
+        let coupon = Coupon {
+            id: None,
+            code: "SPRING10".to_string(),
+            discount_type: CouponDiscountType::Percentage,
+            value: 10.0,
+            currency: None,
+            max_redemptions: None,
+            times_redeemed: None,
+            expires_at: None,
+            active: true,
+            created_at: None,
+        };
+
+        item.apply(&coupon).unwrap();
+        assert_eq!(item.coupon_discount, Some(8.0));
+        assert!(item.apply_coupon("TOO_MUCH", 80.01).is_err());
+        assert_eq!(item.coupon.as_deref(), Some("SPRING10"));
+    }
+}
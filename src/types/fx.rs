@@ -0,0 +1,68 @@
+use crate::error::{Result, TapsilatError};
+use crate::types::{from_minor_units, to_minor_units};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
+
+/// Exchange rates Tapsilat settles with, quoted against `base`
+///
+/// `rates["TRY"]` is the amount of TRY one unit of `base` buys.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExchangeRates {
+    pub base: String,
+    #[serde(deserialize_with = "deserialize_rates")]
+    pub rates: HashMap<String, f64>,
+    pub updated_at: Option<String>,
+}
+
+impl ExchangeRates {
+    /// Returns the rate converting `from` into `to`, crossing through the base currency
+    pub fn rate(&self, from: &str, to: &str) -> Option<f64> {
+        let from = self.base_rate(from)?;
+        let to = self.base_rate(to)?;
+        Some(to / from)
+    }
+
+    /// Converts `amount` from one currency into another, rounded to minor units
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TapsilatError::ValidationError`] if either currency has no rate.
+    pub fn convert(&self, amount: f64, from: &str, to: &str) -> Result<f64> {
+        let rate = self.rate(from, to).ok_or_else(|| {
+            TapsilatError::ValidationError(format!("No exchange rate from {} to {}", from, to))
+        })?;
+        let converted = to_minor_units(amount) as f64 * rate;
+        Ok(from_minor_units(converted.round() as i64))
+    }
+
+    fn base_rate(&self, currency: &str) -> Option<f64> {
+        if currency.eq_ignore_ascii_case(&self.base) {
+            return Some(1.0);
+        }
+        self.rates
+            .iter()
+            .find(|(code, _)| code.eq_ignore_ascii_case(currency))
+            .map(|(_, rate)| *rate)
+            .filter(|rate| *rate > 0.0)
+    }
+}
+
+/// Deserializes rates sent either as JSON numbers or numeric strings
+fn deserialize_rates<'de, D>(deserializer: D) -> std::result::Result<HashMap<String, f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    HashMap::<String, serde_json::Value>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(currency, rate)| {
+            let parsed = match &rate {
+                serde_json::Value::Number(n) => n.as_f64(),
+                serde_json::Value::String(s) => s.trim().parse().ok(),
+                _ => None,
+            };
+            parsed
+                .map(|rate| (currency, rate))
+                .ok_or_else(|| serde::de::Error::custom(format!("invalid exchange rate: {}", rate)))
+        })
+        .collect()
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InvoiceType {
+    /// e-Fatura, for buyers registered with the e-invoice system
+    #[serde(rename = "e_invoice")]
+    EInvoice,
+    /// e-Arşiv, for everyone else; delivered by email
+    #[serde(rename = "e_archive")]
+    EArchive,
+}
+
+/// Invoicing details sent with an order
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InvoiceDTO {
+    pub invoice_type: Option<InvoiceType>,
+    pub tax_office: Option<String>,
+    /// VKN for companies or TCKN for individuals
+    pub tax_number: Option<String>,
+    pub title: Option<String>,
+    /// Address the e-archive invoice is emailed to
+    pub e_archive_email: Option<String>,
+}
+
+/// A generated e-invoice or e-archive document
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Invoice {
+    pub invoice_id: Option<String>,
+    pub invoice_number: Option<String>,
+    pub invoice_type: Option<InvoiceType>,
+    /// ETTN, the document UUID registered with the tax authority
+    pub ettn: Option<String>,
+    pub status: Option<String>,
+    pub issued_at: Option<String>,
+    pub pdf_url: Option<String>,
+}
@@ -0,0 +1,79 @@
+use crate::error::{Result, TapsilatError};
+use crate::types::order::{Order, OrderStatus};
+
+/// Tracks an order's status and the transitions allowed from it
+///
+/// Legal transitions are:
+///
+/// - `Pending` → `Processing`, `Failed`, `Cancelled`
+/// - `Processing` → `Paid`, `Completed`, `Failed`, `Cancelled`
+/// - `Paid` → `Completed`, `Refunded`, `PartiallyRefunded`
+/// - `Completed` → `Refunded`, `PartiallyRefunded`
+/// - `PartiallyRefunded` → `PartiallyRefunded`, `Refunded`
+///
+/// `Failed`, `Cancelled` and `Refunded` are final.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderLifecycle {
+    status: OrderStatus,
+}
+
+impl OrderLifecycle {
+    pub fn new(status: OrderStatus) -> Self {
+        Self { status }
+    }
+
+    /// Builds the lifecycle of a fetched order, if its status is recognised
+    pub fn from_order(order: &Order) -> Option<Self> {
+        order
+            .status_enum
+            .as_deref()
+            .and_then(OrderStatus::parse)
+            .map(Self::new)
+    }
+
+    /// Returns the current status
+    pub fn status(&self) -> &OrderStatus {
+        &self.status
+    }
+
+    /// Returns true if the order may move from its current status to `next`
+    pub fn can_transition_to(&self, next: &OrderStatus) -> bool {
+        use OrderStatus::*;
+
+        matches!(
+            (&self.status, next),
+            (Pending, Processing | Failed | Cancelled)
+                | (Processing, Paid | Completed | Failed | Cancelled)
+                | (Paid, Completed | Refunded | PartiallyRefunded)
+                | (Completed, Refunded | PartiallyRefunded)
+                | (PartiallyRefunded, PartiallyRefunded | Refunded)
+        )
+    }
+
+    /// Moves the order to `next`, rejecting transitions that are not allowed
+    pub fn transition(&mut self, next: OrderStatus) -> Result<()> {
+        if !self.can_transition_to(&next) {
+            return Err(TapsilatError::ValidationError(format!(
+                "Order cannot move from {:?} to {:?}",
+                self.status, next
+            )));
+        }
+        self.status = next;
+        Ok(())
+    }
+
+    /// Returns true if the order has been paid and still has a refundable balance
+    pub fn can_refund(&self) -> bool {
+        self.can_transition_to(&OrderStatus::PartiallyRefunded)
+    }
+
+    /// Returns true if the order can still be cancelled
+    pub fn can_cancel(&self) -> bool {
+        self.can_transition_to(&OrderStatus::Cancelled)
+    }
+
+    /// Returns true if the order is authorized and waiting to be captured
+    pub fn can_capture(&self) -> bool {
+        self.status == OrderStatus::Processing
+    }
+}
@@ -0,0 +1,142 @@
+use crate::types::MetadataDTO;
+use std::collections::{BTreeMap, HashMap};
+
+/// Key/value metadata attached to orders
+///
+/// The API represents metadata as a list of `{key, value}` string pairs. `Metadata`
+/// keeps insertion order, replaces values on duplicate keys and stringifies numbers
+/// and booleans on insert.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use tapsilat::{Metadata, MetadataDTO};
+///
+/// let metadata = Metadata::new()
+///     .with("customer_tier", "gold")
+///     .with("loyalty_points", 1200)
+///     .with("gift", true);
+/// let dtos: Vec<MetadataDTO> = metadata.into();
+/// assert_eq!(dtos[1].value, "1200");
+///
+/// let from_map: Metadata = HashMap::from([("channel", "web")]).into();
+/// assert_eq!(from_map.get("channel"), Some("web"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Metadata {
+    entries: Vec<(String, String)>,
+}
+
+impl Metadata {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds or replaces an entry, returning the metadata for chaining
+    pub fn with(mut self, key: impl Into<String>, value: impl ToString) -> Self {
+        self.insert(key, value);
+        self
+    }
+
+    /// Adds or replaces an entry
+    pub fn insert(&mut self, key: impl Into<String>, value: impl ToString) {
+        let key = key.into();
+        let value = value.to_string();
+        match self.entries.iter_mut().find(|(k, _)| *k == key) {
+            Some(entry) => entry.1 = value,
+            None => self.entries.push((key, value)),
+        }
+    }
+
+    /// Returns the value stored under `key`
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the metadata as a map, dropping ordering
+    pub fn to_map(&self) -> HashMap<String, String> {
+        self.entries.iter().cloned().collect()
+    }
+}
+
+impl<K: Into<String>, V: ToString> FromIterator<(K, V)> for Metadata {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut metadata = Metadata::new();
+        for (key, value) in iter {
+            metadata.insert(key, value);
+        }
+        metadata
+    }
+}
+
+/// Entries are sorted by key so the serialized order is stable
+impl<K: Into<String> + Ord, V: ToString> From<HashMap<K, V>> for Metadata {
+    fn from(map: HashMap<K, V>) -> Self {
+        map.into_iter()
+            .collect::<BTreeMap<K, V>>()
+            .into_iter()
+            .collect()
+    }
+}
+
+impl<K: Into<String>, V: ToString> From<BTreeMap<K, V>> for Metadata {
+    fn from(map: BTreeMap<K, V>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+impl From<Vec<MetadataDTO>> for Metadata {
+    fn from(dtos: Vec<MetadataDTO>) -> Self {
+        dtos.into_iter().map(|dto| (dto.key, dto.value)).collect()
+    }
+}
+
+impl From<&[MetadataDTO]> for Metadata {
+    fn from(dtos: &[MetadataDTO]) -> Self {
+        dtos.iter()
+            .map(|dto| (dto.key.clone(), dto.value.clone()))
+            .collect()
+    }
+}
+
+impl From<Metadata> for Vec<MetadataDTO> {
+    fn from(metadata: Metadata) -> Self {
+        metadata
+            .entries
+            .into_iter()
+            .map(|(key, value)| MetadataDTO { key, value })
+            .collect()
+    }
+}
+
+impl From<Metadata> for HashMap<String, String> {
+    fn from(metadata: Metadata) -> Self {
+        metadata.entries.into_iter().collect()
+    }
+}
+
+impl MetadataDTO {
+    /// Creates an entry, stringifying the value
+    pub fn new(key: impl Into<String>, value: impl ToString) -> Self {
+        Self {
+            key: key.into(),
+            value: value.to_string(),
+        }
+    }
+}
@@ -1,15 +1,44 @@
+pub mod account;
+pub mod api_key;
+pub mod basket;
 pub mod buyer;
+pub mod card;
 pub mod common;
+pub mod compliance;
+pub mod coupon;
+pub mod fx;
+pub mod invoice;
+pub mod lifecycle;
+pub mod metadata;
+pub mod onboarding;
 pub mod order;
 pub mod organization;
 pub mod payment;
+pub mod payment_term;
+pub mod report;
 pub mod subscription;
 pub mod webhook;
 
-pub use buyer::{Address, Buyer, CreateAddressRequest, CreateBuyerRequest};
+pub use account::*;
+pub use api_key::*;
+pub use basket::*;
+pub use buyer::{
+    Address, Buyer, CreateAddressRequest, CreateBuyerRequest, PaymentHistoryEntry,
+    PaymentHistoryFilter, PaymentHistoryKind,
+};
+pub use card::*;
 pub use common::*;
+pub use compliance::*;
+pub use coupon::*;
+pub use fx::*;
+pub use invoice::*;
+pub use lifecycle::*;
+pub use metadata::*;
+pub use onboarding::*;
 pub use order::*;
 pub use organization::*;
 pub use payment::*;
+pub use payment_term::*;
+pub use report::*;
 pub use subscription::*;
 pub use webhook::*;
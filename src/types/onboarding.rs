@@ -0,0 +1,128 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Verification state of a sub-organization; payouts start once it is `Approved`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KycStatus {
+    /// No documents submitted yet
+    NotStarted,
+    /// Documents are being collected
+    Pending,
+    /// Submitted and waiting for review
+    InReview,
+    Approved,
+    Rejected,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Kind of document a sub-organization must provide for verification
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DocumentType {
+    /// Vergi levhası
+    TaxCertificate,
+    /// İmza sirküleri
+    SignatureCircular,
+    /// Ticaret sicil gazetesi
+    TradeRegistryGazette,
+    IdentityCard,
+    /// Bank statement or letter proving ownership of the payout IBAN
+    BankStatement,
+    #[serde(other)]
+    Other,
+}
+
+/// Review state of a single onboarding document
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DocumentStatus {
+    /// Not uploaded yet
+    Missing,
+    /// Uploaded and waiting for review
+    Uploaded,
+    Approved,
+    Rejected,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Entry of the document checklist of a sub-organization
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequiredDocument {
+    pub document_type: DocumentType,
+    pub status: DocumentStatus,
+    /// Whether verification can complete without this document
+    #[serde(default)]
+    pub optional: bool,
+    pub rejection_reason: Option<String>,
+}
+
+impl RequiredDocument {
+    /// Returns whether the document still has to be uploaded, or re-uploaded after a rejection
+    pub fn needs_upload(&self) -> bool {
+        !self.optional
+            && matches!(
+                self.status,
+                DocumentStatus::Missing | DocumentStatus::Rejected
+            )
+    }
+}
+
+/// Verification status and document checklist of a sub-organization
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OnboardingStatus {
+    pub sub_organization_id: String,
+    pub kyc_status: KycStatus,
+    pub rejection_reason: Option<String>,
+    #[serde(default)]
+    pub documents: Vec<RequiredDocument>,
+    pub updated_at: Option<String>,
+}
+
+impl OnboardingStatus {
+    /// Returns whether the sub-organization is verified and can take payments
+    pub fn is_approved(&self) -> bool {
+        self.kyc_status == KycStatus::Approved
+    }
+
+    /// Returns the checklist entries that still need an upload
+    pub fn outstanding_documents(&self) -> Vec<&RequiredDocument> {
+        self.documents
+            .iter()
+            .filter(|document| document.needs_upload())
+            .collect()
+    }
+}
+
+/// Document file to upload for a sub-organization; the content is left out of `Debug`
+#[derive(Clone)]
+pub struct DocumentUpload {
+    pub document_type: DocumentType,
+    pub file_name: String,
+    /// MIME type of the file, e.g. `application/pdf` or `image/jpeg`
+    pub content_type: String,
+    pub content: Vec<u8>,
+}
+
+impl fmt::Debug for DocumentUpload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DocumentUpload")
+            .field("document_type", &self.document_type)
+            .field("file_name", &self.file_name)
+            .field("content_type", &self.content_type)
+            .field("content", &format_args!("<{} bytes>", self.content.len()))
+            .finish()
+    }
+}
+
+/// Document stored by the API after an upload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadedDocument {
+    pub id: String,
+    pub document_type: DocumentType,
+    pub status: DocumentStatus,
+    pub file_name: Option<String>,
+    pub uploaded_at: Option<String>,
+}
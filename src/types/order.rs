@@ -1,4 +1,4 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 
@@ -17,8 +17,13 @@ pub struct Order {
 
     pub status: Option<i32>, // Status is int in logs
     pub status_enum: Option<String>,
+    /// Reason given when the order was cancelled
+    #[serde(default)]
+    pub cancel_reason: Option<CancelReason>,
 
     pub description: Option<String>,
+    #[serde(default)]
+    pub external_reference_id: Option<String>,
     pub buyer: Option<Buyer>,
     pub items: Option<Vec<OrderItem>>, // Items missing in top level? JSON has basket_items?
 
@@ -31,6 +36,60 @@ pub struct Order {
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
     pub metadata: Option<Vec<MetadataDTO>>, // JSON metadata is array of key/value
+    pub payment_terms: Option<Vec<PaymentTerm>>,
+}
+
+impl Order {
+    /// Returns the order's lifecycle, if its status is recognised
+    pub fn lifecycle(&self) -> Option<OrderLifecycle> {
+        OrderLifecycle::from_order(self)
+    }
+
+    /// Returns the paid amount as a number, if present and numeric
+    pub fn paid_amount_value(&self) -> Option<f64> {
+        parse_amount(self.paid_amount.as_deref())
+    }
+
+    /// Returns the refunded amount as a number, treating a missing value as zero
+    pub fn refunded_amount_value(&self) -> f64 {
+        parse_amount(self.refunded_amount.as_deref()).unwrap_or(0.0)
+    }
+
+    /// Returns the order metadata as a map
+    ///
+    /// If a key appears more than once, the last value wins, as in
+    /// [`Metadata`](crate::types::Metadata).
+    pub fn metadata_map(&self) -> HashMap<String, String> {
+        self.metadata
+            .iter()
+            .flatten()
+            .map(|m| (m.key.clone(), m.value.clone()))
+            .collect()
+    }
+
+    /// Returns the metadata value stored under `key`
+    ///
+    /// If the key appears more than once, the last value wins, matching
+    /// [`metadata_map`](Self::metadata_map).
+    pub fn metadata_value(&self, key: &str) -> Option<&str> {
+        self.metadata
+            .iter()
+            .flatten()
+            .rev()
+            .find(|m| m.key == key)
+            .map(|m| m.value.as_str())
+    }
+
+    /// Returns the amount that can still be refunded, if the paid amount is known
+    pub fn refundable_amount(&self) -> Option<f64> {
+        let paid = to_minor_units(self.paid_amount_value()?);
+        let refunded = to_minor_units(self.refunded_amount_value());
+        Some(from_minor_units((paid - refunded).max(0)))
+    }
+}
+
+fn parse_amount(amount: Option<&str>) -> Option<f64> {
+    amount.and_then(|a| a.trim().parse().ok())
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,12 +100,14 @@ pub struct OrderItem {
     pub description: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum OrderStatus {
     #[serde(rename = "pending")]
     Pending,
     #[serde(rename = "processing")]
     Processing,
+    #[serde(rename = "paid")]
+    Paid,
     #[serde(rename = "completed")]
     Completed,
     #[serde(rename = "failed")]
@@ -59,7 +120,29 @@ pub enum OrderStatus {
     PartiallyRefunded,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl OrderStatus {
+    /// Parses a status string as returned by the status endpoint, ignoring case
+    pub fn parse(status: &str) -> Option<Self> {
+        match status.trim().to_ascii_lowercase().as_str() {
+            "pending" => Some(OrderStatus::Pending),
+            "processing" => Some(OrderStatus::Processing),
+            "paid" => Some(OrderStatus::Paid),
+            "completed" => Some(OrderStatus::Completed),
+            "failed" => Some(OrderStatus::Failed),
+            "cancelled" | "canceled" => Some(OrderStatus::Cancelled),
+            "refunded" => Some(OrderStatus::Refunded),
+            "partially_refunded" => Some(OrderStatus::PartiallyRefunded),
+            _ => None,
+        }
+    }
+
+    /// Returns true once the order can no longer change through checkout
+    pub fn is_terminal(&self) -> bool {
+        !matches!(self, OrderStatus::Pending | OrderStatus::Processing)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Currency {
     #[serde(rename = "TRY")]
     TRY,
@@ -71,6 +154,78 @@ pub enum Currency {
     GBP,
 }
 
+impl Currency {
+    /// Returns the ISO 4217 code, e.g. `TRY`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Currency::TRY => "TRY",
+            Currency::USD => "USD",
+            Currency::EUR => "EUR",
+            Currency::GBP => "GBP",
+        }
+    }
+
+    /// Returns the currency symbol, e.g. `₺`
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Currency::TRY => "₺",
+            Currency::USD => "$",
+            Currency::EUR => "€",
+            Currency::GBP => "£",
+        }
+    }
+}
+
+/// Language of the checkout page
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    Tr,
+    En,
+}
+
+impl Locale {
+    /// Returns the locale code sent to the API, e.g. `tr`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Locale::Tr => "tr",
+            Locale::En => "en",
+        }
+    }
+}
+
+/// 3D Secure intent for an order
+///
+/// Sent as the API's `three_d_force` flag: `Force` is `true`, `Disable` is `false`
+/// and `Auto` leaves the decision to the merchant's account settings (`null`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThreeDSPreference {
+    #[default]
+    Auto,
+    Force,
+    Disable,
+}
+
+impl Serialize for ThreeDSPreference {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ThreeDSPreference::Auto => serializer.serialize_none(),
+            ThreeDSPreference::Force => serializer.serialize_bool(true),
+            ThreeDSPreference::Disable => serializer.serialize_bool(false),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ThreeDSPreference {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match Option::<bool>::deserialize(deserializer)? {
+            None => ThreeDSPreference::Auto,
+            Some(true) => ThreeDSPreference::Force,
+            Some(false) => ThreeDSPreference::Disable,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateOrderRequest {
     pub amount: f64,
@@ -94,7 +249,7 @@ pub struct CreateOrderRequest {
     pub external_reference_id: Option<String>,
     pub metadata: Option<Vec<MetadataDTO>>,
     #[serde(rename = "order_cards")]
-    pub order_cards: Option<OrderCardDTO>,
+    pub order_cards: Option<Vec<OrderCardDTO>>,
     #[serde(rename = "paid_amount")]
     pub paid_amount: Option<f64>,
     #[serde(rename = "partial_payment")]
@@ -124,9 +279,181 @@ pub struct CreateOrderRequest {
     pub submerchants: Option<Vec<SubmerchantDTO>>,
     #[serde(rename = "tax_amount")]
     pub tax_amount: Option<f64>,
-    #[serde(rename = "three_d_force")]
-    pub three_d_force: Option<bool>,
+    #[serde(rename = "three_d_force", default)]
+    pub three_ds: ThreeDSPreference,
     pub consents: Option<Vec<OrderConsent>>,
+    /// How long the checkout link stays valid; sent as whole seconds
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::types::common::optional_duration_secs"
+    )]
+    pub checkout_expires_in: Option<std::time::Duration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice: Option<InvoiceDTO>,
+}
+
+impl CreateOrderRequest {
+    /// Starts a request whose currency and locale, unless set, are filled in from
+    /// [`Config::default_currency`](crate::Config::default_currency) and
+    /// [`Config::default_locale`](crate::Config::default_locale) when the order is created
+    pub fn builder() -> CreateOrderRequestBuilder {
+        CreateOrderRequestBuilder::new()
+    }
+}
+
+/// Builds a [`CreateOrderRequest`], taking the amounts from a [`Basket`]
+///
+/// Fields without a setter can be changed on the built request.
+///
+/// # Example
+///
+/// ```rust
+/// use tapsilat::tax::VatRate;
+/// use tapsilat::{BasketBuilder, CreateBuyerRequest, CreateOrderRequest};
+///
+/// let basket = BasketBuilder::new()
+///     .item("Headphones", 1200.0, 1, VatRate::Standard, "Electronics")
+///     .build()
+///     .unwrap();
+///
+/// let request = CreateOrderRequest::builder()
+///     .buyer(CreateBuyerRequest {
+///         name: "Ada".to_string(),
+///         surname: "Lovelace".to_string(),
+///         ..Default::default()
+///     })
+///     .basket(basket)
+///     .conversation_id("cart-42")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(request.amount, 1200.0);
+/// assert_eq!(request.tax_amount, Some(200.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CreateOrderRequestBuilder {
+    request: CreateOrderRequest,
+}
+
+impl Default for CreateOrderRequestBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CreateOrderRequestBuilder {
+    pub fn new() -> Self {
+        Self {
+            request: CreateOrderRequest {
+                amount: 0.0,
+                currency: String::new(),
+                locale: String::new(),
+                buyer: CreateBuyerRequest::default(),
+                basket_items: None,
+                billing_address: None,
+                checkout_design: None,
+                conversation_id: None,
+                enabled_installments: None,
+                external_reference_id: None,
+                metadata: None,
+                order_cards: None,
+                paid_amount: None,
+                partial_payment: None,
+                payment_failure_url: None,
+                payment_methods: None,
+                payment_mode: None,
+                payment_options: None,
+                payment_success_url: None,
+                payment_terms: None,
+                pf_sub_merchant: None,
+                redirect_failure_url: None,
+                redirect_success_url: None,
+                shipping_address: None,
+                sub_organization: None,
+                submerchants: None,
+                tax_amount: None,
+                three_ds: ThreeDSPreference::Auto,
+                consents: None,
+                checkout_expires_in: None,
+                invoice: None,
+            },
+        }
+    }
+
+    /// Sets the amount of an order without basket items
+    pub fn amount(mut self, amount: f64) -> Self {
+        self.request.amount = amount;
+        self
+    }
+
+    /// Sets the basket items together with `amount` and `tax_amount`
+    pub fn basket(mut self, basket: Basket) -> Self {
+        self.request.amount = basket.amount;
+        self.request.tax_amount = Some(basket.tax_amount);
+        self.request.basket_items = Some(basket.items);
+        self
+    }
+
+    pub fn currency(mut self, currency: impl Into<String>) -> Self {
+        self.request.currency = currency.into();
+        self
+    }
+
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.request.locale = locale.into();
+        self
+    }
+
+    pub fn buyer(mut self, buyer: CreateBuyerRequest) -> Self {
+        self.request.buyer = buyer;
+        self
+    }
+
+    pub fn conversation_id(mut self, conversation_id: impl Into<String>) -> Self {
+        self.request.conversation_id = Some(conversation_id.into());
+        self
+    }
+
+    pub fn external_reference_id(mut self, external_reference_id: impl Into<String>) -> Self {
+        self.request.external_reference_id = Some(external_reference_id.into());
+        self
+    }
+
+    pub fn enabled_installments(mut self, installments: Vec<i32>) -> Self {
+        self.request.enabled_installments = Some(installments);
+        self
+    }
+
+    pub fn payment_success_url(mut self, url: impl Into<String>) -> Self {
+        self.request.payment_success_url = Some(url.into());
+        self
+    }
+
+    pub fn payment_failure_url(mut self, url: impl Into<String>) -> Self {
+        self.request.payment_failure_url = Some(url.into());
+        self
+    }
+
+    pub fn three_ds(mut self, three_ds: ThreeDSPreference) -> Self {
+        self.request.three_ds = three_ds;
+        self
+    }
+
+    /// Checks the amount, basket and buyer and returns the request
+    pub fn build(self) -> Result<CreateOrderRequest, TapsilatError> {
+        crate::modules::Validators::validate_amount(self.request.amount)?;
+        if self.request.buyer.name.trim().is_empty() || self.request.buyer.surname.trim().is_empty()
+        {
+            return Err(TapsilatError::ValidationError(
+                "Buyer name and surname are required".to_string(),
+            ));
+        }
+        if let Some(items) = &self.request.basket_items {
+            crate::modules::Validators::validate_basket_total(items, self.request.amount)?;
+        }
+        Ok(self.request)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -157,6 +484,105 @@ pub struct CreateOrderResponse {
     pub checkout_url: Option<String>,
 }
 
+/// Token for rendering an order's checkout inside an iframe or embedded widget
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckoutToken {
+    pub token: String,
+    pub expires_at: Option<String>,
+    pub checkout_url: Option<String>,
+}
+
+impl CheckoutToken {
+    /// Returns the expiry as a UTC timestamp, if present and RFC 3339 formatted
+    pub fn expires_at_utc(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.expires_at
+            .as_deref()
+            .and_then(|at| chrono::DateTime::parse_from_rfc3339(at).ok())
+            .map(|at| at.with_timezone(&chrono::Utc))
+    }
+
+    /// Returns true if the token has an expiry that is already in the past
+    pub fn is_expired(&self) -> bool {
+        self.expires_at_utc()
+            .is_some_and(|at| at <= chrono::Utc::now())
+    }
+}
+
+/// Shortened or custom-domain checkout link of an order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShortCheckoutUrl {
+    pub short_url: String,
+    /// Full checkout URL the short link redirects to
+    pub checkout_url: Option<String>,
+    /// Domain serving the short link
+    pub domain: Option<String>,
+    pub expires_at: Option<String>,
+}
+
+impl ShortCheckoutUrl {
+    /// Returns the expiry as a UTC timestamp, if present and RFC 3339 formatted
+    pub fn expires_at_utc(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.expires_at
+            .as_deref()
+            .and_then(|at| chrono::DateTime::parse_from_rfc3339(at).ok())
+            .map(|at| at.with_timezone(&chrono::Utc))
+    }
+
+    /// Returns true if the link has an expiry that is already in the past
+    pub fn is_expired(&self) -> bool {
+        self.expires_at_utc()
+            .is_some_and(|at| at <= chrono::Utc::now())
+    }
+}
+
+/// Internal note stored on an order, e.g. support context on a dispute or refund
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderNote {
+    pub id: String,
+    pub text: String,
+    /// User or API key that wrote the note
+    pub author: Option<String>,
+    pub created_at: Option<String>,
+}
+
+/// Payment receipt of a paid order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Receipt {
+    pub receipt_number: String,
+    pub reference_id: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::types::common::deserialize_optional_amount"
+    )]
+    pub amount: Option<f64>,
+    pub currency: Option<String>,
+    pub paid_at: Option<String>,
+    pub payment_method: Option<String>,
+    /// Masked number of the card the order was paid with
+    pub masked_card_number: Option<String>,
+    pub buyer_email: Option<String>,
+    /// Link to the PDF rendering of the receipt
+    pub pdf_url: Option<String>,
+}
+
+/// Havale/EFT instructions for an order that accepts bank transfer payments
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BankTransferInfo {
+    pub bank_name: Option<String>,
+    pub account_holder: Option<String>,
+    /// Virtual IBAN assigned to the order
+    pub iban: String,
+    /// Code the buyer must include in the transfer description
+    pub reference_code: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::types::common::deserialize_optional_amount"
+    )]
+    pub amount: Option<f64>,
+    pub currency: Option<String>,
+    pub expires_at: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RefundOrderRequest {
     pub amount: f64,
@@ -166,6 +592,8 @@ pub struct RefundOrderRequest {
     pub order_item_id: Option<String>,
     #[serde(rename = "order_item_payment_id")]
     pub order_item_payment_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<RefundReason>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -173,6 +601,276 @@ pub struct RefundOrderResponse {
     pub order: Order,
     pub refund_amount: f64,
     pub refund_id: String,
+    #[serde(default)]
+    pub reason: Option<RefundReason>,
+}
+
+/// Why a refund was issued
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RefundReason {
+    CustomerRequest,
+    Fraud,
+    OutOfStock,
+    Duplicate,
+    /// Any other reason, sent and received verbatim
+    Other(String),
+}
+
+impl RefundReason {
+    pub fn as_str(&self) -> &str {
+        match self {
+            RefundReason::CustomerRequest => "customer_request",
+            RefundReason::Fraud => "fraud",
+            RefundReason::OutOfStock => "out_of_stock",
+            RefundReason::Duplicate => "duplicate",
+            RefundReason::Other(reason) => reason,
+        }
+    }
+}
+
+impl From<&str> for RefundReason {
+    fn from(reason: &str) -> Self {
+        match reason.to_ascii_lowercase().as_str() {
+            "customer_request" => RefundReason::CustomerRequest,
+            "fraud" => RefundReason::Fraud,
+            "out_of_stock" => RefundReason::OutOfStock,
+            "duplicate" => RefundReason::Duplicate,
+            _ => RefundReason::Other(reason.to_string()),
+        }
+    }
+}
+
+impl Serialize for RefundReason {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for RefundReason {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let reason = String::deserialize(deserializer)?;
+        Ok(RefundReason::from(reason.as_str()))
+    }
+}
+
+/// Why an order was cancelled
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CancelReason {
+    CustomerRequest,
+    Fraud,
+    OutOfStock,
+    Duplicate,
+    /// Any other reason, sent and received verbatim
+    Other(String),
+}
+
+impl CancelReason {
+    pub fn as_str(&self) -> &str {
+        match self {
+            CancelReason::CustomerRequest => "customer_request",
+            CancelReason::Fraud => "fraud",
+            CancelReason::OutOfStock => "out_of_stock",
+            CancelReason::Duplicate => "duplicate",
+            CancelReason::Other(reason) => reason,
+        }
+    }
+}
+
+impl From<&str> for CancelReason {
+    fn from(reason: &str) -> Self {
+        match reason.to_ascii_lowercase().as_str() {
+            "customer_request" => CancelReason::CustomerRequest,
+            "fraud" => CancelReason::Fraud,
+            "out_of_stock" => CancelReason::OutOfStock,
+            "duplicate" => CancelReason::Duplicate,
+            _ => CancelReason::Other(reason.to_string()),
+        }
+    }
+}
+
+impl Serialize for CancelReason {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for CancelReason {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let reason = String::deserialize(deserializer)?;
+        Ok(CancelReason::from(reason.as_str()))
+    }
+}
+
+/// Request body for cancelling an order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CancelOrderRequest {
+    pub reference_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<CancelReason>,
+}
+
+/// Result of cancelling an order
+///
+/// All fields are empty when the API confirms the cancellation without a body.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CancelResponse {
+    pub reference_id: Option<String>,
+    /// Order status after the cancellation
+    pub status: Option<String>,
+    /// Refunds the API started because the order was already paid
+    #[serde(default)]
+    pub refund_ids: Vec<String>,
+    pub message: Option<String>,
+}
+
+impl CancelResponse {
+    /// Returns the order status after the cancellation, if it is recognised
+    pub fn order_status(&self) -> Option<OrderStatus> {
+        self.status.as_deref().and_then(OrderStatus::parse)
+    }
+
+    /// Returns true if the cancellation started refunds of captured payments
+    pub fn triggered_refunds(&self) -> bool {
+        !self.refund_ids.is_empty()
+    }
+}
+
+/// Result of terminating an order or one of its payment terms
+///
+/// All fields are empty when the API confirms the termination without a body.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TerminateResponse {
+    pub reference_id: Option<String>,
+    /// Set when a single payment term was terminated
+    pub term_reference_id: Option<String>,
+    /// Order status after the termination
+    pub status: Option<String>,
+    /// Refunds the API started for payments already collected
+    #[serde(default)]
+    pub refund_ids: Vec<String>,
+    pub message: Option<String>,
+}
+
+impl TerminateResponse {
+    /// Returns the order status after the termination, if it is recognised
+    pub fn order_status(&self) -> Option<OrderStatus> {
+        self.status.as_deref().and_then(OrderStatus::parse)
+    }
+
+    /// Returns true if the termination started refunds of collected payments
+    pub fn triggered_refunds(&self) -> bool {
+        !self.refund_ids.is_empty()
+    }
+}
+
+/// Payment callback to simulate for an order, e.g. to complete payments in staging
+///
+/// # Example
+///
+/// ```rust
+/// use tapsilat::{ManualCallbackRequest, OrderStatus};
+///
+/// let request = ManualCallbackRequest::new("order-reference-id")
+///     .with_status(OrderStatus::Paid)
+///     .with_amount(150.0);
+/// assert_eq!(request.status, Some(OrderStatus::Paid));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManualCallbackRequest {
+    pub reference_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conversation_id: Option<String>,
+    /// Payment result to simulate; the API completes the payment when not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<OrderStatus>,
+    /// Amount paid; the API uses the order amount when not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<f64>,
+}
+
+impl ManualCallbackRequest {
+    pub fn new(reference_id: impl Into<String>) -> Self {
+        Self {
+            reference_id: reference_id.into(),
+            conversation_id: None,
+            status: None,
+            amount: None,
+        }
+    }
+
+    pub fn with_conversation_id(mut self, conversation_id: impl Into<String>) -> Self {
+        self.conversation_id = Some(conversation_id.into());
+        self
+    }
+
+    pub fn with_status(mut self, status: OrderStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn with_amount(mut self, amount: f64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+}
+
+/// Result of a simulated payment callback
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManualCallbackResponse {
+    pub reference_id: Option<String>,
+    pub conversation_id: Option<String>,
+    /// Order status after the callback was processed
+    pub status: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::types::common::deserialize_optional_amount"
+    )]
+    pub paid_amount: Option<f64>,
+    pub message: Option<String>,
+}
+
+impl ManualCallbackResponse {
+    /// Returns the order status after the callback, if it is recognised
+    pub fn order_status(&self) -> Option<OrderStatus> {
+        self.status.as_deref().and_then(OrderStatus::parse)
+    }
+}
+
+/// Processing state of a refund, which some acquirers settle asynchronously
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RefundStatus {
+    Pending,
+    Processing,
+    Completed,
+    Failed,
+    #[serde(other)]
+    Unknown,
+}
+
+impl RefundStatus {
+    /// Returns whether the refund will not change status anymore
+    pub fn is_final(&self) -> bool {
+        matches!(self, RefundStatus::Completed | RefundStatus::Failed)
+    }
+}
+
+/// A refund and its processing state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Refund {
+    pub refund_id: String,
+    pub reference_id: Option<String>,
+    #[serde(default, deserialize_with = "crate::types::common::deserialize_amount")]
+    pub amount: f64,
+    pub currency: Option<String>,
+    pub status: RefundStatus,
+    /// Acquirer's explanation when the refund failed
+    pub failure_reason: Option<String>,
+    /// Reason given when the refund was requested
+    #[serde(default)]
+    pub reason: Option<RefundReason>,
+    pub created_at: Option<String>,
+    pub completed_at: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -181,7 +879,7 @@ pub struct MetadataDTO {
     pub value: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct BasketItemDTO {
     pub category1: Option<String>,
     pub category2: Option<String>,
@@ -200,6 +898,63 @@ pub struct BasketItemDTO {
     pub quantity_unit: Option<String>,
     pub sub_merchant_key: Option<String>,
     pub sub_merchant_price: Option<String>,
+    /// VAT rate used by [`crate::tax`] helpers; not sent to the API
+    #[serde(skip)]
+    pub vat_rate: Option<VatRate>,
+}
+
+impl BasketItemDTO {
+    /// Returns the line total before discounts (`price` times quantity, defaulting to one)
+    pub fn line_total(&self) -> f64 {
+        let quantity = self
+            .quantity_float
+            .or(self.quantity.map(f64::from))
+            .unwrap_or(1.0);
+        from_minor_units(to_minor_units(self.price.unwrap_or(0.0) * quantity))
+    }
+
+    /// Returns the line total after any coupon discount
+    pub fn discounted_total(&self) -> f64 {
+        from_minor_units(
+            to_minor_units(self.line_total()) - to_minor_units(self.coupon_discount.unwrap_or(0.0)),
+        )
+    }
+
+    /// Applies a coupon code and discount to this item
+    ///
+    /// Fails without modifying the item if the discount is negative, has more than
+    /// two decimals or exceeds the line total.
+    pub fn apply_coupon(
+        &mut self,
+        code: impl Into<String>,
+        discount: f64,
+    ) -> crate::error::Result<()> {
+        if discount < 0.0 {
+            return Err(TapsilatError::ValidationError(
+                "Coupon discount cannot be negative".to_string(),
+            ));
+        }
+        if discount > 0.0 {
+            crate::modules::Validators::validate_amount(discount)?;
+        }
+        if to_minor_units(discount) > to_minor_units(self.line_total()) {
+            return Err(TapsilatError::ValidationError(format!(
+                "Coupon discount {:.2} exceeds the item total of {:.2}",
+                discount,
+                self.line_total()
+            )));
+        }
+
+        self.coupon = Some(code.into());
+        self.coupon_discount = Some(discount);
+        Ok(())
+    }
+
+    /// Applies a coupon, computing the discount from the item's line total
+    pub fn apply(&mut self, coupon: &Coupon) -> crate::error::Result<()> {
+        let discount = coupon.discount_for(self.line_total());
+        self.apply_coupon(coupon.code.clone(), discount)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -247,6 +1002,9 @@ pub struct CheckoutDesignDTO {
 pub struct OrderCardDTO {
     pub card_id: String,
     pub card_sequence: i32,
+    /// Amount charged to this card when splitting an order across several cards
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -288,6 +1046,40 @@ pub struct ShippingAddressDTO {
     pub zip_code: Option<String>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ManualPaymentMethod {
+    #[serde(rename = "cash")]
+    Cash,
+    #[serde(rename = "bank_transfer")]
+    BankTransfer,
+    #[serde(rename = "other")]
+    Other,
+}
+
+/// A payment collected outside Tapsilat, such as cash or an EFT
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManualPaymentRequest {
+    pub amount: f64,
+    /// Date the payment was received (`YYYY-MM-DD`)
+    pub payment_date: String,
+    pub method: ManualPaymentMethod,
+    /// Bank transfer reference, receipt number or similar
+    pub reference: Option<String>,
+    pub note: Option<String>,
+}
+
+/// Shipment details pushed after payment, e.g. to release escrowed marketplace funds
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ShippingUpdate {
+    pub tracking_code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub carrier: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracking_url: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubOrganizationDTO {
     pub acquirer: Option<String>,
@@ -326,6 +1118,17 @@ pub struct OrderPostAuthRequest {
     pub amount: f64,
     #[serde(rename = "reference_id")]
     pub reference_id: String,
+    /// Per-item breakdown of a partial capture; item amounts must sum to `amount`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<ItemCapture>>,
+}
+
+/// Amount captured for one basket item, e.g. for a single consignment of a marketplace order
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemCapture {
+    /// `id` of the basket item
+    pub basket_item_id: String,
+    pub amount: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -361,5 +1164,56 @@ pub struct OrderTermRefundRequest {
 }
 
 // Re-export Buyer from buyer.rs
+use crate::error::TapsilatError;
+use crate::tax::VatRate;
+use crate::types::basket::Basket;
 use crate::types::buyer::Buyer;
 pub use crate::types::buyer::CreateBuyerRequest;
+use crate::types::common::{from_minor_units, to_minor_units};
+use crate::types::coupon::Coupon;
+use crate::types::invoice::InvoiceDTO;
+use crate::types::lifecycle::OrderLifecycle;
+use crate::types::payment_term::PaymentTerm;
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Validators;
+
+    #[test]
+    fn test_card_split() {
+        let card = |sequence: i32, amount: Option<f64>| OrderCardDTO {
+            card_id: format!("card_{}", sequence),
+            card_sequence: sequence,
+            amount,
+        };
+
+        let json = serde_json::to_value(card(1, None)).unwrap();
+        assert!(json.get("amount").is_none());
+        assert_eq!(
+            serde_json::to_value(card(2, Some(200.0))).unwrap()["amount"],
+            200.0
+        );
+
+        assert!(Validators::validate_card_split(&[card(1, None)], 500.0).is_ok());
+        assert!(Validators::validate_card_split(
+            &[card(1, Some(300.0)), card(2, Some(200.0))],
+            500.0
+        )
+        .is_ok());
+        assert!(Validators::validate_card_split(
+            &[card(1, Some(300.0)), card(2, Some(150.0))],
+            500.0
+        )
+        .is_err());
+        assert!(
+            Validators::validate_card_split(&[card(1, Some(300.0)), card(2, None)], 500.0).is_err()
+        );
+        assert!(Validators::validate_card_split(
+            &[card(1, Some(250.0)), card(1, Some(250.0))],
+            500.0
+        )
+        .is_err());
+    }
+}
@@ -1,4 +1,6 @@
+use crate::masking::Redacted;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Payment {
@@ -10,9 +12,22 @@ pub struct Payment {
     pub customer_id: Option<String>,
     pub created_at: String,
     pub updated_at: String,
+    /// First six or eight digits of the card number
+    #[serde(default)]
+    pub card_bin: Option<String>,
+    #[serde(default)]
+    pub card_last_four: Option<String>,
+    /// Card scheme, e.g. `VISA` or `TROY`
+    #[serde(default)]
+    pub card_brand: Option<String>,
+    #[serde(default)]
+    pub installment_count: Option<u32>,
+    /// Acquirer or fraud check message for failed payments
+    #[serde(default)]
+    pub failure_reason: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PaymentStatus {
     #[serde(rename = "pending")]
     Pending,
@@ -26,6 +41,19 @@ pub enum PaymentStatus {
     Cancelled,
 }
 
+/// Filters for listing payments; unset fields are not sent
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PaymentListFilter {
+    pub status: Option<PaymentStatus>,
+    /// Only payments created on or after this date (`YYYY-MM-DD`)
+    pub start_date: Option<String>,
+    /// Only payments created before this date (`YYYY-MM-DD`)
+    pub end_date: Option<String>,
+    pub customer_id: Option<String>,
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreatePaymentRequest {
     pub amount: f64,
@@ -35,8 +63,113 @@ pub struct CreatePaymentRequest {
     pub callback_url: Option<String>,
 }
 
+/// Result of refunding a direct payment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentRefund {
+    pub refund_id: String,
+    pub payment_id: String,
+    pub amount: f64,
+    pub currency: Option<String>,
+    pub status: Option<String>,
+    pub created_at: Option<String>,
+}
+
+/// Result of capturing a previously authorized direct payment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentCapture {
+    pub payment_id: String,
+    pub captured_amount: f64,
+    pub currency: Option<String>,
+    pub status: PaymentStatus,
+    pub captured_at: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaymentResponse {
     pub payment: Payment,
     pub checkout_url: Option<String>,
 }
+
+/// Wallet payment token to charge against an order, as received from the device
+///
+/// Serialized as `{"apple_pay_token": ...}` or `{"google_pay_token": ...}`. Pass the
+/// token exactly as returned by PassKit or the Google Pay API. Tokens are left out of
+/// the `Debug` output.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WalletPaymentRequest {
+    ApplePayToken(String),
+    GooglePayToken(String),
+}
+
+impl WalletPaymentRequest {
+    /// Returns the raw wallet token
+    pub fn token(&self) -> &str {
+        match self {
+            WalletPaymentRequest::ApplePayToken(token)
+            | WalletPaymentRequest::GooglePayToken(token) => token,
+        }
+    }
+}
+
+impl fmt::Debug for WalletPaymentRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let wallet = match self {
+            WalletPaymentRequest::ApplePayToken(_) => "ApplePayToken",
+            WalletPaymentRequest::GooglePayToken(_) => "GooglePayToken",
+        };
+        f.debug_tuple(wallet)
+            .field(&Redacted::new(self.token()))
+            .finish()
+    }
+}
+
+/// Position of a charge within a stored-card agreement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecurringSequence {
+    /// First charge, made with the cardholder present, that sets up the agreement
+    Initial,
+    /// Later charge made under the agreement
+    Subsequent,
+}
+
+/// Stored-credential indicators issuers require for recurring and merchant-initiated charges
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecurringIndicator {
+    pub sequence: RecurringSequence,
+    /// Whether the merchant charges the card without the cardholder taking part (MIT)
+    pub merchant_initiated: bool,
+    /// Transaction ID of the initial charge, required for subsequent charges
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_transaction_reference: Option<String>,
+}
+
+impl RecurringIndicator {
+    /// Indicators for the customer-initiated charge that stores the card for recurring use
+    pub fn initial() -> Self {
+        Self {
+            sequence: RecurringSequence::Initial,
+            merchant_initiated: false,
+            original_transaction_reference: None,
+        }
+    }
+
+    /// Indicators for a merchant-initiated charge under an existing agreement
+    pub fn subsequent(original_transaction_reference: impl Into<String>) -> Self {
+        Self {
+            sequence: RecurringSequence::Subsequent,
+            merchant_initiated: true,
+            original_transaction_reference: Some(original_transaction_reference.into()),
+        }
+    }
+}
+
+/// Charge of a stored card against an order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CardPaymentRequest {
+    /// Token returned by card tokenization
+    pub card_token: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring: Option<RecurringIndicator>,
+}
@@ -0,0 +1,237 @@
+use crate::error::{Result, TapsilatError};
+use crate::types::{from_minor_units, to_minor_units, PaymentTermDTO};
+use chrono::{Days, Months, NaiveDate};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Spacing between consecutive payment term due dates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interval {
+    Weekly,
+    Monthly,
+    Quarterly,
+    /// A fixed number of days between terms
+    Days(u32),
+}
+
+impl Interval {
+    /// Returns the due date of the term at zero-based `index`
+    ///
+    /// Months are added from the start date rather than chained, so a schedule
+    /// starting on the 31st falls on the last day of shorter months without drifting.
+    fn due_date(self, start: NaiveDate, index: u32) -> Option<NaiveDate> {
+        match self {
+            Interval::Weekly => start.checked_add_days(Days::new(7 * index as u64)),
+            Interval::Monthly => start.checked_add_months(Months::new(index)),
+            Interval::Quarterly => start.checked_add_months(Months::new(3 * index)),
+            Interval::Days(days) => start.checked_add_days(Days::new(days as u64 * index as u64)),
+        }
+    }
+}
+
+/// Generates payment term schedules for `CreateOrderRequest::payment_terms`
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use tapsilat::{Interval, PaymentTermsBuilder};
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+/// let terms = PaymentTermsBuilder::equal_split(100.0, 3, start, Interval::Monthly)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(terms.len(), 3);
+/// assert_eq!(terms[0].amount, Some(33.34));
+/// assert_eq!(terms[1].due_date.as_deref(), Some("2024-02-29"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PaymentTermsBuilder {
+    total: f64,
+    count: u32,
+    start_date: NaiveDate,
+    interval: Interval,
+    required: bool,
+}
+
+impl PaymentTermsBuilder {
+    /// Splits `total` into `count` terms due every `interval` starting at `start_date`
+    ///
+    /// Amounts are rounded to two decimals; leftover minor units are spread one at a
+    /// time over the earliest terms so the schedule always sums to `total`.
+    pub fn equal_split(total: f64, count: u32, start_date: NaiveDate, interval: Interval) -> Self {
+        Self {
+            total,
+            count,
+            start_date,
+            interval,
+            required: true,
+        }
+    }
+
+    /// Sets whether each generated term must be paid (defaults to `true`)
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Builds the payment terms
+    pub fn build(self) -> Result<Vec<PaymentTermDTO>> {
+        if self.count == 0 {
+            return Err(TapsilatError::ValidationError(
+                "Payment term count must be greater than 0".to_string(),
+            ));
+        }
+        crate::modules::Validators::validate_amount(self.total)?;
+
+        let total = to_minor_units(self.total);
+        let count = self.count as i64;
+        let base = total / count;
+        let remainder = total % count;
+        if base == 0 {
+            return Err(TapsilatError::ValidationError(format!(
+                "Amount {:.2} is too small to split into {} terms",
+                self.total, self.count
+            )));
+        }
+
+        (0..self.count)
+            .map(|index| {
+                let units = base + i64::from((index as i64) < remainder);
+                let due_date = self
+                    .interval
+                    .due_date(self.start_date, index)
+                    .ok_or_else(|| {
+                        TapsilatError::ValidationError(
+                            "Payment term due date is out of range".to_string(),
+                        )
+                    })?;
+
+                Ok(PaymentTermDTO {
+                    amount: Some(from_minor_units(units)),
+                    data: None,
+                    due_date: Some(due_date.format("%Y-%m-%d").to_string()),
+                    paid_date: None,
+                    required: Some(self.required),
+                    status: None,
+                    term_reference_id: None,
+                    term_sequence: Some(index as i32 + 1),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Status of a single payment term
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaymentTermStatus {
+    Pending,
+    Paid,
+    Overdue,
+    Cancelled,
+    Refunded,
+    /// A status this SDK version does not know about, kept verbatim
+    Other(String),
+}
+
+impl PaymentTermStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            PaymentTermStatus::Pending => "pending",
+            PaymentTermStatus::Paid => "paid",
+            PaymentTermStatus::Overdue => "overdue",
+            PaymentTermStatus::Cancelled => "cancelled",
+            PaymentTermStatus::Refunded => "refunded",
+            PaymentTermStatus::Other(status) => status,
+        }
+    }
+}
+
+impl From<&str> for PaymentTermStatus {
+    fn from(status: &str) -> Self {
+        match status.to_ascii_lowercase().as_str() {
+            "pending" | "unpaid" | "waiting" => PaymentTermStatus::Pending,
+            "paid" | "completed" | "success" => PaymentTermStatus::Paid,
+            "overdue" | "expired" => PaymentTermStatus::Overdue,
+            "cancelled" | "canceled" | "terminated" => PaymentTermStatus::Cancelled,
+            "refunded" => PaymentTermStatus::Refunded,
+            _ => PaymentTermStatus::Other(status.to_string()),
+        }
+    }
+}
+
+impl Serialize for PaymentTermStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentTermStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let status = String::deserialize(deserializer)?;
+        Ok(PaymentTermStatus::from(status.as_str()))
+    }
+}
+
+/// A payment term as returned by the API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentTerm {
+    pub term_reference_id: Option<String>,
+    pub term_sequence: Option<i32>,
+    #[serde(
+        default,
+        deserialize_with = "crate::types::common::deserialize_optional_amount"
+    )]
+    pub amount: Option<f64>,
+    pub due_date: Option<String>,
+    pub paid_date: Option<String>,
+    pub required: Option<bool>,
+    pub status: Option<PaymentTermStatus>,
+    pub data: Option<String>,
+}
+
+impl PaymentTerm {
+    /// Returns true if the term has been paid
+    pub fn is_paid(&self) -> bool {
+        self.status == Some(PaymentTermStatus::Paid) || self.paid_date.is_some()
+    }
+
+    /// Returns true if the term still awaits payment
+    pub fn is_unpaid(&self) -> bool {
+        !self.is_paid()
+            && !matches!(
+                self.status,
+                Some(PaymentTermStatus::Cancelled) | Some(PaymentTermStatus::Refunded)
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Validators;
+
+    #[test]
+    fn test_equal_split_schedule() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        let terms = PaymentTermsBuilder::equal_split(100.0, 3, start, Interval::Monthly)
+            .build()
+            .unwrap();
+
+        let amounts: Vec<f64> = terms.iter().map(|t| t.amount.unwrap()).collect();
+        assert_eq!(amounts, vec![33.34, 33.33, 33.33]);
+        let due_dates: Vec<&str> = terms
+            .iter()
+            .map(|t| t.due_date.as_deref().unwrap())
+            .collect();
+        assert_eq!(due_dates, vec!["2024-01-31", "2024-02-29", "2024-03-31"]);
+        assert_eq!(terms[2].term_sequence, Some(3));
+        assert!(Validators::validate_payment_terms(&terms, 100.0).is_ok());
+
+        assert!(
+            PaymentTermsBuilder::equal_split(0.02, 3, start, Interval::Weekly)
+                .build()
+                .is_err()
+        );
+    }
+}
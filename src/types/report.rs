@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExportFormat {
+    #[serde(rename = "csv")]
+    Csv,
+    #[serde(rename = "json")]
+    Json,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderExportFilter {
+    pub buyer_id: Option<String>,
+    /// Inclusive lower bound on `created_at`, e.g. `2024-01-01`
+    pub start_date: Option<String>,
+    /// Exclusive upper bound on `created_at`, e.g. `2024-02-01`
+    pub end_date: Option<String>,
+    /// Page size used while walking the order list
+    pub per_page: u32,
+}
+
+impl Default for OrderExportFilter {
+    fn default() -> Self {
+        Self {
+            buyer_id: None,
+            start_date: None,
+            end_date: None,
+            per_page: 100,
+        }
+    }
+}
+
+/// Business event behind a ledger entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerEntryKind {
+    OrderPayment,
+    Commission,
+    Refund,
+    Chargeback,
+    Payout,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Side of the merchant account a ledger entry is booked on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerDirection {
+    /// Decreases the merchant balance
+    Debit,
+    /// Increases the merchant balance
+    Credit,
+    #[serde(other)]
+    Unknown,
+}
+
+/// A single booking on the merchant account statement
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    pub entry_id: String,
+    #[serde(rename = "type")]
+    pub kind: LedgerEntryKind,
+    pub direction: LedgerDirection,
+    /// Always positive; see [`direction`](Self::direction) for the sign
+    #[serde(default, deserialize_with = "crate::types::common::deserialize_amount")]
+    pub amount: f64,
+    pub currency: Option<String>,
+    /// Reference ID of the order the entry belongs to, if any
+    pub reference_id: Option<String>,
+    /// ID of the payout that settled or booked the entry, if any
+    pub payout_id: Option<String>,
+    pub description: Option<String>,
+    /// Merchant balance after this entry
+    #[serde(
+        default,
+        deserialize_with = "crate::types::common::deserialize_optional_amount"
+    )]
+    pub balance_after: Option<f64>,
+    pub created_at: Option<String>,
+}
+
+impl LedgerEntry {
+    /// Returns the amount as a balance change: positive for credits, negative for debits
+    ///
+    /// Returns `None` if the direction is not recognised, since its sign is unknown.
+    pub fn signed_amount(&self) -> Option<f64> {
+        match self.direction {
+            LedgerDirection::Credit => Some(self.amount),
+            LedgerDirection::Debit => Some(-self.amount),
+            LedgerDirection::Unknown => None,
+        }
+    }
+}
+
+/// A page of ledger entries
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LedgerPage {
+    pub entries: Vec<LedgerEntry>,
+    /// Cursor to pass to the next `ledger_entries` call
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
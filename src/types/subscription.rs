@@ -1,4 +1,53 @@
-use serde::{Deserialize, Serialize};
+use crate::error::{Result, TapsilatError};
+use crate::types::{from_minor_units, to_minor_units, RecurringIndicator};
+use chrono::{Datelike, Days, Months, NaiveDate};
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Billing period of a subscription, sent to the API unchanged as a number
+///
+/// The API's encoding of periods is not documented, so the SDK offers no named
+/// periods. Pass the value your integration already uses; earlier releases of the
+/// examples sent `1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SubscriptionPeriod(i32);
+
+impl SubscriptionPeriod {
+    pub fn new(value: i32) -> Self {
+        Self(value)
+    }
+
+    /// Returns the number sent to the API
+    pub fn value(self) -> i32 {
+        self.0
+    }
+}
+
+/// Number of billing cycles a subscription runs for; always at least one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(transparent)]
+pub struct SubscriptionCycle(i32);
+
+impl SubscriptionCycle {
+    pub fn new(count: i32) -> Result<Self> {
+        if count < 1 {
+            return Err(TapsilatError::ValidationError(
+                "Subscription cycle count must be at least 1".to_string(),
+            ));
+        }
+        Ok(Self(count))
+    }
+
+    pub fn count(self) -> i32 {
+        self.0
+    }
+}
+
+impl<'de> Deserialize<'de> for SubscriptionCycle {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        SubscriptionCycle::new(i32::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubscriptionBilling {
@@ -44,6 +93,88 @@ pub struct SubscriptionOrder {
     pub status: Option<String>,
 }
 
+impl SubscriptionOrder {
+    /// Returns true if this renewal's payment failed
+    pub fn is_failed(&self) -> bool {
+        self.status.as_deref().is_some_and(|status| {
+            let status = status.to_ascii_lowercase();
+            status.contains("fail") || status == "error" || status == "declined"
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubscriptionRetryPaymentResponse {
+    pub code: Option<i32>,
+    pub message: Option<String>,
+    #[serde(rename = "order_reference_id")]
+    pub order_reference_id: Option<String>,
+    #[serde(rename = "payment_url")]
+    pub payment_url: Option<String>,
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubscriptionUpdateCardResponse {
+    pub code: Option<i32>,
+    pub message: Option<String>,
+}
+
+/// Lifecycle state of a subscription
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionStatus {
+    Active,
+    /// The last renewal failed and has not been retried successfully yet
+    PastDue,
+    #[serde(alias = "canceled")]
+    Cancelled,
+    Paused,
+    /// In a free trial before the first charge
+    Trialing,
+}
+
+impl SubscriptionStatus {
+    /// Maps a `payment_status` value to a status, if it is recognised
+    pub fn from_payment_status(payment_status: &str) -> Option<Self> {
+        match payment_status.trim().to_ascii_lowercase().as_str() {
+            "active" | "paid" | "success" | "succeeded" => Some(SubscriptionStatus::Active),
+            "past_due" | "pastdue" | "overdue" | "unpaid" | "failed" | "payment_failed" => {
+                Some(SubscriptionStatus::PastDue)
+            }
+            "cancelled" | "canceled" | "ended" | "expired" => Some(SubscriptionStatus::Cancelled),
+            "paused" | "suspended" => Some(SubscriptionStatus::Paused),
+            "trial" | "trialing" | "in_trial" => Some(SubscriptionStatus::Trialing),
+            _ => None,
+        }
+    }
+
+    /// Derives the status from the API's `payment_status` and `is_active` fields
+    ///
+    /// A cancelled or paused `payment_status` wins; otherwise an inactive subscription
+    /// is cancelled. Returns `None` if neither field settles the status.
+    pub fn from_fields(payment_status: Option<&str>, is_active: Option<bool>) -> Option<Self> {
+        let parsed = payment_status.and_then(Self::from_payment_status);
+        match (parsed, is_active) {
+            (Some(status @ (SubscriptionStatus::Cancelled | SubscriptionStatus::Paused)), _) => {
+                Some(status)
+            }
+            (_, Some(false)) => Some(SubscriptionStatus::Cancelled),
+            (Some(status), _) => Some(status),
+            (None, Some(true)) => Some(SubscriptionStatus::Active),
+            (None, None) => None,
+        }
+    }
+
+    /// Returns `true` if the subscription will still be charged
+    pub fn is_billable(self) -> bool {
+        matches!(
+            self,
+            SubscriptionStatus::Active | SubscriptionStatus::PastDue | SubscriptionStatus::Trialing
+        )
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubscriptionDetail {
     pub amount: Option<String>,
@@ -59,9 +190,18 @@ pub struct SubscriptionDetail {
     pub payment_date: Option<i32>,
     #[serde(rename = "payment_status")]
     pub payment_status: Option<String>,
-    pub period: Option<i32>,
+    pub period: Option<SubscriptionPeriod>,
     pub title: Option<String>,
     pub user: Option<SubscriptionUser>,
+    pub trial_days: Option<i32>,
+    pub trial_end_date: Option<String>,
+}
+
+impl SubscriptionDetail {
+    /// Returns the subscription's status, if the API fields settle it
+    pub fn status(&self) -> Option<SubscriptionStatus> {
+        SubscriptionStatus::from_fields(self.payment_status.as_deref(), self.is_active)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -76,12 +216,19 @@ pub struct SubscriptionListItem {
     pub payment_date: Option<i32>,
     #[serde(rename = "payment_status")]
     pub payment_status: Option<String>,
-    pub period: Option<i32>,
+    pub period: Option<SubscriptionPeriod>,
     #[serde(rename = "reference_id")]
     pub reference_id: Option<String>,
     pub title: Option<String>,
 }
 
+impl SubscriptionListItem {
+    /// Returns the subscription's status, if the API fields settle it
+    pub fn status(&self) -> Option<SubscriptionStatus> {
+        SubscriptionStatus::from_fields(self.payment_status.as_deref(), self.is_active)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubscriptionCreateRequest {
     pub amount: Option<f64>,
@@ -89,20 +236,68 @@ pub struct SubscriptionCreateRequest {
     #[serde(rename = "card_id")]
     pub card_id: Option<String>,
     pub currency: Option<String>,
-    pub cycle: Option<i32>,
+    pub cycle: Option<SubscriptionCycle>,
     #[serde(rename = "external_reference_id")]
     pub external_reference_id: Option<String>,
     #[serde(rename = "failure_url")]
     pub failure_url: Option<String>,
     #[serde(rename = "payment_date")]
     pub payment_date: Option<i32>,
-    pub period: Option<i32>,
+    pub period: Option<SubscriptionPeriod>,
     #[serde(rename = "success_url")]
     pub success_url: Option<String>,
     pub title: Option<String>,
     pub user: Option<SubscriptionUser>,
     #[serde(rename = "price_option")]
     pub price_option: Option<SubscriptionPriceOption>,
+    /// Length of a free trial before the first charge
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_days: Option<i32>,
+    /// Last day of a free trial (`YYYY-MM-DD`); the first charge follows it, see
+    /// [`first_charge_date`](Self::first_charge_date)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_end_date: Option<String>,
+    /// Stored-credential indicators for the first charge, normally [`RecurringIndicator::initial`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring: Option<RecurringIndicator>,
+}
+
+impl SubscriptionCreateRequest {
+    /// Returns the date of the first charge after the free trial, counting from `today`
+    ///
+    /// A trial of `trial_days` starts on `today`; one with `trial_end_date` runs through
+    /// that day. The first charge falls on the first `payment_date` day of the month after
+    /// the trial's last day, or on the month's last day in months that are shorter.
+    /// Without `payment_date` the first charge is the day after the trial.
+    ///
+    /// Returns `None` if no trial is set or `trial_end_date` or `payment_date` is invalid.
+    pub fn first_charge_date(&self, today: NaiveDate) -> Option<NaiveDate> {
+        let first_paid_day = match (self.trial_days, &self.trial_end_date) {
+            (Some(days), _) => today.checked_add_days(Days::new(u64::try_from(days).ok()?))?,
+            (None, Some(end_date)) => NaiveDate::parse_from_str(end_date, "%Y-%m-%d")
+                .ok()?
+                .succ_opt()?,
+            (None, None) => return None,
+        };
+        let Some(payment_date) = self.payment_date else {
+            return Some(first_paid_day);
+        };
+        let day = u32::try_from(payment_date)
+            .ok()
+            .filter(|day| (1..=31).contains(day))?;
+
+        let charge_in = |year: i32, month: u32| {
+            let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+            let last = first.checked_add_months(Months::new(1))?.pred_opt()?;
+            first.with_day(day.min(last.day()))
+        };
+        let charge = charge_in(first_paid_day.year(), first_paid_day.month())?;
+        if charge >= first_paid_day {
+            return Some(charge);
+        }
+        let next_month = first_paid_day.checked_add_months(Months::new(1))?;
+        charge_in(next_month.year(), next_month.month())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -121,6 +316,168 @@ pub struct SubscriptionCreateResponse {
     pub reference_id: Option<String>,
 }
 
+/// The next charge of a subscription as the API would bill it today
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpcomingInvoice {
+    pub reference_id: Option<String>,
+    #[serde(default, deserialize_with = "crate::types::common::deserialize_amount")]
+    pub amount: f64,
+    pub currency: Option<String>,
+    /// First day of the billing period the charge closes
+    pub period_start: NaiveDate,
+    /// Day the next charge is taken
+    pub charge_date: NaiveDate,
+}
+
+impl UpcomingInvoice {
+    /// Prorates a switch to `new_amount` on `change_date` against this invoice
+    pub fn prorate(&self, new_amount: f64, change_date: NaiveDate) -> Result<ProrationQuote> {
+        ProrationQuote::calculate(
+            self.amount,
+            new_amount,
+            self.period_start,
+            self.charge_date,
+            change_date,
+        )
+    }
+}
+
+/// Charge resulting from a mid-cycle amount change
+///
+/// The unused days of the current amount are credited and the same days are charged
+/// at the new amount; the difference is settled with the next charge.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProrationQuote {
+    /// Credit for the unused part of the period at the current amount
+    pub unused_credit: f64,
+    /// Charge for the rest of the period at the new amount
+    pub prorated_charge: f64,
+    /// Amount taken on `next_charge_date`, never negative
+    pub next_charge_amount: f64,
+    pub next_charge_date: NaiveDate,
+    /// Credit left over when a downgrade exceeds the next charge
+    pub remaining_credit: f64,
+}
+
+impl ProrationQuote {
+    /// Prorates a change from `current_amount` to `new_amount` on `change_date`
+    ///
+    /// Amounts are rounded to kuruş per component, so the quote matches what the
+    /// customer is shown line by line.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TapsilatError::ValidationError`] if an amount is negative or
+    /// `change_date` is not within `period_start..charge_date`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use tapsilat::ProrationQuote;
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    /// let next = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+    /// let change = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
+    ///
+    /// let quote = ProrationQuote::calculate(100.0, 160.0, start, next, change).unwrap();
+    /// assert_eq!(quote.unused_credit, 50.0);
+    /// assert_eq!(quote.prorated_charge, 80.0);
+    /// assert_eq!(quote.next_charge_amount, 190.0);
+    /// ```
+    pub fn calculate(
+        current_amount: f64,
+        new_amount: f64,
+        period_start: NaiveDate,
+        charge_date: NaiveDate,
+        change_date: NaiveDate,
+    ) -> Result<Self> {
+        if current_amount < 0.0 || new_amount < 0.0 {
+            return Err(TapsilatError::ValidationError(
+                "Subscription amounts cannot be negative".to_string(),
+            ));
+        }
+        if change_date < period_start || change_date >= charge_date {
+            return Err(TapsilatError::ValidationError(format!(
+                "Change date {} is outside the billing period {} to {}",
+                change_date, period_start, charge_date
+            )));
+        }
+
+        let period_days = (charge_date - period_start).num_days() as f64;
+        let remaining = (charge_date - change_date).num_days() as f64 / period_days;
+        let prorate = |amount: f64| (to_minor_units(amount) as f64 * remaining).round() as i64;
+
+        let credit = prorate(current_amount);
+        let charge = prorate(new_amount);
+        let next = to_minor_units(new_amount) + charge - credit;
+
+        Ok(Self {
+            unused_credit: from_minor_units(credit),
+            prorated_charge: from_minor_units(charge),
+            next_charge_amount: from_minor_units(next.max(0)),
+            next_charge_date: charge_date,
+            remaining_credit: from_minor_units((-next).max(0)),
+        })
+    }
+}
+
+/// Outcome of one request in a subscription batch
+#[derive(Debug)]
+pub enum SubscriptionBatchOutcome {
+    Created(SubscriptionCreateResponse),
+    /// The subscription already exists and was not created again
+    Duplicate {
+        /// Index of the earlier entry with the same external reference ID, or `None`
+        /// if the API reported the existing subscription
+        first_index: Option<usize>,
+    },
+    Failed(TapsilatError),
+}
+
+/// A request of a subscription batch together with its outcome
+#[derive(Debug)]
+pub struct SubscriptionBatchEntry {
+    /// Position of the request in the batch
+    pub index: usize,
+    pub external_reference_id: Option<String>,
+    pub outcome: SubscriptionBatchOutcome,
+}
+
+/// Per-request results of a subscription batch, in request order
+#[derive(Debug)]
+pub struct SubscriptionBatchReport {
+    pub entries: Vec<SubscriptionBatchEntry>,
+}
+
+impl SubscriptionBatchReport {
+    /// Returns the entries whose subscription was created
+    pub fn created(&self) -> impl Iterator<Item = &SubscriptionBatchEntry> {
+        self.entries
+            .iter()
+            .filter(|e| matches!(e.outcome, SubscriptionBatchOutcome::Created(_)))
+    }
+
+    /// Returns the entries skipped as duplicates
+    pub fn duplicates(&self) -> impl Iterator<Item = &SubscriptionBatchEntry> {
+        self.entries
+            .iter()
+            .filter(|e| matches!(e.outcome, SubscriptionBatchOutcome::Duplicate { .. }))
+    }
+
+    /// Returns the entries that failed and may be retried
+    pub fn failed(&self) -> impl Iterator<Item = &SubscriptionBatchEntry> {
+        self.entries
+            .iter()
+            .filter(|e| matches!(e.outcome, SubscriptionBatchOutcome::Failed(_)))
+    }
+
+    /// Returns `true` if no entry failed
+    pub fn is_complete(&self) -> bool {
+        self.failed().next().is_none()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubscriptionGetRequest {
     #[serde(rename = "external_reference_id")]
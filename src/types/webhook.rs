@@ -1,5 +1,9 @@
+use crate::error::TapsilatError;
+use crate::secret::SecretString;
+use crate::types::SubscriptionStatus;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WebhookEvent {
@@ -7,9 +11,63 @@ pub struct WebhookEvent {
     pub data: WebhookData,
     pub timestamp: String,
     pub signature: Option<String>,
+    /// How the event was verified; set by
+    /// [`WebhookModule::verify_and_parse`](crate::WebhookModule::verify_and_parse)
+    #[serde(skip)]
+    pub verification: Option<WebhookVerification>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl WebhookEvent {
+    /// Returns the typed payload of a `subscription.*` event
+    ///
+    /// Returns `None` for other event types or if the event names no subscription.
+    pub fn subscription(&self) -> Option<SubscriptionWebhookPayload> {
+        if !self.event_type.is_subscription_event() {
+            return None;
+        }
+        Some(SubscriptionWebhookPayload {
+            subscription_id: self.data.subscription_id.clone()?,
+            status: self
+                .data
+                .status
+                .as_deref()
+                .and_then(SubscriptionStatus::from_payment_status),
+            order_id: self.data.order_id.clone(),
+            amount: self.data.amount,
+            currency: self.data.currency.clone(),
+            failure_reason: self.data.failure_reason.clone(),
+            next_retry_at: self.data.next_retry_at.clone(),
+        })
+    }
+}
+
+/// Payload of a subscription lifecycle event
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubscriptionWebhookPayload {
+    /// Reference ID of the subscription
+    pub subscription_id: String,
+    pub status: Option<SubscriptionStatus>,
+    /// Renewal order the event is about, if any
+    pub order_id: Option<String>,
+    pub amount: Option<f64>,
+    pub currency: Option<String>,
+    /// Why the renewal payment failed, for `subscription.payment_failed`
+    pub failure_reason: Option<String>,
+    /// When the failed renewal is retried next (RFC 3339), if a retry is scheduled
+    pub next_retry_at: Option<String>,
+}
+
+/// Outcome of a successful webhook verification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WebhookVerification {
+    /// Seconds between the event timestamp and the time of verification; negative
+    /// when the timestamp lies in the future. `None` if the timestamp is unparsable.
+    pub timestamp_skew_seconds: Option<i64>,
+    /// Secret the signature matched: 0 for `secret`, 1 and up for `previous_secrets`
+    pub matched_secret: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum WebhookEventType {
     #[serde(rename = "order.completed")]
     OrderCompleted,
@@ -27,6 +85,30 @@ pub enum WebhookEventType {
     InstallmentCompleted,
     #[serde(rename = "installment.failed")]
     InstallmentFailed,
+    #[serde(rename = "subscription.created")]
+    SubscriptionCreated,
+    #[serde(rename = "subscription.renewed")]
+    SubscriptionRenewed,
+    #[serde(rename = "subscription.payment_failed")]
+    SubscriptionPaymentFailed,
+    #[serde(rename = "subscription.cancelled")]
+    SubscriptionCancelled,
+    /// An event type this version of the SDK does not know yet
+    #[serde(other)]
+    Unknown,
+}
+
+impl WebhookEventType {
+    /// Returns `true` for the `subscription.*` lifecycle events
+    pub fn is_subscription_event(self) -> bool {
+        matches!(
+            self,
+            WebhookEventType::SubscriptionCreated
+                | WebhookEventType::SubscriptionRenewed
+                | WebhookEventType::SubscriptionPaymentFailed
+                | WebhookEventType::SubscriptionCancelled
+        )
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,9 +116,14 @@ pub struct WebhookData {
     pub order_id: Option<String>,
     pub payment_id: Option<String>,
     pub installment_id: Option<String>,
+    /// Reference ID of the subscription, for `subscription.*` events
+    pub subscription_id: Option<String>,
     pub amount: Option<f64>,
     pub currency: Option<String>,
     pub status: Option<String>,
+    pub failure_reason: Option<String>,
+    /// Scheduled retry of a failed subscription renewal (RFC 3339)
+    pub next_retry_at: Option<String>,
     pub metadata: Option<HashMap<String, serde_json::Value>>,
 }
 
@@ -48,6 +135,93 @@ pub struct WebhookVerificationResult {
 
 #[derive(Debug, Clone)]
 pub struct WebhookVerificationConfig {
-    pub secret: String,
+    pub secret: SecretString,
     pub tolerance_seconds: Option<u64>, // For timestamp validation
+    /// Older secrets still accepted while a secret rotation is in progress
+    pub previous_secrets: Vec<SecretString>,
+}
+
+impl WebhookVerificationConfig {
+    /// Also accepts signatures made with `secret`, e.g. the one being rotated out
+    pub fn with_previous_secret(mut self, secret: impl Into<String>) -> Self {
+        self.previous_secrets.push(SecretString::new(secret));
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WebhookDeliveryStatus {
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "succeeded", alias = "success", alias = "delivered")]
+    Succeeded,
+    #[serde(rename = "failed")]
+    Failed,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Filters for listing webhook deliveries; unset fields are not sent
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WebhookDeliveryFilter {
+    pub status: Option<WebhookDeliveryStatus>,
+    pub event_type: Option<String>,
+    /// Only deliveries created at or after this RFC 3339 timestamp
+    pub since: Option<String>,
+    /// Only deliveries created before this RFC 3339 timestamp
+    pub until: Option<String>,
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+}
+
+/// A single attempt to deliver a webhook to the merchant endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDeliveryAttempt {
+    pub attempted_at: Option<String>,
+    pub response_code: Option<u16>,
+    pub duration_ms: Option<u64>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDelivery {
+    pub id: String,
+    pub event_type: Option<String>,
+    pub url: Option<String>,
+    pub status: WebhookDeliveryStatus,
+    pub response_code: Option<u16>,
+    #[serde(default)]
+    pub attempts: Vec<WebhookDeliveryAttempt>,
+    pub created_at: Option<String>,
+    pub last_attempt_at: Option<String>,
+    pub next_retry_at: Option<String>,
+}
+
+/// A page of events returned by the polling API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventPage {
+    pub events: Vec<WebhookEvent>,
+    /// Cursor to pass to the next `list_events` call; unchanged when no events were returned
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
+
+/// A failed request inside `EventModule::poll_loop`, with the cursor to resume from
+#[derive(Debug)]
+pub struct EventPollError {
+    pub error: TapsilatError,
+    /// Cursor after the last page whose events were all handled
+    pub cursor: Option<String>,
+}
+
+impl fmt::Display for EventPollError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Event polling failed: {}", self.error)
+    }
+}
+
+impl std::error::Error for EventPollError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
 }
@@ -0,0 +1,160 @@
+//! Fetch-based client for WebAssembly targets, enabled with the `wasm` feature.
+//!
+//! [`FetchClient`] sends requests through the JavaScript `fetch` API, so it runs in
+//! browsers, Cloudflare Workers and other `wasm32-unknown-unknown` hosts where the
+//! blocking `TapsilatClient` transport is unavailable. Build for those targets
+//! with `default-features = false, features = ["wasm"]`.
+//!
+//! Only read-only order lookups are provided. Anything that needs the API key should
+//! run in a Worker or on a server; never ship the key to a browser.
+
+use crate::config::Config;
+use crate::error::{Result, TapsilatError};
+use crate::types::common::{decode_null, unwrap_envelope};
+use crate::types::{CheckoutToken, Order};
+use gloo_net::http::{Request, RequestBuilder};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// Asynchronous Tapsilat client backed by the `fetch` API
+///
+/// Honours the base URL, default headers, trace header and response size limit of
+/// the [`Config`]. Fetch has no built-in timeout, so `Config::timeout` is not applied.
+///
+/// A response whose `Content-Length` exceeds the size limit is rejected before its
+/// body is read. Without that header fetch buffers the whole body, so an oversized
+/// body is only rejected after it has been downloaded.
+#[derive(Debug, Clone)]
+pub struct FetchClient {
+    config: Config,
+}
+
+impl FetchClient {
+    pub fn new(config: Config) -> Result<Self> {
+        config.validate()?;
+        Ok(Self { config })
+    }
+
+    /// Returns the configuration this client was built with.
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Retrieves an order by reference ID
+    pub async fn get_order(&self, reference_id: &str) -> Result<Order> {
+        let endpoint = format!("order/{}", reference_id);
+        self.get(&endpoint, "order response").await
+    }
+
+    /// Gets the raw status of an order
+    pub async fn get_order_status(&self, reference_id: &str) -> Result<Value> {
+        let endpoint = format!("order/{}/status", reference_id);
+        self.get(&endpoint, "order status response").await
+    }
+
+    /// Gets the checkout URL of an order
+    pub async fn get_checkout_url(&self, reference_id: &str) -> Result<String> {
+        let order = self.get_order(reference_id).await?;
+        order
+            .checkout_url
+            .ok_or_else(|| TapsilatError::InvalidResponse("Checkout URL not found".to_string()))
+    }
+
+    /// Gets a token for embedded (iframe) checkout of an order
+    pub async fn get_checkout_token(&self, reference_id: &str) -> Result<CheckoutToken> {
+        let endpoint = format!("order/{}/checkout-token", reference_id);
+        self.get(&endpoint, "checkout token response").await
+    }
+
+    async fn get<T: DeserializeOwned>(&self, endpoint: &str, context: &str) -> Result<T> {
+        let url = format!(
+            "{}/{}",
+            self.config.base_url.trim_end_matches('/'),
+            endpoint.trim_start_matches('/')
+        );
+
+        let response = self
+            .apply_headers(Request::get(&url))
+            .send()
+            .await
+            .map_err(|e| TapsilatError::ConnectionFailed {
+                url: url.clone(),
+                message: e.to_string(),
+            })?;
+
+        let status_code = response.status();
+        let trace_id = response.headers().get(&self.config.trace_header);
+        let content_type = response.headers().get("Content-Type");
+        let limit = self.config.max_response_size;
+        let too_large = || {
+            TapsilatError::InvalidResponse(format!(
+                "Response body exceeds the {} byte limit",
+                limit
+            ))
+        };
+
+        if response
+            .headers()
+            .get("Content-Length")
+            .and_then(|length| length.trim().parse::<u64>().ok())
+            .is_some_and(|length| length > limit)
+        {
+            return Err(too_large());
+        }
+
+        let body_text = response.text().await.map_err(|e| {
+            TapsilatError::InvalidResponse(format!("Failed to read response body: {}", e))
+        })?;
+        if body_text.len() as u64 > limit {
+            return Err(too_large());
+        }
+        if let Some(error) =
+            TapsilatError::unexpected_content_type(status_code, content_type.as_deref(), &body_text)
+        {
+            return Err(error);
+        }
+
+        if status_code >= 400 {
+            return Err(TapsilatError::from_status(
+                status_code,
+                &body_text,
+                trace_id,
+            ));
+        }
+        if body_text.trim().is_empty() {
+            return decode_null(context);
+        }
+
+        let value: Value = serde_json::from_str(&body_text)?;
+        match unwrap_envelope(value, context, trace_id)? {
+            Value::Null => decode_null(context),
+            data => serde_json::from_value(data).map_err(|e| {
+                TapsilatError::InvalidResponse(format!("Failed to parse {}: {}", context, e))
+            }),
+        }
+    }
+
+    fn apply_headers(&self, request: RequestBuilder) -> RequestBuilder {
+        let mut request = request
+            .header(
+                "Authorization",
+                &format!("Bearer {}", self.config.api_key.expose_secret()),
+            )
+            .header("Content-Type", "application/json");
+
+        for (name, value) in &self.config.default_headers {
+            request = request.header(name, value);
+        }
+
+        if let Some(trace_id) = self
+            .config
+            .trace_id_provider
+            .as_ref()
+            .and_then(|provider| provider.trace_id())
+        {
+            request = request.header(&self.config.trace_header, &trace_id);
+        }
+
+        request
+    }
+}
@@ -1,4 +1,4 @@
-use tapsilat::{Config, CreateOrderRequest, TapsilatClient};
+use tapsilat::{Config, CreateOrderRequest, TapsilatClient, ThreeDSPreference};
 
 #[test]
 fn test_client_creation() {
@@ -13,6 +13,68 @@ fn test_config_validation() {
 
     let empty_config = Config::new("");
     assert!(empty_config.validate().is_err());
+
+    let reserved_header = Config::new("test-key").with_default_header("authorization", "x");
+    assert!(reserved_header.validate().is_err());
+}
+
+#[test]
+fn test_config_debug_redacts_api_key() {
+    let config = Config::new("sk_live_1234567890abcdef");
+    let debug = format!("{:?}", config);
+    assert!(!debug.contains("sk_live_1234567890abcdef"));
+    assert!(debug.contains("sk_l...cdef"));
+    assert_eq!(config.api_key.expose_secret(), "sk_live_1234567890abcdef");
+}
+
+#[test]
+fn test_client_certificate_config() {
+    let config = Config::new("test-key").with_client_certificate(
+        "-----BEGIN CERTIFICATE-----\nbm90IGEgY2VydA==\n-----END CERTIFICATE-----\n",
+        "secret-key-material",
+    );
+    assert!(!format!("{:?}", config).contains("secret-key-material"));
+    assert!(matches!(
+        TapsilatClient::new(config),
+        Err(tapsilat::TapsilatError::ConfigError(_))
+    ));
+
+    let empty = Config::new("test-key").with_client_certificate(Vec::new(), Vec::new());
+    assert!(empty.validate().is_err());
+}
+
+#[test]
+fn test_config_from_file_profiles() {
+    let path = std::env::temp_dir().join(format!("tapsilat-{}.toml", std::process::id()));
+    std::fs::write(
+        &path,
+        r#"
+[default]
+api_key = "default-key"
+timeout = 45
+
+[sandbox]
+base_url = "https://sandbox.example.com/api/v1"
+max_retries = 2
+"#,
+    )
+    .unwrap();
+
+    let sandbox = Config::from_file_profile(&path, "sandbox");
+    let missing = Config::from_file_profile(&path, "production");
+    std::fs::remove_file(&path).unwrap();
+
+    // TAPSILAT_* variables would override the file
+    if std::env::var_os("TAPSILAT_BASE_URL").is_none() {
+        let sandbox = sandbox.unwrap();
+        assert_eq!(sandbox.base_url, "https://sandbox.example.com/api/v1");
+        assert_eq!(sandbox.timeout, 45);
+        assert_eq!(sandbox.max_retries, 2);
+    }
+    assert!(matches!(
+        missing,
+        Err(tapsilat::TapsilatError::ConfigError(_))
+    ));
 }
 
 #[test]
@@ -40,6 +102,7 @@ fn test_order_creation_request() {
             quantity_unit: None,
             sub_merchant_key: None,
             sub_merchant_price: None,
+            vat_rate: None,
         }]),
         buyer: tapsilat::types::CreateBuyerRequest {
             name: "John".to_string(),
@@ -52,6 +115,7 @@ fn test_order_creation_request() {
             city: None,
             country: None,
             zip_code: None,
+            citizenship: None,
         },
         metadata: None,
         billing_address: None,
@@ -74,10 +138,58 @@ fn test_order_creation_request() {
         sub_organization: None,
         submerchants: None,
         tax_amount: None,
-        three_d_force: None,
+        three_ds: ThreeDSPreference::Auto,
         consents: None,
+        checkout_expires_in: None,
+        invoice: None,
     };
 
+    // Auto leaves 3DS to the account settings
+    let serialized = serde_json::to_value(&request).unwrap();
+    assert!(serialized["three_d_force"].is_null());
+    assert!(serialized.get("checkout_expires_in").is_none());
+    let mut forced = request.clone();
+    forced.three_ds = ThreeDSPreference::Force;
+    assert_eq!(
+        serde_json::to_value(&forced).unwrap()["three_d_force"],
+        true
+    );
+
+    // Forcing 3DS on a non-3DS payment mode is rejected before any request is sent
+    forced.payment_mode = Some("NON_3D".to_string());
+    let client = TapsilatClient::from_api_key("test-api-key").unwrap();
+    assert!(matches!(
+        client.create_order(forced),
+        Err(tapsilat::TapsilatError::ValidationError(_))
+    ));
+
+    // Buyer IPs are taken from the connection address, and malformed ones are rejected
+    let remote: std::net::SocketAddr = "[::ffff:203.0.113.7]:51234".parse().unwrap();
+    let buyer = request.buyer.clone().with_remote_addr(remote);
+    assert_eq!(buyer.ip.as_deref(), Some("203.0.113.7"));
+    let mut bad_ip = request.clone();
+    bad_ip.buyer.ip = Some("203.0.113".to_string());
+    assert!(matches!(
+        client.create_order(bad_ip),
+        Err(tapsilat::TapsilatError::ValidationError(_))
+    ));
+
+    // Foreign buyer numbers are rejected in Turkish GSM mode
+    let mut tourist = request.clone();
+    tourist.buyer.gsm_number = Some("+44 7911 123456".to_string());
+    let strict = TapsilatClient::new(
+        Config::new("test-api-key").with_phone_validation(tapsilat::PhoneValidation::TurkishGsm),
+    )
+    .unwrap();
+    assert!(matches!(
+        strict.create_order(tourist.clone()),
+        Err(tapsilat::TapsilatError::ValidationError(_))
+    ));
+    assert!(tapsilat::Validators::validate_phone_e164(
+        tourist.buyer.gsm_number.as_deref().unwrap()
+    )
+    .is_ok());
+
     // Should be valid
     assert_eq!(request.amount, 100.0);
     assert_eq!(request.currency, "TRY".to_string());
@@ -110,3 +222,648 @@ fn test_validators() {
     assert!(Validators::validate_installments(0).is_err());
     assert!(Validators::validate_installments(13).is_err());
 }
+
+#[test]
+fn test_order_metadata_accessors() {
+    use tapsilat::{Metadata, MetadataDTO, Order};
+
+    let order = Order {
+        metadata: Some(
+            Metadata::new()
+                .with("tier", "gold")
+                .with("points", 42)
+                .into(),
+        ),
+        ..Default::default()
+    };
+
+    assert_eq!(order.metadata_value("points"), Some("42"));
+    assert_eq!(order.metadata_value("missing"), None);
+    assert_eq!(order.metadata_map().len(), 2);
+
+    let duplicated = Order {
+        metadata: Some(vec![
+            MetadataDTO::new("tier", "silver"),
+            MetadataDTO::new("tier", "gold"),
+        ]),
+        ..Default::default()
+    };
+    assert_eq!(duplicated.metadata_value("tier"), Some("gold"));
+    assert_eq!(duplicated.metadata_map()["tier"], "gold");
+
+    let round_trip: Metadata = order.metadata.clone().unwrap().into();
+    assert_eq!(round_trip.get("tier"), Some("gold"));
+    assert_eq!(MetadataDTO::new("gift", true).value, "true");
+}
+
+#[test]
+fn test_subscription_period_and_cycle() {
+    use tapsilat::{SubscriptionCycle, SubscriptionDetail, SubscriptionPeriod};
+
+    // Periods go over the wire exactly as given
+    assert_eq!(serde_json::to_value(SubscriptionPeriod::new(1)).unwrap(), 1);
+    let detail: SubscriptionDetail =
+        serde_json::from_value(serde_json::json!({ "period": 30 })).unwrap();
+    assert_eq!(detail.period.map(SubscriptionPeriod::value), Some(30));
+
+    assert!(SubscriptionCycle::new(0).is_err());
+    assert_eq!(
+        serde_json::to_value(SubscriptionCycle::new(12).unwrap()).unwrap(),
+        12
+    );
+}
+
+#[test]
+fn test_subscription_trial_validation() {
+    use tapsilat::{SubscriptionCreateRequest, SubscriptionPeriod};
+
+    let request = |trial_days: Option<i32>, trial_end_date: Option<&str>, payment_date| {
+        SubscriptionCreateRequest {
+            amount: Some(99.0),
+            billing: None,
+            card_id: None,
+            currency: Some("TRY".to_string()),
+            cycle: None,
+            external_reference_id: None,
+            failure_url: None,
+            payment_date,
+            period: Some(SubscriptionPeriod::new(1)),
+            success_url: None,
+            title: Some("Pro plan".to_string()),
+            user: None,
+            price_option: None,
+            trial_days,
+            trial_end_date: trial_end_date.map(str::to_string),
+            recurring: None,
+        }
+    };
+
+    let client = TapsilatClient::from_api_key("test-api-key").unwrap();
+    let is_validation_error = |request| {
+        matches!(
+            client.create_subscription(request),
+            Err(tapsilat::TapsilatError::ValidationError(_))
+        )
+    };
+
+    assert!(is_validation_error(request(
+        Some(14),
+        Some("2099-01-01"),
+        None
+    )));
+    assert!(is_validation_error(request(Some(0), None, None)));
+    assert!(is_validation_error(request(None, Some("2020-01-01"), None)));
+    assert!(is_validation_error(request(None, Some("01/02/2099"), None)));
+    assert!(is_validation_error(request(Some(14), None, Some(0))));
+    assert!(is_validation_error(request(Some(14), None, Some(32))));
+
+    let serialized = serde_json::to_value(request(Some(14), None, Some(1))).unwrap();
+    assert_eq!(serialized["trial_days"], 14);
+    assert!(serialized.get("trial_end_date").is_none());
+
+    let date = |s: &str| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+    let today = date("2024-03-01");
+    assert_eq!(
+        request(Some(14), None, None).first_charge_date(today),
+        Some(date("2024-03-15"))
+    );
+    assert_eq!(
+        request(None, Some("2024-03-15"), Some(15)).first_charge_date(today),
+        Some(date("2024-04-15"))
+    );
+    assert_eq!(
+        request(None, Some("2024-03-15"), Some(20)).first_charge_date(today),
+        Some(date("2024-03-20"))
+    );
+    assert_eq!(
+        request(None, Some("2024-01-31"), Some(31)).first_charge_date(today),
+        Some(date("2024-02-29"))
+    );
+    assert_eq!(request(None, None, Some(1)).first_charge_date(today), None);
+}
+
+#[test]
+fn test_subscription_url_validation() {
+    use tapsilat::{SubscriptionCreateRequest, SubscriptionPeriod};
+
+    let request = |success_url: &str| SubscriptionCreateRequest {
+        amount: Some(99.0),
+        billing: None,
+        card_id: None,
+        currency: Some("TRY".to_string()),
+        cycle: None,
+        external_reference_id: None,
+        failure_url: Some("https://shop.example.com/failed".to_string()),
+        payment_date: None,
+        period: Some(SubscriptionPeriod::new(1)),
+        success_url: Some(success_url.to_string()),
+        title: Some("Pro plan".to_string()),
+        user: None,
+        price_option: None,
+        trial_days: None,
+        trial_end_date: None,
+        recurring: None,
+    };
+    let is_validation_error = |client: &TapsilatClient, url: &str| {
+        matches!(
+            client.create_subscription(request(url)),
+            Err(tapsilat::TapsilatError::ValidationError(_))
+        )
+    };
+
+    let client = TapsilatClient::from_api_key("test-api-key").unwrap();
+    assert!(is_validation_error(&client, "/subscription/success"));
+    assert!(is_validation_error(
+        &client,
+        "https://shop.example.com/success#plan"
+    ));
+
+    let https_only =
+        TapsilatClient::new(Config::new("test-api-key").with_require_https_urls(true)).unwrap();
+    assert!(is_validation_error(
+        &https_only,
+        "http://shop.example.com/success"
+    ));
+}
+
+#[test]
+fn test_basket_vat_calculation() {
+    use tapsilat::tax::{basket_tax, PricingMode, VatRate};
+    use tapsilat::BasketItemDTO;
+
+    let items = vec![
+        BasketItemDTO {
+            price: Some(50.0),
+            quantity: Some(2),
+            vat_rate: Some(VatRate::Standard),
+            ..Default::default()
+        },
+        BasketItemDTO {
+            price: Some(33.33),
+            quantity: Some(1),
+            vat_rate: Some(VatRate::Reduced),
+            ..Default::default()
+        },
+        BasketItemDTO {
+            price: Some(10.0),
+            quantity: Some(1),
+            vat_rate: Some(VatRate::Intermediate),
+            coupon_discount: Some(5.0),
+            ..Default::default()
+        },
+    ];
+
+    // 20.00 + 0.33 + 0.50
+    assert_eq!(basket_tax(&items, PricingMode::Exclusive).unwrap(), 20.83);
+    // 16.67 + 0.33 + 0.45
+    assert_eq!(basket_tax(&items, PricingMode::Inclusive).unwrap(), 17.45);
+
+    let missing_rate = vec![BasketItemDTO {
+        price: Some(10.0),
+        ..Default::default()
+    }];
+    assert!(basket_tax(&missing_rate, PricingMode::Inclusive).is_err());
+}
+
+#[test]
+fn test_basket_builder() {
+    use tapsilat::tax::{PricingMode, VatRate};
+    use tapsilat::{BasketBuilder, CreateBuyerRequest};
+
+    let basket = BasketBuilder::new()
+        .pricing(PricingMode::Exclusive)
+        .item("Lamp", 100.0, 2, VatRate::Standard, "Home")
+        .item_with_id("SKU-9", "Tea", 9.99, 1, VatRate::Reduced, "Food")
+        .build()
+        .unwrap();
+    assert_eq!(basket.items[0].price, Some(120.0));
+    assert_eq!(basket.items[0].paid_amount, Some(240.0));
+    assert_eq!(basket.items[1].id.as_deref(), Some("SKU-9"));
+    assert_eq!(basket.items[1].price, Some(10.09));
+    assert_eq!(basket.amount, 250.09);
+    assert_eq!(basket.tax_amount, 40.1);
+
+    let request = CreateOrderRequest::builder()
+        .buyer(CreateBuyerRequest {
+            name: "Ada".to_string(),
+            surname: "Lovelace".to_string(),
+            ..Default::default()
+        })
+        .basket(basket)
+        .currency("EUR")
+        .build()
+        .unwrap();
+    assert_eq!(request.amount, 250.09);
+    assert_eq!(request.tax_amount, Some(40.1));
+    assert_eq!(request.basket_items.as_ref().map(Vec::len), Some(2));
+
+    assert!(BasketBuilder::new().build().is_err());
+    assert!(BasketBuilder::new()
+        .item("Lamp", 10.0, 0, VatRate::Standard, "Home")
+        .build()
+        .is_err());
+    assert!(BasketBuilder::new()
+        .item("Lamp", 10.0, 1, VatRate::Standard, "Home")
+        .item_with_id("1", "Bulb", 2.0, 1, VatRate::Standard, "Home")
+        .build()
+        .is_err());
+    assert!(CreateOrderRequest::builder().amount(10.0).build().is_err());
+}
+
+#[test]
+fn test_submerchant_split() {
+    use tapsilat::tax::VatRate;
+    use tapsilat::{BasketBuilder, Commission, CreateBuyerRequest, SubmerchantSplitBuilder};
+
+    let mut basket = BasketBuilder::new()
+        .item("Vase", 99.99, 1, VatRate::Standard, "Home")
+        .item("Rug", 300.0, 1, VatRate::Standard, "Home")
+        .item("Gift wrap", 5.0, 1, VatRate::Standard, "Service")
+        .build()
+        .unwrap();
+    basket.items[0].sub_merchant_key = Some("seller_a".to_string());
+    basket.items[1].sub_merchant_key = Some("seller_b".to_string());
+
+    let mut request = CreateOrderRequest::builder()
+        .buyer(CreateBuyerRequest {
+            name: "Ada".to_string(),
+            surname: "Lovelace".to_string(),
+            ..Default::default()
+        })
+        .basket(basket)
+        .build()
+        .unwrap();
+
+    let split = SubmerchantSplitBuilder::new(Commission::Percent(12.5))
+        .commission_for("seller_b", Commission::Fixed(20.0));
+    split.apply(&mut request).unwrap();
+
+    let submerchants = request.submerchants.as_ref().unwrap();
+    assert_eq!(submerchants.len(), 2);
+    assert_eq!(submerchants[0].amount, Some(87.49));
+    assert_eq!(
+        submerchants[0].merchant_reference_id.as_deref(),
+        Some("seller_a")
+    );
+    assert_eq!(submerchants[0].order_basket_item_id.as_deref(), Some("1"));
+    assert_eq!(submerchants[1].amount, Some(280.0));
+    let items = request.basket_items.as_ref().unwrap();
+    assert_eq!(items[0].commission_amount, Some(12.5));
+    assert_eq!(items[0].sub_merchant_price.as_deref(), Some("87.49"));
+    assert_eq!(items[2].commission_amount, None);
+
+    request.amount = 100.0;
+    assert!(split.apply(&mut request).is_err());
+
+    let too_much = SubmerchantSplitBuilder::new(Commission::Fixed(150.0));
+    let mut items = request.basket_items.clone().unwrap();
+    assert!(too_much.allocate(&mut items).is_err());
+}
+
+#[test]
+fn test_order_lifecycle_transitions() {
+    use tapsilat::{OrderLifecycle, OrderStatus};
+
+    let mut lifecycle = OrderLifecycle::new(OrderStatus::Pending);
+    assert!(lifecycle.can_cancel());
+    assert!(!lifecycle.can_refund());
+    assert!(lifecycle.transition(OrderStatus::Refunded).is_err());
+
+    lifecycle.transition(OrderStatus::Processing).unwrap();
+    assert!(lifecycle.can_capture());
+
+    lifecycle.transition(OrderStatus::Completed).unwrap();
+    assert!(lifecycle.can_refund());
+    assert!(!lifecycle.can_cancel());
+
+    lifecycle
+        .transition(OrderStatus::PartiallyRefunded)
+        .unwrap();
+    assert!(lifecycle.can_refund());
+    lifecycle.transition(OrderStatus::Refunded).unwrap();
+    assert!(!lifecycle.can_refund());
+    assert!(lifecycle.transition(OrderStatus::Pending).is_err());
+
+    let order = tapsilat::Order {
+        status_enum: Some("CANCELLED".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        order.lifecycle().map(|l| l.status().clone()),
+        Some(OrderStatus::Cancelled)
+    );
+}
+
+#[test]
+fn test_installment_schedule_and_summary() {
+    use tapsilat::{Installment, InstallmentPlan, InstallmentStatus};
+
+    let installment = |number: u8, due_date: &str, status: InstallmentStatus| Installment {
+        id: format!("inst_{}", number),
+        installment_number: number,
+        amount: 100.10,
+        due_date: due_date.to_string(),
+        paid_at: None,
+        status,
+    };
+    let plan = InstallmentPlan {
+        id: "plan_1".to_string(),
+        order_id: "order_1".to_string(),
+        total_installments: 3,
+        installment_amount: 100.10,
+        currency: "TRY".to_string(),
+        status: InstallmentStatus::Pending,
+        installments: vec![
+            installment(3, "2024-03-01", InstallmentStatus::Pending),
+            installment(1, "2024-01-01", InstallmentStatus::Paid),
+            installment(2, "2024-02-01T09:00:00Z", InstallmentStatus::Pending),
+        ],
+        created_at: "2023-12-01".to_string(),
+        updated_at: "2024-01-01".to_string(),
+    };
+    let now = chrono::DateTime::parse_from_rfc3339("2024-02-11T12:00:00Z")
+        .unwrap()
+        .with_timezone(&chrono::Utc);
+
+    let table = plan.schedule_table();
+    assert_eq!(
+        table
+            .iter()
+            .map(|row| row.installment_number)
+            .collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+    assert_eq!(table[0].paid_to_date, 100.10);
+    assert_eq!(table[1].remaining_after, 100.10);
+    assert_eq!(table[2].remaining_after, 0.0);
+
+    assert_eq!(plan.installments[2].days_overdue(now), 10);
+    assert_eq!(plan.installments[0].days_overdue(now), 0);
+    assert_eq!(plan.installments[1].days_overdue(now), 0);
+
+    let summary = plan.summary(now);
+    assert_eq!(summary.paid_amount, 100.10);
+    assert_eq!(summary.outstanding_amount, 200.20);
+    assert_eq!(summary.overdue_count, 1);
+    assert_eq!(summary.max_days_overdue, 10);
+    assert_eq!(summary.next_due.unwrap().installment_number, 2);
+}
+
+#[test]
+fn test_refund_and_cancel_reason_codes() {
+    use tapsilat::{CancelReason, RefundReason};
+
+    assert_eq!(
+        serde_json::to_value(RefundReason::CustomerRequest).unwrap(),
+        "customer_request"
+    );
+    assert_eq!(
+        serde_json::from_value::<RefundReason>(serde_json::json!("OUT_OF_STOCK")).unwrap(),
+        RefundReason::OutOfStock
+    );
+    assert_eq!(
+        serde_json::from_value::<CancelReason>(serde_json::json!("price_error")).unwrap(),
+        CancelReason::Other("price_error".to_string())
+    );
+    assert_eq!(
+        CancelReason::Other("price_error".to_string()).as_str(),
+        "price_error"
+    );
+
+    let order: tapsilat::Order = serde_json::from_value(serde_json::json!({
+        "reference_id": "ref_1",
+        "cancel_reason": "fraud"
+    }))
+    .unwrap();
+    assert_eq!(order.cancel_reason, Some(CancelReason::Fraud));
+}
+
+#[test]
+fn test_ledger_reconciliation() {
+    use tapsilat::reconcile::{
+        parse_minor_units, reconcile, Discrepancy, LedgerSide, LocalLedgerRow,
+    };
+    use tapsilat::{LedgerDirection, LedgerEntry, LedgerEntryKind};
+
+    assert_eq!(parse_minor_units("100.1").unwrap(), 10010);
+    assert_eq!(parse_minor_units("0.070").unwrap(), 7);
+    assert!(parse_minor_units("1.005").is_err());
+    assert!(parse_minor_units("-3").is_err());
+
+    let row = |reference: &str, kind, direction, amount: &str| LocalLedgerRow {
+        reference_id: reference.to_string(),
+        kind,
+        direction,
+        amount: amount.to_string(),
+    };
+    let local = vec![
+        row(
+            "ref_1",
+            LedgerEntryKind::OrderPayment,
+            LedgerDirection::Credit,
+            "100.00",
+        ),
+        row(
+            "ref_1",
+            LedgerEntryKind::Commission,
+            LedgerDirection::Debit,
+            "2.50",
+        ),
+        row(
+            "ref_2",
+            LedgerEntryKind::OrderPayment,
+            LedgerDirection::Credit,
+            "40.00",
+        ),
+        row(
+            "ref_2",
+            LedgerEntryKind::OrderPayment,
+            LedgerDirection::Credit,
+            "40.00",
+        ),
+        row(
+            "ref_3",
+            LedgerEntryKind::Refund,
+            LedgerDirection::Debit,
+            "10.00",
+        ),
+        row(
+            "po_1",
+            LedgerEntryKind::Payout,
+            LedgerDirection::Debit,
+            "97.50",
+        ),
+    ];
+    let remote: Vec<LedgerEntry> = serde_json::from_value(serde_json::json!([
+        { "entry_id": "le_1", "type": "order_payment", "direction": "credit", "amount": "100", "reference_id": "ref_1" },
+        { "entry_id": "le_2", "type": "commission", "direction": "debit", "amount": 2.6, "reference_id": "ref_1" },
+        { "entry_id": "le_3", "type": "order_payment", "direction": "credit", "amount": "40.00", "reference_id": "ref_2" },
+        { "entry_id": "le_4", "type": "payout", "direction": "debit", "amount": "97.50", "payout_id": "po_1" },
+        { "entry_id": "le_5", "type": "chargeback", "direction": "debit", "amount": "15.00", "reference_id": "ref_4" }
+    ]))
+    .unwrap();
+
+    let report = reconcile(&local, &remote).unwrap();
+    assert_eq!(report.matched, 3);
+    assert!(!report.is_clean());
+    assert_eq!(report.discrepancies.len(), 4);
+    assert!(matches!(
+        &report.discrepancies[0],
+        Discrepancy::AmountMismatch { row, entry } if row.amount == "2.50" && entry.entry_id == "le_2"
+    ));
+    assert!(matches!(
+        &report.discrepancies[1],
+        Discrepancy::Duplicate { side: LedgerSide::Local, reference_id, amount, extra: 1, .. }
+            if reference_id == "ref_2" && *amount == 40.0
+    ));
+    assert!(matches!(
+        &report.discrepancies[2],
+        Discrepancy::MissingRemotely { row } if row.reference_id == "ref_3"
+    ));
+    assert!(matches!(
+        &report.discrepancies[3],
+        Discrepancy::MissingLocally { entry } if entry.entry_id == "le_5"
+    ));
+
+    let bad = vec![row(
+        "ref_1",
+        LedgerEntryKind::Refund,
+        LedgerDirection::Debit,
+        "1,50",
+    )];
+    assert!(reconcile(&bad, &[]).is_err());
+}
+
+#[test]
+fn test_subscription_proration() {
+    use chrono::NaiveDate;
+    use tapsilat::ProrationQuote;
+
+    let start = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+    let next = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    let date = |day| NaiveDate::from_ymd_opt(2024, 2, day).unwrap();
+
+    // 10 of 29 days left: upgrade from 99.90 to 149.90
+    let upgrade = ProrationQuote::calculate(99.9, 149.9, start, next, date(20)).unwrap();
+    assert_eq!(upgrade.unused_credit, 34.45);
+    assert_eq!(upgrade.prorated_charge, 51.69);
+    assert_eq!(upgrade.next_charge_amount, 167.14);
+    assert_eq!(upgrade.next_charge_date, next);
+    assert_eq!(upgrade.remaining_credit, 0.0);
+
+    // A downgrade on the first day can leave credit beyond the next charge
+    let downgrade = ProrationQuote::calculate(300.0, 50.0, start, next, start).unwrap();
+    assert_eq!(downgrade.next_charge_amount, 0.0);
+    assert_eq!(downgrade.remaining_credit, 200.0);
+
+    assert!(ProrationQuote::calculate(100.0, 150.0, start, next, next).is_err());
+    assert!(ProrationQuote::calculate(100.0, -1.0, start, next, date(10)).is_err());
+}
+
+#[test]
+fn test_subscription_status() {
+    use tapsilat::{SubscriptionDetail, SubscriptionStatus};
+
+    let detail = |payment_status: Option<&str>, is_active: Option<bool>| -> SubscriptionDetail {
+        serde_json::from_value(serde_json::json!({
+            "payment_status": payment_status,
+            "is_active": is_active
+        }))
+        .unwrap()
+    };
+
+    assert_eq!(
+        detail(Some("PAST_DUE"), Some(true)).status(),
+        Some(SubscriptionStatus::PastDue)
+    );
+    assert_eq!(
+        detail(Some("paused"), Some(false)).status(),
+        Some(SubscriptionStatus::Paused)
+    );
+    assert_eq!(
+        detail(Some("paid"), Some(false)).status(),
+        Some(SubscriptionStatus::Cancelled)
+    );
+    assert_eq!(
+        detail(Some("trialing"), None).status(),
+        Some(SubscriptionStatus::Trialing)
+    );
+    assert_eq!(
+        detail(None, Some(true)).status(),
+        Some(SubscriptionStatus::Active)
+    );
+    assert_eq!(detail(Some("pending_review"), None).status(), None);
+
+    assert!(SubscriptionStatus::PastDue.is_billable());
+    assert!(!SubscriptionStatus::Cancelled.is_billable());
+    assert_eq!(
+        serde_json::from_value::<SubscriptionStatus>(serde_json::json!("canceled")).unwrap(),
+        SubscriptionStatus::Cancelled
+    );
+}
+
+#[test]
+fn test_localized_error_messages() {
+    use tapsilat::{ErrorCode, Locale, TapsilatError};
+
+    let api_error = |status_code, message: &str| TapsilatError::ApiError {
+        status_code,
+        message: message.to_string(),
+        trace_id: Some("trace-1".to_string()),
+    };
+
+    assert_eq!(
+        api_error(400, "Insufficient funds").code(),
+        ErrorCode::InsufficientFunds
+    );
+    assert_eq!(
+        api_error(402, "Do not honour").code(),
+        ErrorCode::CardDeclined
+    );
+    assert_eq!(api_error(429, "Slow down").code(), ErrorCode::RateLimited);
+    assert_eq!(
+        api_error(503, "Bad gateway").code(),
+        ErrorCode::ServiceUnavailable
+    );
+    assert_eq!(
+        TapsilatError::ValidationError("bad".to_string()).code(),
+        ErrorCode::InvalidRequest
+    );
+    assert_eq!(ErrorCode::CardDeclined.as_str(), "card_declined");
+
+    let declined = api_error(400, "Card declined by issuer");
+    assert_eq!(
+        declined.localized_message(Locale::Tr),
+        "Kartınız bankanız tarafından onaylanmadı. Lütfen başka bir kart deneyin."
+    );
+    let english = declined.localized_message(Locale::En);
+    assert!(english.starts_with("Your card was declined"));
+    assert!(!english.contains("issuer") && !english.contains("trace-1"));
+}
+
+#[test]
+fn test_amount_formatting() {
+    use tapsilat::money::{format_amount, format_minor_units};
+    use tapsilat::{Currency, Locale};
+
+    assert_eq!(
+        format_amount(1234567.891, Currency::TRY, Locale::Tr),
+        "1.234.567,89 ₺"
+    );
+    assert_eq!(format_amount(0.5, Currency::USD, Locale::En), "$0.50");
+    assert_eq!(
+        format_amount(999.999, Currency::GBP, Locale::En),
+        "£1,000.00"
+    );
+    assert_eq!(
+        format_amount(-1500.0, Currency::TRY, Locale::Tr),
+        "-1.500,00 ₺"
+    );
+    assert_eq!(
+        format_minor_units(123456, Currency::EUR, Locale::Tr),
+        "1.234,56 €"
+    );
+
+    assert_eq!(tapsilat::to_minor_units(0.1 + 0.2), 30);
+    assert_eq!(tapsilat::from_minor_units(-250), -2.5);
+}
@@ -1,13 +1,57 @@
 use mockito::{Server, ServerGuard};
 use serde_json::json;
+use std::sync::{Arc, Mutex};
 use tapsilat::{
-    Config, CreateInstallmentPlanRequest, CreateOrderRequest, RefundOrderRequest, TapsilatClient,
+    AddBankAccountRequest, CancelReason, Config, CreateApiKeyRequest, CreateInstallmentPlanRequest,
+    CreateOrderRequest, DocumentType, DocumentUpload, ErasureStatus, InstallmentStatus, KycStatus,
+    OverdueFilter, ParseWarning, PaymentHistoryFilter, PaymentHistoryKind, RefundOrderRequest,
+    RefundReason, RefundStatus, RescheduleRequest, TapsilatClient, ThreeDSPreference,
 };
 
 async fn setup_mock_server() -> ServerGuard {
     Server::new_async().await
 }
 
+fn minimal_order_request(conversation_id: &str) -> CreateOrderRequest {
+    CreateOrderRequest {
+        amount: 100.0,
+        currency: "TRY".to_string(),
+        locale: "tr".to_string(),
+        conversation_id: Some(conversation_id.to_string()),
+        basket_items: None,
+        buyer: tapsilat::types::CreateBuyerRequest {
+            name: "John".to_string(),
+            surname: "Doe".to_string(),
+            ..Default::default()
+        },
+        metadata: None,
+        billing_address: None,
+        shipping_address: None,
+        checkout_design: None,
+        enabled_installments: None,
+        external_reference_id: None,
+        order_cards: None,
+        paid_amount: None,
+        partial_payment: None,
+        payment_failure_url: None,
+        payment_methods: None,
+        payment_mode: None,
+        payment_options: None,
+        payment_success_url: None,
+        payment_terms: None,
+        pf_sub_merchant: None,
+        redirect_failure_url: None,
+        redirect_success_url: None,
+        sub_organization: None,
+        submerchants: None,
+        tax_amount: None,
+        three_ds: ThreeDSPreference::Auto,
+        consents: None,
+        checkout_expires_in: None,
+        invoice: None,
+    }
+}
+
 #[tokio::test]
 async fn test_order_creation_with_mock() {
     let mut server = setup_mock_server().await;
@@ -20,6 +64,9 @@ async fn test_order_creation_with_mock() {
 
     let _mock = server
         .mock("POST", "/order/create")
+        .match_body(mockito::Matcher::PartialJson(
+            json!({ "checkout_expires_in": 1800 }),
+        ))
         .with_status(201)
         .with_header("content-type", "application/json")
         .with_body(mock_response.to_string())
@@ -27,7 +74,7 @@ async fn test_order_creation_with_mock() {
         .await;
 
     // Configure client to use mock server
-    let config = Config::new("test-api-key").with_base_url(&server.url());
+    let config = Config::new("test-api-key").with_base_url(server.url());
 
     let client = TapsilatClient::new(config).unwrap();
 
@@ -54,6 +101,7 @@ async fn test_order_creation_with_mock() {
             quantity_unit: None,
             sub_merchant_key: None,
             sub_merchant_price: None,
+            vat_rate: None,
         }]),
         buyer: tapsilat::types::CreateBuyerRequest {
             name: "John".to_string(),
@@ -66,6 +114,7 @@ async fn test_order_creation_with_mock() {
             city: None,
             country: None,
             zip_code: None,
+            citizenship: None,
         },
         metadata: None,
         billing_address: None,
@@ -88,8 +137,10 @@ async fn test_order_creation_with_mock() {
         sub_organization: None,
         submerchants: None,
         tax_amount: None,
-        three_d_force: None,
+        three_ds: ThreeDSPreference::Auto,
         consents: None,
+        checkout_expires_in: Some(std::time::Duration::from_secs(30 * 60)),
+        invoice: None,
     };
 
     let result = client.orders().create(order_request);
@@ -138,7 +189,7 @@ async fn test_order_get_with_mock() {
         .create_async()
         .await;
 
-    let config = Config::new("test-api-key").with_base_url(&server.url());
+    let config = Config::new("test-api-key").with_base_url(server.url());
 
     let client = TapsilatClient::new(config).unwrap();
 
@@ -186,7 +237,7 @@ async fn test_installment_plan_creation_with_mock() {
         .create_async()
         .await;
 
-    let config = Config::new("test-api-key").with_base_url(&server.url());
+    let config = Config::new("test-api-key").with_base_url(server.url());
 
     let client = TapsilatClient::new(config).unwrap();
 
@@ -208,6 +259,255 @@ async fn test_installment_plan_creation_with_mock() {
     assert_eq!(plan.installment_amount, 50.0);
 }
 
+#[tokio::test]
+async fn test_installment_plan_pause_and_reschedule_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let plan = |status: &str, due_date: &str| {
+        json!({
+            "id": "plan_456",
+            "order_id": "order_123",
+            "total_installments": 2,
+            "installment_amount": 50.0,
+            "currency": "TRY",
+            "status": status,
+            "installments": [
+                { "id": "inst_1", "installment_number": 1, "amount": 50.0, "due_date": "2024-01-15", "paid_at": "2024-01-15T09:00:00Z", "status": "paid" },
+                { "id": "inst_2", "installment_number": 2, "amount": 50.0, "due_date": due_date, "paid_at": null, "status": "pending" }
+            ],
+            "created_at": "2023-12-01T10:30:00Z",
+            "updated_at": "2024-02-01T10:30:00Z"
+        })
+    };
+
+    let _pause = server
+        .mock("POST", "/installments/plans/plan_456/pause")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(plan("paused", "2024-01-31").to_string())
+        .create_async()
+        .await;
+    let _reschedule = server
+        .mock("POST", "/installments/plans/plan_456/reschedule")
+        .match_body(mockito::Matcher::Json(json!({
+            "installments": [{ "installment_number": 2, "due_date": "2024-02-29" }],
+            "reason": "hardship"
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(plan("pending", "2024-02-29").to_string())
+        .create_async()
+        .await;
+
+    let client =
+        TapsilatClient::new(Config::new("test-api-key").with_base_url(server.url())).unwrap();
+    let installments = client.installments();
+
+    let paused = installments.pause_plan("plan_456").unwrap();
+    assert_eq!(paused.status, InstallmentStatus::Paused);
+
+    let schedule = RescheduleRequest::shift(&paused, 1).with_reason("hardship");
+    let rescheduled = installments.reschedule("plan_456", schedule).unwrap();
+    assert_eq!(rescheduled.installments[1].due_date, "2024-02-29");
+
+    let empty = RescheduleRequest {
+        installments: Vec::new(),
+        reason: None,
+    };
+    assert!(installments.reschedule("plan_456", empty).is_err());
+    assert!(installments.resume_plan("").is_err());
+}
+
+#[tokio::test]
+async fn test_list_overdue_installments_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let plan = |id: &str, status: &str, installments: serde_json::Value| {
+        json!({
+            "id": id,
+            "order_id": format!("order_{}", id),
+            "total_installments": 2,
+            "installment_amount": 50.0,
+            "currency": "TRY",
+            "status": status,
+            "installments": installments,
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-01T00:00:00Z"
+        })
+    };
+    let installment = |number: u8, due_date: &str, status: &str| json!({ "id": format!("inst_{}", number), "installment_number": number, "amount": 50.0, "due_date": due_date, "paid_at": null, "status": status });
+
+    let _page_1 = server
+        .mock("GET", "/installments/plans")
+        .match_query(mockito::Matcher::UrlEncoded("page".into(), "1".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "data": [
+                    plan("plan_a", "pending", json!([installment(1, "2024-03-01", "pending"), installment(2, "2024-04-01", "pending")])),
+                    plan("plan_b", "cancelled", json!([installment(1, "2024-01-01", "pending")]))
+                ],
+                "pagination": { "current_page": 1, "per_page": 100, "total": 3, "total_pages": 2 }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let _page_2 = server
+        .mock("GET", "/installments/plans")
+        .match_query(mockito::Matcher::UrlEncoded("page".into(), "2".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "data": [plan("plan_c", "pending", json!([installment(1, "2024-02-01", "overdue")]))],
+                "pagination": { "current_page": 2, "per_page": 100, "total": 3, "total_pages": 2 }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let client =
+        TapsilatClient::new(Config::new("test-api-key").with_base_url(server.url())).unwrap();
+    let as_of = "2024-03-11T12:00:00Z".parse().unwrap();
+
+    let overdue = client
+        .installments()
+        .list_overdue(&OverdueFilter {
+            as_of: Some(as_of),
+            ..Default::default()
+        })
+        .unwrap();
+    let found: Vec<(&str, u32)> = overdue
+        .iter()
+        .map(|item| (item.plan_id.as_str(), item.days_late))
+        .collect();
+    assert_eq!(found, vec![("plan_c", 39), ("plan_a", 10)]);
+    assert_eq!(overdue[1].amount_outstanding, 50.0);
+
+    let late = client
+        .installments()
+        .list_overdue(&OverdueFilter {
+            as_of: Some(as_of),
+            min_days_late: Some(30),
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(late.len(), 1);
+    assert_eq!(late[0].order_id, "order_plan_c");
+}
+
+#[tokio::test]
+async fn test_installment_payoff_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _quote = server
+        .mock("GET", "/installments/plans/plan_456/payoff-quote")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "data": {
+                    "plan_id": "plan_456",
+                    "currency": "TRY",
+                    "remaining_principal": 250.0,
+                    "early_settlement_discount": 12.5,
+                    "payoff_amount": 237.5,
+                    "remaining_installments": 5,
+                    "valid_until": "2024-03-01T23:59:59Z"
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let _settle = server
+        .mock("POST", "/installments/plans/plan_456/settle")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "plan_456",
+                "order_id": "order_123",
+                "total_installments": 6,
+                "installment_amount": 50.0,
+                "currency": "TRY",
+                "status": "paid",
+                "installments": [],
+                "created_at": "2023-12-01T10:30:00Z",
+                "updated_at": "2024-02-20T10:30:00Z"
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let client =
+        TapsilatClient::new(Config::new("test-api-key").with_base_url(server.url())).unwrap();
+
+    let quote = client.installments().payoff_quote("plan_456").unwrap();
+    assert_eq!(quote.payoff_amount, 237.5);
+    assert_eq!(quote.early_settlement_discount, 12.5);
+    assert_eq!(quote.remaining_installments, 5);
+
+    let plan = client.installments().settle("plan_456").unwrap();
+    assert_eq!(plan.status, InstallmentStatus::Paid);
+    assert!(client.installments().payoff_quote("").is_err());
+}
+
+#[tokio::test]
+async fn test_buyer_payment_history_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _mock = server
+        .mock("GET", "/buyer/buyer_1/payment-history")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("type".into(), "refund".into()),
+            mockito::Matcher::UrlEncoded("page".into(), "1".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "data": [
+                    { "id": "rf_1", "type": "refund", "reference_id": "ref_1", "amount": "25.50", "currency": "TRY", "status": "completed", "occurred_at": "2024-02-02T10:00:00Z" },
+                    { "id": "cb_1", "type": "chargeback", "reference_id": "ref_2", "amount": 10, "currency": "TRY" }
+                ],
+                "pagination": { "current_page": 1, "per_page": 20, "total": 2, "total_pages": 1 }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let client =
+        TapsilatClient::new(Config::new("test-api-key").with_base_url(server.url())).unwrap();
+
+    let history = client
+        .get_buyer_payment_history(
+            "buyer_1",
+            &PaymentHistoryFilter {
+                kind: Some(PaymentHistoryKind::Refund),
+                page: Some(1),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(history.data.len(), 2);
+    assert_eq!(history.data[0].kind, PaymentHistoryKind::Refund);
+    assert_eq!(history.data[0].amount, 25.5);
+    assert_eq!(history.data[1].kind, PaymentHistoryKind::Unknown);
+    assert_eq!(history.pagination.total, 2);
+
+    assert!(client
+        .buyers()
+        .payment_history(" ", &PaymentHistoryFilter::default())
+        .is_err());
+}
+
 #[tokio::test]
 async fn test_error_handling_with_mock() {
     let mut server = setup_mock_server().await;
@@ -226,7 +526,7 @@ async fn test_error_handling_with_mock() {
         .create_async()
         .await;
 
-    let config = Config::new("invalid-api-key").with_base_url(&server.url());
+    let config = Config::new("invalid-api-key").with_base_url(server.url());
 
     let client = TapsilatClient::new(config).unwrap();
 
@@ -253,6 +553,7 @@ async fn test_error_handling_with_mock() {
             quantity_unit: None,
             sub_merchant_key: None,
             sub_merchant_price: None,
+            vat_rate: None,
         }]),
         buyer: tapsilat::types::CreateBuyerRequest {
             name: "John".to_string(),
@@ -265,6 +566,7 @@ async fn test_error_handling_with_mock() {
             city: None,
             country: None,
             zip_code: None,
+            citizenship: None,
         },
         metadata: None,
         billing_address: None,
@@ -287,8 +589,10 @@ async fn test_error_handling_with_mock() {
         sub_organization: None,
         submerchants: None,
         tax_amount: None,
-        three_d_force: None,
+        three_ds: ThreeDSPreference::Auto,
         consents: None,
+        checkout_expires_in: None,
+        invoice: None,
     };
 
     let result = client.orders().create(order_request);
@@ -329,7 +633,7 @@ async fn test_order_refund_with_mock() {
         .create_async()
         .await;
 
-    let config = Config::new("test-api-key").with_base_url(&server.url());
+    let config = Config::new("test-api-key").with_base_url(server.url());
 
     let client = TapsilatClient::new(config).unwrap();
 
@@ -338,6 +642,7 @@ async fn test_order_refund_with_mock() {
         reference_id: "order_123".to_string(), // In struct, this field exists
         order_item_id: None,
         order_item_payment_id: None,
+        reason: None,
     };
 
     // The method seems to be taking just the request object in source, so we match that.
@@ -391,7 +696,7 @@ async fn test_pagination_with_mock() {
         .create_async()
         .await;
 
-    let config = Config::new("test-api-key").with_base_url(&server.url());
+    let config = Config::new("test-api-key").with_base_url(server.url());
 
     let client = TapsilatClient::new(config).unwrap();
 
@@ -408,3 +713,3666 @@ async fn test_pagination_with_mock() {
     assert_eq!(paginated_response.pagination.current_page, 1);
     assert_eq!(paginated_response.pagination.total, 1);
 }
+
+#[tokio::test]
+async fn test_batch_order_statuses_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _paid = server
+        .mock("GET", "/order/ref_1/status")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "status": "paid" }).to_string())
+        .create_async()
+        .await;
+
+    let _missing = server
+        .mock("GET", "/order/ref_2/status")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "message": "Order not found" }).to_string())
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let statuses = client
+        .orders()
+        .get_statuses_with_concurrency(&["ref_1", "ref_2", "ref_1"], 2);
+
+    assert_eq!(statuses.len(), 2);
+    assert_eq!(statuses["ref_1"].as_ref().unwrap()["status"], "paid");
+    assert!(statuses["ref_2"].is_err());
+}
+
+#[tokio::test]
+async fn test_compressed_response_with_mock() {
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    let mut server = setup_mock_server().await;
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(json!({ "status": "paid" }).to_string().as_bytes())
+        .unwrap();
+    let compressed = encoder.finish().unwrap();
+
+    let _mock = server
+        .mock("GET", "/order/ref_gzip/status")
+        .match_header(
+            "accept-encoding",
+            mockito::Matcher::Regex("gzip".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("content-encoding", "gzip")
+        .with_body(compressed)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let status = client.orders().get_status("ref_gzip").unwrap();
+    assert_eq!(status["status"], "paid");
+}
+
+#[tokio::test]
+async fn test_default_headers_and_user_agent_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _mock = server
+        .mock("GET", "/health")
+        .match_header("x-partner-id", "partner-42")
+        .match_header(
+            "user-agent",
+            mockito::Matcher::Regex(r"^tapsilat-rust/\S+ my-shop/1\.2$".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "status": "ok" }).to_string())
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key")
+        .with_base_url(server.url())
+        .with_default_header("X-Partner-Id", "partner-42")
+        .with_user_agent_suffix("my-shop/1.2");
+    let client = TapsilatClient::new(config).unwrap();
+
+    let health = client.health_check().unwrap();
+    assert_eq!(health["status"], "ok");
+}
+
+#[tokio::test]
+async fn test_refund_with_request_options_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _mock = server
+        .mock("POST", "/order/refund")
+        .match_header("idempotency-key", "refund-order_123-1")
+        .match_header("x-channel", "support")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "success": true, "data": { "refund_id": "refund_1" } }).to_string())
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let options = tapsilat::RequestOptions::new()
+        .timeout(5)
+        .idempotency_key("refund-order_123-1")
+        .header("X-Channel", "support");
+
+    let refund_request = RefundOrderRequest {
+        amount: 10.0,
+        reference_id: "order_123".to_string(),
+        order_item_id: None,
+        order_item_payment_id: None,
+        reason: None,
+    };
+
+    let result = client
+        .orders()
+        .refund_with(refund_request.clone(), &options)
+        .unwrap();
+    assert_eq!(result["refund_id"], "refund_1");
+
+    let options = options.header("authorization", "Bearer other-key");
+    assert!(matches!(
+        client.orders().refund_with(refund_request, &options),
+        Err(tapsilat::TapsilatError::ValidationError(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_trace_id_propagation_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _ok = server
+        .mock("GET", "/health")
+        .match_header("x-request-id", "trace-from-hook")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-request-id", "server-trace-1")
+        .with_body(json!({ "status": "ok" }).to_string())
+        .create_async()
+        .await;
+
+    let _error = server
+        .mock("GET", "/order/missing/status")
+        .match_header("x-request-id", "trace-override")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_header("x-request-id", "server-trace-2")
+        .with_body(json!({ "message": "Order not found" }).to_string())
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key")
+        .with_base_url(server.url())
+        .with_trace_id_provider(|| Some("trace-from-hook".to_string()));
+    let client = TapsilatClient::new(config).unwrap();
+
+    client.health_check().unwrap();
+    assert_eq!(client.last_trace_id().as_deref(), Some("server-trace-1"));
+
+    let options = tapsilat::RequestOptions::new().trace_id("trace-override");
+    let error = client
+        .orders()
+        .get_status_with("missing", &options)
+        .unwrap_err();
+    assert_eq!(error.trace_id(), Some("server-trace-2"));
+    assert!(matches!(
+        error,
+        tapsilat::TapsilatError::ApiError {
+            status_code: 404,
+            ..
+        }
+    ));
+}
+
+#[tokio::test]
+async fn test_field_validation_errors_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _mock = server
+        .mock("POST", "/order/refund")
+        .with_status(422)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "message": "Validation failed",
+                "errors": [
+                    { "field": "amount", "message": "must be less than paid amount", "code": "max" },
+                    { "field": "reference_id", "message": "is required" }
+                ]
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let refund_request = RefundOrderRequest {
+        amount: 1000.0,
+        reference_id: String::new(),
+        order_item_id: None,
+        order_item_payment_id: None,
+        reason: None,
+    };
+
+    match client.orders().refund(refund_request) {
+        Err(tapsilat::TapsilatError::ValidationFailed { fields, .. }) => {
+            assert_eq!(fields.len(), 2);
+            assert_eq!(fields[0].field, "amount");
+            assert_eq!(fields[0].code.as_deref(), Some("max"));
+            assert_eq!(fields[1].message, "is required");
+        }
+        other => panic!("expected ValidationFailed, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_strict_parsing_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let mock_response = json!({
+        "order_id": "order_123",
+        "reference_id": "ref_12345",
+        "checkout_url": null,
+        "unexpected_field": true
+    });
+
+    let _mock = server
+        .mock("GET", "/order/conversation/conv_1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_response.to_string())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let lenient =
+        TapsilatClient::new(Config::new("test-api-key").with_base_url(server.url())).unwrap();
+    assert!(lenient.get_order_by_conversation_id("conv_1").is_ok());
+
+    let strict = TapsilatClient::new(
+        Config::new("test-api-key")
+            .with_base_url(server.url())
+            .with_strict_parsing(true),
+    )
+    .unwrap();
+    match strict.get_order_by_conversation_id("conv_1") {
+        Err(tapsilat::TapsilatError::InvalidResponse(message)) => {
+            assert!(message.contains("unexpected_field"))
+        }
+        other => panic!("expected InvalidResponse, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_parse_warnings_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _drifted = server
+        .mock("GET", "/order/ref_drift")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "data": { "reference_id": "ref_drift", "risk_score": 12 }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let _bare = server
+        .mock("GET", "/order/ref_bare")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "success": true, "data": 5, "reference_id": "ref_bare" }).to_string())
+        .create_async()
+        .await;
+    let _broken = server
+        .mock("GET", "/order/ref_broken")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "success": true, "data": "not an order" }).to_string())
+        .create_async()
+        .await;
+
+    let warnings = Arc::new(Mutex::new(Vec::<ParseWarning>::new()));
+    let sink = Arc::clone(&warnings);
+    let client = TapsilatClient::new(
+        Config::new("test-api-key")
+            .with_base_url(server.url())
+            .with_parse_warnings(move |warning: &ParseWarning| {
+                sink.lock().unwrap().push(warning.clone())
+            }),
+    )
+    .unwrap();
+
+    let order = client.get_order("ref_drift").unwrap();
+    assert_eq!(order.reference_id.as_deref(), Some("ref_drift"));
+    let order = client.get_order("ref_bare").unwrap();
+    assert_eq!(order.reference_id.as_deref(), Some("ref_bare"));
+    assert!(client.get_order("ref_broken").is_err());
+
+    let warnings = warnings.lock().unwrap();
+    assert_eq!(warnings.len(), 3);
+    assert!(warnings[0].enveloped);
+    assert_eq!(warnings[0].unknown_fields, vec!["risk_score"]);
+    assert!(warnings[0]
+        .present_fields
+        .contains(&"reference_id".to_string()));
+    assert!(!warnings[1].enveloped);
+    assert!(warnings[1].unknown_fields.contains(&"success".to_string()));
+    assert_eq!(warnings[2].context, "order response");
+    assert!(warnings[2].error.is_some());
+}
+
+#[tokio::test]
+async fn test_order_export_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _page_1 = server
+        .mock("GET", "/order/list")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("page".into(), "1".into()),
+            mockito::Matcher::UrlEncoded("buyer_id".into(), "buyer 7&vip".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "rows": [
+                    { "reference_id": "ref_1", "amount": "100.00", "currency": "TRY", "created_at": "2024-01-05T10:00:00Z", "buyer": { "name": "Ada, Jr." } },
+                    { "reference_id": "ref_old", "amount": "5.00", "currency": "TRY", "created_at": "2023-12-31T23:00:00Z" }
+                ],
+                "total_pages": 2
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let _page_2 = server
+        .mock("GET", "/order/list")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("page".into(), "2".into()),
+            mockito::Matcher::UrlEncoded("buyer_id".into(), "buyer 7&vip".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "rows": [
+                    { "reference_id": "ref_2", "amount": "42.50", "currency": "TRY", "created_at": "2024-01-20T08:30:00Z" }
+                ],
+                "total_pages": 2
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+    let filter = tapsilat::OrderExportFilter {
+        buyer_id: Some("buyer 7&vip".to_string()),
+        start_date: Some("2024-01-01".to_string()),
+        end_date: Some("2024-02-01".to_string()),
+        per_page: 2,
+    };
+
+    let mut csv = Vec::new();
+    let written = client
+        .reports()
+        .export_orders(&filter, tapsilat::ExportFormat::Csv, &mut csv)
+        .unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+
+    assert_eq!(written, 2);
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("reference_id,"));
+    assert!(lines[1].starts_with("ref_1,"));
+    assert!(lines[1].contains("\"Ada, Jr.\""));
+    assert!(lines[2].starts_with("ref_2,"));
+
+    let mut json_out = Vec::new();
+    client
+        .reports()
+        .export_orders(&filter, tapsilat::ExportFormat::Json, &mut json_out)
+        .unwrap();
+    let rows: serde_json::Value = serde_json::from_slice(&json_out).unwrap();
+    assert_eq!(rows.as_array().unwrap().len(), 2);
+    assert_eq!(rows[1]["reference_id"], "ref_2");
+}
+
+#[tokio::test]
+async fn test_ledger_entries_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let first = server
+        .mock("GET", "/account/ledger?from=2024-01-01&to=2024-01-31")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "data": {
+                    "rows": [
+                        { "entry_id": "le_1", "type": "order_payment", "direction": "credit", "amount": "100.00", "currency": "TRY", "reference_id": "ref_1" },
+                        { "entry_id": "le_2", "type": "commission", "direction": "debit", "amount": "2.50", "currency": "TRY", "reference_id": "ref_1" }
+                    ],
+                    "next_cursor": "c_2+a/b=",
+                    "has_more": true
+                }
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let second = server
+        .mock(
+            "GET",
+            "/account/ledger?from=2024-01-01&to=2024-01-31&cursor=c_2%2Ba%2Fb%3D",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "rows": [
+                    { "entry_id": "le_3", "type": "payout", "direction": "debit", "amount": "97.50", "payout_id": "po_1" },
+                    { "entry_id": "le_4", "type": "reserve_release", "direction": "hold", "amount": "1.00" }
+                ],
+                "has_more": false
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client =
+        TapsilatClient::new(Config::new("test-api-key").with_base_url(server.url())).unwrap();
+    let from = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    let to = chrono::NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+
+    let page = client.reports().ledger_entries(from, to, None).unwrap();
+    assert!(page.has_more);
+    assert_eq!(
+        page.entries[0].kind,
+        tapsilat::LedgerEntryKind::OrderPayment
+    );
+    assert_eq!(page.entries[0].signed_amount(), Some(100.0));
+    assert_eq!(page.entries[1].signed_amount(), Some(-2.5));
+
+    let page = client
+        .reports()
+        .ledger_entries(from, to, page.next_cursor.as_deref())
+        .unwrap();
+    assert!(!page.has_more);
+    assert_eq!(page.next_cursor, None);
+    assert_eq!(page.entries[0].payout_id.as_deref(), Some("po_1"));
+    assert_eq!(page.entries[1].kind, tapsilat::LedgerEntryKind::Unknown);
+    assert_eq!(page.entries[1].signed_amount(), None);
+    first.assert_async().await;
+    second.assert_async().await;
+
+    assert!(client.reports().ledger_entries(to, from, None).is_err());
+}
+
+#[tokio::test]
+async fn test_list_order_terms_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _mock = server
+        .mock("GET", "/order/ref_terms")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "data": {
+                    "reference_id": "ref_terms",
+                    "amount": "100.00",
+                    "payment_terms": [
+                        { "term_reference_id": "term_2", "term_sequence": 2, "amount": 50, "due_date": "2024-02-15", "status": "PENDING" },
+                        { "term_reference_id": "term_1", "term_sequence": 1, "amount": "50.00", "due_date": "2024-01-15", "paid_date": "2024-01-14", "status": "PAID" }
+                    ]
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key")
+        .with_base_url(server.url())
+        .with_strict_parsing(true);
+    let client = TapsilatClient::new(config).unwrap();
+
+    let terms = client.list_order_terms("ref_terms").unwrap();
+
+    assert_eq!(terms.len(), 2);
+    assert_eq!(terms[0].term_reference_id.as_deref(), Some("term_1"));
+    assert_eq!(terms[0].amount, Some(50.0));
+    assert_eq!(terms[0].status, Some(tapsilat::PaymentTermStatus::Paid));
+    assert!(terms[0].is_paid());
+    assert!(terms[1].is_unpaid());
+}
+
+#[tokio::test]
+async fn test_refund_guard_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _order = server
+        .mock("GET", "/order/ref_guard")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "data": { "reference_id": "ref_guard", "paid_amount": "100.00", "refunded_amount": "80.00" }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let refund = server
+        .mock("POST", "/order/refund")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "success": true, "data": { "refunded": true } }).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key")
+        .with_base_url(server.url())
+        .with_refund_guard(true);
+    let client = TapsilatClient::new(config).unwrap();
+
+    let refund_request = |amount: f64| RefundOrderRequest {
+        amount,
+        reference_id: "ref_guard".to_string(),
+        order_item_id: None,
+        order_item_payment_id: None,
+        reason: None,
+    };
+
+    match client.refund_order(refund_request(50.0)) {
+        Err(tapsilat::TapsilatError::ValidationError(message)) => {
+            assert!(message.contains("20.00"));
+        }
+        other => panic!("expected ValidationError, got {:?}", other),
+    }
+
+    assert!(client.refund_order(refund_request(20.0)).is_ok());
+    refund.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_checkout_token_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _mock = server
+        .mock("GET", "/order/ref_embed/checkout-token")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "data": { "token": "tok_123", "expires_at": "2020-01-01T00:15:00Z" }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let token = client.get_checkout_token("ref_embed").unwrap();
+
+    assert_eq!(token.token, "tok_123");
+    assert!(token.expires_at_utc().is_some());
+    assert!(token.is_expired());
+}
+
+#[tokio::test]
+async fn test_short_checkout_url_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let branded = server
+        .mock("POST", "/order/ref_sms/short-url")
+        .match_body(mockito::Matcher::Json(
+            json!({ "domain": "pay.example.com" }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "data": {
+                    "short_url": "https://pay.example.com/x7Kp",
+                    "checkout_url": "https://checkout.tapsilat.dev/ref_sms",
+                    "domain": "pay.example.com",
+                    "expires_at": "2099-01-01T00:00:00Z"
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let link = client
+        .get_short_checkout_url("ref_sms", Some("pay.example.com"))
+        .unwrap();
+    branded.assert_async().await;
+    assert_eq!(link.short_url, "https://pay.example.com/x7Kp");
+    assert!(link.expires_at_utc().is_some());
+    assert!(!link.is_expired());
+
+    assert!(client
+        .get_short_checkout_url("ref_sms", Some("https://pay.example.com/"))
+        .is_err());
+    assert!(client
+        .get_short_checkout_url("ref_sms", Some("localhost"))
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_cancel_and_terminate_outcomes_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _cancel = server
+        .mock("POST", "/order/cancel")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "data": { "reference_id": "ref_paid", "status": "refunded", "refund_ids": ["rf_1"] }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let _terminate = server
+        .mock("POST", "/order/terminate")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "reference_id": "ref_open", "status": "CANCELLED" }).to_string())
+        .create_async()
+        .await;
+    let _terminate_term = server
+        .mock("POST", "/order/term/terminate")
+        .match_body(mockito::Matcher::PartialJson(
+            json!({ "term_reference_id": "term_2" }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({ "reference_id": "ref_terms", "term_reference_id": "term_2", "status": "partially_refunded", "refund_ids": ["rf_7", "rf_8"] })
+                .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let client =
+        TapsilatClient::new(Config::new("test-api-key").with_base_url(server.url())).unwrap();
+
+    let cancelled = client.cancel_order("ref_paid").unwrap();
+    assert_eq!(
+        cancelled.order_status(),
+        Some(tapsilat::OrderStatus::Refunded)
+    );
+    assert!(cancelled.triggered_refunds());
+    assert_eq!(cancelled.refund_ids, vec!["rf_1".to_string()]);
+
+    let terminated = client.order_terminate("ref_open").unwrap();
+    assert_eq!(
+        terminated.order_status(),
+        Some(tapsilat::OrderStatus::Cancelled)
+    );
+    assert!(!terminated.triggered_refunds());
+
+    let term = client
+        .terminate_order_term("term_2", Some("customer_request".to_string()))
+        .unwrap();
+    assert_eq!(term.term_reference_id.as_deref(), Some("term_2"));
+    assert_eq!(term.refund_ids.len(), 2);
+}
+
+#[tokio::test]
+async fn test_cancel_and_terminate_empty_body_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _cancel_empty = server
+        .mock("POST", "/order/cancel")
+        .match_body(mockito::Matcher::PartialJson(
+            json!({ "reference_id": "ref_empty" }),
+        ))
+        .with_status(204)
+        .create_async()
+        .await;
+    let _cancel_null = server
+        .mock("POST", "/order/cancel")
+        .match_body(mockito::Matcher::PartialJson(
+            json!({ "reference_id": "ref_null" }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "success": true, "data": null }).to_string())
+        .create_async()
+        .await;
+    let _terminate = server
+        .mock("POST", "/order/terminate")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("")
+        .create_async()
+        .await;
+
+    let client =
+        TapsilatClient::new(Config::new("test-api-key").with_base_url(server.url())).unwrap();
+
+    let cancelled = client.cancel_order("ref_empty").unwrap();
+    assert!(cancelled.order_status().is_none());
+    assert!(!cancelled.triggered_refunds());
+
+    let cancelled = client
+        .cancel_order_with_reason("ref_null", CancelReason::CustomerRequest)
+        .unwrap();
+    assert!(cancelled.reference_id.is_none());
+
+    let terminated = client.order_terminate("ref_open").unwrap();
+    assert!(terminated.refund_ids.is_empty());
+}
+
+#[tokio::test]
+async fn test_envelope_without_data_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _cancel = server
+        .mock("POST", "/order/cancel")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "success": true, "message": "Order cancelled" }).to_string())
+        .create_async()
+        .await;
+
+    let client =
+        TapsilatClient::new(Config::new("test-api-key").with_base_url(server.url())).unwrap();
+
+    // Without a `data` key the envelope itself is decoded, keeping its message
+    let cancelled = client.cancel_order("ref_1").unwrap();
+    assert_eq!(cancelled.message.as_deref(), Some("Order cancelled"));
+    assert!(cancelled.reference_id.is_none());
+}
+
+#[tokio::test]
+async fn test_extend_checkout_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let mock = server
+        .mock("POST", "/order/checkout/extend")
+        .match_body(mockito::Matcher::Json(
+            json!({ "reference_id": "ref_cart", "checkout_expires_in": 86400 }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "success": true }).to_string())
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    assert!(client
+        .extend_checkout("ref_cart", std::time::Duration::from_secs(24 * 60 * 60))
+        .is_ok());
+    mock.assert_async().await;
+    assert!(client
+        .extend_checkout("ref_cart", std::time::Duration::ZERO)
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_webhook_deliveries_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _list = server
+        .mock("GET", "/webhook/deliveries")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "status".into(),
+            "failed".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "data": [{
+                    "id": "dlv_1",
+                    "event_type": "order.completed",
+                    "status": "failed",
+                    "response_code": 500,
+                    "attempts": [
+                        { "attempted_at": "2024-01-01T10:00:00Z", "response_code": 500, "duration_ms": 120 }
+                    ]
+                }]
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let _window = server
+        .mock("GET", "/webhook/deliveries")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("event_type".into(), "order.completed".into()),
+            mockito::Matcher::UrlEncoded("since".into(), "2024-01-01T00:00:00+03:00".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "success": true, "data": [] }).to_string())
+        .create_async()
+        .await;
+
+    let _retry = server
+        .mock("POST", "/webhook/deliveries/dlv_1/retry")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "data": { "id": "dlv_1", "status": "pending" }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let failed = client.webhooks().list_failed_deliveries().unwrap();
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].attempts[0].response_code, Some(500));
+
+    let retried = client.webhooks().retry_delivery(&failed[0].id).unwrap();
+    assert_eq!(retried.status, tapsilat::WebhookDeliveryStatus::Pending);
+    assert!(retried.attempts.is_empty());
+
+    let window = client
+        .webhooks()
+        .list_deliveries(&tapsilat::WebhookDeliveryFilter {
+            event_type: Some("order.completed".to_string()),
+            since: Some("2024-01-01T00:00:00+03:00".to_string()),
+            ..Default::default()
+        })
+        .unwrap();
+    assert!(window.is_empty());
+}
+
+#[tokio::test]
+async fn test_event_polling_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let event = |order_id: &str| {
+        json!({
+            "event_type": "order.completed",
+            "data": { "order_id": order_id },
+            "timestamp": "2024-01-01T00:00:00Z"
+        })
+    };
+
+    let _first = server
+        .mock("GET", "/events")
+        .match_query(mockito::Matcher::Regex("^$".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({ "events": [event("order_1"), event("order_2")], "next_cursor": "c2", "has_more": true })
+                .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let _second = server
+        .mock("GET", "/events")
+        .match_query(mockito::Matcher::UrlEncoded("cursor".into(), "c2".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({ "events": [event("order_3")], "next_cursor": "c3", "has_more": false })
+                .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let mut seen = Vec::new();
+    let cursor = client
+        .events()
+        .poll_loop(None, std::time::Duration::from_millis(10), |event| {
+            seen.push(event.data.order_id.unwrap());
+            if seen.len() == 3 {
+                std::ops::ControlFlow::Break(())
+            } else {
+                std::ops::ControlFlow::Continue(())
+            }
+        })
+        .unwrap();
+
+    assert_eq!(seen, vec!["order_1", "order_2", "order_3"]);
+    assert_eq!(cursor.as_deref(), Some("c3"));
+}
+
+#[tokio::test]
+async fn test_event_polling_keeps_cursor_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let event = |order_id: &str| {
+        json!({
+            "event_type": "order.completed",
+            "data": { "order_id": order_id },
+            "timestamp": "2024-01-01T00:00:00Z"
+        })
+    };
+
+    let _first = server
+        .mock("GET", "/events")
+        .match_query(mockito::Matcher::UrlEncoded("cursor".into(), "c1/a+b".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({ "events": [event("order_1"), event("order_2")], "next_cursor": "c2", "has_more": true })
+                .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let _failing = server
+        .mock("GET", "/events")
+        .match_query(mockito::Matcher::UrlEncoded("cursor".into(), "c2".into()))
+        .with_status(503)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "message": "unavailable" }).to_string())
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key")
+        .with_base_url(server.url())
+        .with_max_retries(0);
+    let client = TapsilatClient::new(config).unwrap();
+    let interval = std::time::Duration::from_millis(10);
+
+    let mut seen = Vec::new();
+    let cursor = client
+        .events()
+        .poll_loop(Some("c1/a+b".to_string()), interval, |event| {
+            seen.push(event.data.order_id.unwrap());
+            std::ops::ControlFlow::Break(())
+        })
+        .unwrap();
+    assert_eq!(seen, vec!["order_1"]);
+    assert_eq!(cursor.as_deref(), Some("c1/a+b"));
+
+    let err = client
+        .events()
+        .poll_loop(cursor, interval, |_| std::ops::ControlFlow::Continue(()))
+        .unwrap_err();
+    assert_eq!(err.cursor.as_deref(), Some("c2"));
+    assert!(matches!(
+        err.error,
+        tapsilat::TapsilatError::ApiError {
+            status_code: 503,
+            ..
+        }
+    ));
+}
+
+#[tokio::test]
+async fn test_create_idempotent_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _create = server
+        .mock("POST", "/order/create")
+        .match_body(mockito::Matcher::PartialJson(
+            json!({ "conversation_id": "conv-1" }),
+        ))
+        .with_status(409)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "message": "conversation_id already exists" }).to_string())
+        .create_async()
+        .await;
+    let _other_conflict = server
+        .mock("POST", "/order/create")
+        .match_body(mockito::Matcher::PartialJson(
+            json!({ "conversation_id": "conv-2" }),
+        ))
+        .with_status(409)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "message": "external_reference_id already exists" }).to_string())
+        .create_async()
+        .await;
+    let _vanished = server
+        .mock("POST", "/order/create")
+        .match_body(mockito::Matcher::PartialJson(
+            json!({ "conversation_id": "conv-3" }),
+        ))
+        .with_status(409)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "message": "conversation_id already exists" }).to_string())
+        .create_async()
+        .await;
+    let lookup_other = server
+        .mock("GET", "/order/conversation/conv-2")
+        .expect(0)
+        .create_async()
+        .await;
+    let _lookup_missing = server
+        .mock("GET", "/order/conversation/conv-3")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "message": "Order not found" }).to_string())
+        .create_async()
+        .await;
+
+    let _existing = server
+        .mock("GET", "/order/conversation/conv-1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({ "order_id": "order_1", "reference_id": "ref_1", "checkout_url": "https://checkout/ref_1" })
+                .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let response = client
+        .create_order_idempotent(minimal_order_request("conv-1"))
+        .unwrap();
+    assert_eq!(response.reference_id.as_deref(), Some("ref_1"));
+
+    // A conflict on another field is returned as is, without a lookup
+    match client.create_order_idempotent(minimal_order_request("conv-2")) {
+        Err(tapsilat::TapsilatError::ApiError {
+            status_code,
+            message,
+            ..
+        }) => {
+            assert_eq!(status_code, 409);
+            assert!(message.contains("external_reference_id"));
+        }
+        other => panic!("expected the create conflict, got {:?}", other),
+    }
+    lookup_other.assert_async().await;
+
+    // A failed lookup surfaces the original conflict rather than the 404
+    assert!(matches!(
+        client.create_order_idempotent(minimal_order_request("conv-3")),
+        Err(tapsilat::TapsilatError::ApiError {
+            status_code: 409,
+            ..
+        })
+    ));
+
+    let mut without_conversation = minimal_order_request("unused");
+    without_conversation.conversation_id = None;
+    assert!(client
+        .create_order_idempotent(without_conversation)
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_subscription_dunning_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _detail = server
+        .mock("POST", "/subscription")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "title": "Pro plan",
+                "orders": [
+                    { "reference_id": "order_1", "status": "PAID" },
+                    { "reference_id": "order_2", "status": "FAILED" }
+                ]
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let retry = server
+        .mock("POST", "/subscription/retry-payment")
+        .match_body(mockito::Matcher::Json(json!({ "reference_id": "sub_1" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({ "code": 0, "order_reference_id": "order_3", "status": "pending" }).to_string(),
+        )
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let failed = client.get_subscription_failed_orders("sub_1").unwrap();
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].reference_id.as_deref(), Some("order_2"));
+
+    let retried = client.retry_subscription_payment("sub_1").unwrap();
+    assert_eq!(retried.order_reference_id.as_deref(), Some("order_3"));
+    retry.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_subscription_card_update_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let update = server
+        .mock("POST", "/subscription/update-card")
+        .match_body(mockito::Matcher::Json(
+            json!({ "reference_id": "sub_1", "card_id": "card_9" }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "code": 0, "message": "ok" }).to_string())
+        .create_async()
+        .await;
+
+    let _url = server
+        .mock("POST", "/subscription/card-update-url")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "url": "https://checkout/update/sub_1" }).to_string())
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    assert_eq!(
+        client
+            .update_subscription_card("sub_1", "card_9")
+            .unwrap()
+            .code,
+        Some(0)
+    );
+    update.assert_async().await;
+    assert!(client.update_subscription_card("sub_1", "").is_err());
+
+    let page = client.subscription_card_update_url("sub_1").unwrap();
+    assert_eq!(page.url.as_deref(), Some("https://checkout/update/sub_1"));
+}
+
+#[tokio::test]
+async fn test_subscription_create_batch_with_mock() {
+    use tapsilat::{SubscriptionBatchOutcome, SubscriptionCreateRequest, SubscriptionPeriod};
+
+    let mut server = setup_mock_server().await;
+
+    let created = server
+        .mock("POST", "/subscription/create")
+        .match_body(mockito::Matcher::PartialJson(
+            json!({ "external_reference_id": "ext_1" }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "code": 0, "reference_id": "sub_1" }).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let _existing = server
+        .mock("POST", "/subscription/create")
+        .match_body(mockito::Matcher::PartialJson(
+            json!({ "external_reference_id": "ext_2" }),
+        ))
+        .with_status(409)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "message": "Subscription already exists" }).to_string())
+        .create_async()
+        .await;
+    let _rejected = server
+        .mock("POST", "/subscription/create")
+        .match_body(mockito::Matcher::PartialJson(
+            json!({ "external_reference_id": "ext_3" }),
+        ))
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "message": "Subscription plan does not exist" }).to_string())
+        .create_async()
+        .await;
+    let _taken = server
+        .mock("POST", "/subscription/create")
+        .match_body(mockito::Matcher::PartialJson(
+            json!({ "external_reference_id": "ext_4" }),
+        ))
+        .with_status(422)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "message": "Validation failed",
+                "errors": [{ "field": "external_reference_id", "message": "has already been taken" }]
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let request = |external_reference_id: &str| SubscriptionCreateRequest {
+        amount: Some(99.0),
+        billing: None,
+        card_id: Some("card_1".to_string()),
+        currency: Some("TRY".to_string()),
+        cycle: None,
+        external_reference_id: Some(external_reference_id.to_string()),
+        failure_url: None,
+        payment_date: None,
+        period: Some(SubscriptionPeriod::new(1)),
+        success_url: None,
+        title: Some("Pro plan".to_string()),
+        user: None,
+        price_option: None,
+        trial_days: None,
+        trial_end_date: None,
+        recurring: None,
+    };
+
+    let client =
+        TapsilatClient::new(Config::new("test-api-key").with_base_url(server.url())).unwrap();
+    let report = client.subscriptions().create_batch_with_concurrency(
+        vec![
+            request("ext_1"),
+            request("ext_2"),
+            request("ext_1"),
+            request("ext_3"),
+            request("ext_4"),
+        ],
+        2,
+    );
+
+    assert_eq!(report.entries.len(), 5);
+    assert!(matches!(
+        &report.entries[0].outcome,
+        SubscriptionBatchOutcome::Created(response) if response.reference_id.as_deref() == Some("sub_1")
+    ));
+    assert!(matches!(
+        report.entries[1].outcome,
+        SubscriptionBatchOutcome::Duplicate { first_index: None }
+    ));
+    assert!(matches!(
+        report.entries[2].outcome,
+        SubscriptionBatchOutcome::Duplicate {
+            first_index: Some(0)
+        }
+    ));
+    assert!(matches!(
+        report.entries[4].outcome,
+        SubscriptionBatchOutcome::Duplicate { first_index: None }
+    ));
+    assert_eq!(report.created().count(), 1);
+    assert_eq!(report.duplicates().count(), 3);
+    let failed: Vec<_> = report.failed().collect();
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].external_reference_id.as_deref(), Some("ext_3"));
+    assert!(!report.is_complete());
+    created.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_subscription_preview_upcoming_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let upcoming = server
+        .mock("POST", "/subscription/upcoming")
+        .match_body(mockito::Matcher::Json(json!({ "reference_id": "sub_1" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "reference_id": "sub_1",
+                "amount": "100.00",
+                "currency": "TRY",
+                "period_start": "2024-03-01",
+                "charge_date": "2024-03-31"
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let client =
+        TapsilatClient::new(Config::new("test-api-key").with_base_url(server.url())).unwrap();
+    let invoice = client
+        .preview_upcoming_subscription_invoice("sub_1")
+        .unwrap();
+    upcoming.assert_async().await;
+    assert_eq!(invoice.amount, 100.0);
+    assert_eq!(
+        invoice.charge_date,
+        chrono::NaiveDate::from_ymd_opt(2024, 3, 31).unwrap()
+    );
+
+    let quote = invoice
+        .prorate(160.0, chrono::NaiveDate::from_ymd_opt(2024, 3, 16).unwrap())
+        .unwrap();
+    assert_eq!(quote.next_charge_amount, 190.0);
+    assert_eq!(quote.next_charge_date, invoice.charge_date);
+
+    assert!(client.subscriptions().preview_upcoming("").is_err());
+}
+
+#[tokio::test]
+async fn test_coupons_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _create = server
+        .mock("POST", "/coupon/create")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({ "id": "cpn_1", "code": "SPRING10", "discount_type": "percentage", "value": 10.0 })
+                .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let _list = server
+        .mock("GET", "/coupon/list?page=1&per_page=10")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({ "rows": [
+                { "code": "SPRING10", "discount_type": "percentage", "value": 10.0, "active": false }
+            ] })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let request = |value: f64| tapsilat::CreateCouponRequest {
+        code: "SPRING10".to_string(),
+        discount_type: tapsilat::CouponDiscountType::Percentage,
+        value,
+        currency: None,
+        max_redemptions: Some(100),
+        expires_at: None,
+    };
+
+    let coupon = client.coupons().create(request(10.0)).unwrap();
+    assert!(coupon.active);
+    assert!(client.coupons().create(request(150.0)).is_err());
+
+    let coupons = client.coupons().list(1, 10).unwrap();
+    assert_eq!(coupons.len(), 1);
+    assert!(!coupons[0].active);
+}
+
+#[tokio::test]
+async fn test_invoice_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _mock = server
+        .mock("GET", "/order/ref_inv/invoice")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "data": {
+                    "invoice_number": "ARS2024000000001",
+                    "invoice_type": "e_archive",
+                    "ettn": "f47ac10b-58cc-4372-a567-0e02b2c3d479",
+                    "pdf_url": "https://invoices/ARS2024000000001.pdf"
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let invoice = client.invoices().get_invoice("ref_inv").unwrap();
+    assert_eq!(invoice.invoice_type, Some(tapsilat::InvoiceType::EArchive));
+    assert!(invoice.pdf_url.unwrap().ends_with(".pdf"));
+
+    let mut request = minimal_order_request("conv-invoice");
+    request.invoice = Some(tapsilat::InvoiceDTO {
+        invoice_type: Some(tapsilat::InvoiceType::EArchive),
+        ..Default::default()
+    });
+    assert!(matches!(
+        client.create_order(request),
+        Err(tapsilat::TapsilatError::ValidationError(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_update_shipping_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let mock = server
+        .mock("PATCH", "/order/shipping")
+        .match_body(mockito::Matcher::Json(json!({
+            "reference_id": "ref_ship",
+            "tracking_code": "TR123456789",
+            "carrier": "Yurtiçi Kargo"
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "success": true }).to_string())
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let update = tapsilat::ShippingUpdate {
+        tracking_code: "TR123456789".to_string(),
+        carrier: Some("Yurtiçi Kargo".to_string()),
+        ..Default::default()
+    };
+    assert!(client.update_order_shipping("ref_ship", update).is_ok());
+    mock.assert_async().await;
+
+    assert!(client
+        .update_order_shipping("ref_ship", tapsilat::ShippingUpdate::default())
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_manual_payment_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let mock = server
+        .mock("POST", "/order/manual-payment")
+        .match_body(mockito::Matcher::PartialJson(json!({
+            "reference_id": "ref_eft",
+            "amount": 250.0,
+            "method": "bank_transfer",
+            "reference": "EFT-0042"
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "success": true, "paid_amount": "250.00" }).to_string())
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let payment = |payment_date: &str| tapsilat::ManualPaymentRequest {
+        amount: 250.0,
+        payment_date: payment_date.to_string(),
+        method: tapsilat::ManualPaymentMethod::BankTransfer,
+        reference: Some("EFT-0042".to_string()),
+        note: None,
+    };
+
+    assert!(client
+        .mark_order_paid_external("ref_eft", payment("2024-03-15"))
+        .is_ok());
+    mock.assert_async().await;
+    assert!(client
+        .mark_order_paid_external("ref_eft", payment("15.03.2024"))
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_bank_transfer_info_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let mock = server
+        .mock("GET", "/order/ref_eft/bank-transfer")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "data": {
+                    "bank_name": "Test Bank",
+                    "account_holder": "Tapsilat Odeme",
+                    "iban": "TR330006100519786457841326",
+                    "reference_code": "TPS-8841",
+                    "amount": "250.00",
+                    "currency": "TRY"
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let info = client.get_bank_transfer_info("ref_eft").unwrap();
+    mock.assert_async().await;
+    assert_eq!(info.iban, "TR330006100519786457841326");
+    assert_eq!(info.reference_code.as_deref(), Some("TPS-8841"));
+    assert_eq!(info.amount, Some(250.0));
+}
+
+#[tokio::test]
+async fn test_wait_for_completion_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let pending = server
+        .mock("GET", "/order/ref_wait/status")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "status": "pending" }).to_string())
+        .expect(2)
+        .create_async()
+        .await;
+    let paid = server
+        .mock("GET", "/order/ref_wait/status")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "status": "PAID" }).to_string())
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+    let options = tapsilat::PollOptions {
+        interval: std::time::Duration::from_millis(5),
+        max_interval: std::time::Duration::from_millis(10),
+        timeout: std::time::Duration::from_secs(5),
+    };
+
+    let status = client
+        .wait_for_order_completion("ref_wait", options)
+        .unwrap();
+    pending.assert_async().await;
+    paid.assert_async().await;
+    assert_eq!(status, tapsilat::OrderStatus::Paid);
+
+    let _stuck = server
+        .mock("GET", "/order/ref_stuck/status")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "status": "processing" }).to_string())
+        .create_async()
+        .await;
+    let options = tapsilat::PollOptions {
+        timeout: std::time::Duration::from_millis(30),
+        ..options
+    };
+    match client.wait_for_order_completion("ref_stuck", options) {
+        Err(tapsilat::TapsilatError::PollTimeout { last_status, .. }) => {
+            assert_eq!(last_status.as_deref(), Some("processing"))
+        }
+        other => panic!("expected poll timeout, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_wait_for_refund_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let refund = |status: &str| {
+        json!({
+            "success": true,
+            "data": { "refund_id": "rf_1", "reference_id": "ref_1", "amount": "40.00", "currency": "TRY", "status": status }
+        })
+        .to_string()
+    };
+    let processing = server
+        .mock("GET", "/order/refund/rf_1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("retry-after", "0")
+        .with_body(refund("processing"))
+        .expect(1)
+        .create_async()
+        .await;
+    let completed = server
+        .mock("GET", "/order/refund/rf_1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(refund("completed"))
+        .create_async()
+        .await;
+
+    let client =
+        TapsilatClient::new(Config::new("test-api-key").with_base_url(server.url())).unwrap();
+    let options = tapsilat::PollOptions {
+        interval: std::time::Duration::from_secs(10),
+        max_interval: std::time::Duration::from_secs(10),
+        timeout: std::time::Duration::from_secs(20),
+    };
+
+    let started = std::time::Instant::now();
+    let refund = client.wait_for_refund("rf_1", options).unwrap();
+    assert!(started.elapsed() < std::time::Duration::from_secs(5));
+    processing.assert_async().await;
+    completed.assert_async().await;
+    assert_eq!(refund.status, RefundStatus::Completed);
+    assert_eq!(refund.amount, 40.0);
+
+    let current = client.get_refund("rf_1").unwrap();
+    assert!(current.status.is_final());
+    assert!(client.get_refund("").is_err());
+}
+
+#[tokio::test]
+async fn test_refund_and_cancel_reasons_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let refund = server
+        .mock("POST", "/order/refund")
+        .match_body(mockito::Matcher::PartialJson(
+            json!({ "reference_id": "ref_1", "reason": "out_of_stock" }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "success": true }).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let cancel = server
+        .mock("POST", "/order/cancel")
+        .match_body(mockito::Matcher::Json(
+            json!({ "reference_id": "ref_2", "reason": "changed_mind" }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "success": true }).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let _refund_status = server
+        .mock("GET", "/order/refund/rf_1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({ "refund_id": "rf_1", "amount": "5.00", "status": "completed", "reason": "fraud" })
+                .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let client =
+        TapsilatClient::new(Config::new("test-api-key").with_base_url(server.url())).unwrap();
+    client
+        .refund_order(RefundOrderRequest {
+            amount: 5.0,
+            reference_id: "ref_1".to_string(),
+            order_item_id: None,
+            order_item_payment_id: None,
+            reason: Some(RefundReason::OutOfStock),
+        })
+        .unwrap();
+    client
+        .cancel_order_with_reason("ref_2", CancelReason::Other("changed_mind".to_string()))
+        .unwrap();
+    assert!(client
+        .cancel_order_with_reason("", CancelReason::Duplicate)
+        .is_err());
+    refund.assert_async().await;
+    cancel.assert_async().await;
+
+    let status = client.get_refund("rf_1").unwrap();
+    assert_eq!(status.reason, Some(RefundReason::Fraud));
+}
+
+#[tokio::test]
+async fn test_simulate_callback_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let callback = server
+        .mock("POST", "/order/callback")
+        .match_body(mockito::Matcher::Json(json!({
+            "reference_id": "ref_1",
+            "conversation_id": "conv_1",
+            "status": "paid",
+            "amount": 150.0
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "data": { "reference_id": "ref_1", "status": "PAID", "paid_amount": "150.00" }
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let legacy = server
+        .mock("POST", "/order/callback")
+        .match_body(mockito::Matcher::Json(json!({ "reference_id": "ref_2" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "success": true }).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client =
+        TapsilatClient::new(Config::new("test-api-key").with_base_url(server.url())).unwrap();
+    let response = client
+        .order_simulate_callback(
+            tapsilat::ManualCallbackRequest::new("ref_1")
+                .with_conversation_id("conv_1")
+                .with_status(tapsilat::OrderStatus::Paid)
+                .with_amount(150.0),
+        )
+        .unwrap();
+    assert_eq!(response.order_status(), Some(tapsilat::OrderStatus::Paid));
+    assert_eq!(response.paid_amount, Some(150.0));
+    client.order_manual_callback("ref_2", None).unwrap();
+    callback.assert_async().await;
+    legacy.assert_async().await;
+
+    assert!(client
+        .order_simulate_callback(tapsilat::ManualCallbackRequest::new(" "))
+        .is_err());
+    assert!(client
+        .order_simulate_callback(tapsilat::ManualCallbackRequest::new("ref_1").with_amount(-1.0))
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_response_envelopes_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _bare = server
+        .mock("GET", "/order/ref_bare")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "reference_id": "ref_bare", "amount": "10.00" }).to_string())
+        .create_async()
+        .await;
+    let _enveloped = server
+        .mock("GET", "/order/ref_enveloped")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({ "success": true, "data": { "reference_id": "ref_enveloped" } }).to_string(),
+        )
+        .create_async()
+        .await;
+    let _unsuccessful = server
+        .mock("GET", "/order/ref_rejected")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "success": false, "message": "Order is locked" }).to_string())
+        .create_async()
+        .await;
+    let _empty = server
+        .mock("GET", "/order/ref_empty")
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let bare = client.get_order("ref_bare").unwrap();
+    assert_eq!(bare.reference_id.as_deref(), Some("ref_bare"));
+    assert_eq!(bare.amount.as_deref(), Some("10.00"));
+
+    let enveloped = client.get_order("ref_enveloped").unwrap();
+    assert_eq!(enveloped.reference_id.as_deref(), Some("ref_enveloped"));
+
+    match client.get_order("ref_rejected") {
+        Err(tapsilat::TapsilatError::ApiError { message, .. }) => {
+            assert_eq!(message, "Order is locked")
+        }
+        other => panic!("expected API error, got {:?}", other),
+    }
+
+    assert!(matches!(
+        client.get_order("ref_empty"),
+        Err(tapsilat::TapsilatError::InvalidResponse(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_response_size_limit_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _large = server
+        .mock("GET", "/order/ref_large/status")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "status": "paid", "padding": "x".repeat(4096) }).to_string())
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key")
+        .with_base_url(server.url())
+        .with_max_response_size(1024);
+    let client = TapsilatClient::new(config).unwrap();
+
+    match client.get_order_status("ref_large") {
+        Err(tapsilat::TapsilatError::InvalidResponse(message)) => {
+            assert!(message.contains("1024 byte limit"))
+        }
+        other => panic!("expected size limit error, got {:?}", other),
+    }
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+    assert!(client.get_order_status("ref_large").is_ok());
+}
+
+#[tokio::test]
+async fn test_transport_error_classification() {
+    let refused_addr = {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.local_addr().unwrap()
+    };
+    let config = Config::new("test-api-key").with_base_url(format!("http://{}", refused_addr));
+    let client = TapsilatClient::new(config).unwrap();
+    match client.get_order_status("ref_1") {
+        Err(tapsilat::TapsilatError::ConnectionFailed { url, .. }) => {
+            assert!(url.ends_with("/order/ref_1/status"))
+        }
+        other => panic!("expected connection failure, got {:?}", other),
+    }
+
+    // Accepts connections but never answers
+    let silent = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let config = Config::new("test-api-key")
+        .with_base_url(format!("http://{}", silent.local_addr().unwrap()))
+        .with_timeout(1);
+    let client = TapsilatClient::new(config).unwrap();
+    match client.get_order_status("ref_1") {
+        Err(tapsilat::TapsilatError::Timeout { url }) => {
+            assert!(url.ends_with("/order/ref_1/status"))
+        }
+        other => panic!("expected timeout, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_non_json_responses_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _challenge = server
+        .mock("GET", "/order/ref_waf/status")
+        .with_status(403)
+        .with_header("content-type", "text/html; charset=utf-8")
+        .with_body("<!DOCTYPE html>\n<html>\n  <title>Attention Required!</title>\n</html>")
+        .create_async()
+        .await;
+    let _html_ok = server
+        .mock("GET", "/order/ref_html")
+        .with_status(200)
+        .with_body("<html><body>Maintenance</body></html>")
+        .create_async()
+        .await;
+    let _plain = server
+        .mock("GET", "/order/ref_plain/status")
+        .with_status(500)
+        .with_body("Internal Server Error")
+        .create_async()
+        .await;
+
+    let client =
+        TapsilatClient::new(Config::new("test-api-key").with_base_url(server.url())).unwrap();
+
+    match client.get_order_status("ref_waf") {
+        Err(tapsilat::TapsilatError::UnexpectedContentType {
+            status,
+            content_type,
+            snippet,
+        }) => {
+            assert_eq!(status, 403);
+            assert_eq!(content_type.as_deref(), Some("text/html; charset=utf-8"));
+            assert_eq!(
+                snippet,
+                "<!DOCTYPE html> <html> <title>Attention Required!</title> </html>"
+            );
+        }
+        other => panic!("expected unexpected content type, got {:?}", other),
+    }
+    match client.get_order("ref_html") {
+        Err(tapsilat::TapsilatError::UnexpectedContentType {
+            status,
+            content_type,
+            ..
+        }) => {
+            assert_eq!(status, 200);
+            assert_eq!(content_type, None);
+        }
+        other => panic!("expected unexpected content type, got {:?}", other),
+    }
+    // Plain error text without a content type is still an API error
+    assert!(matches!(
+        client.get_order_status("ref_plain"),
+        Err(tapsilat::TapsilatError::ApiError {
+            status_code: 500,
+            ..
+        })
+    ));
+}
+
+#[tokio::test]
+async fn test_payment_refund_and_capture_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let refund = server
+        .mock("POST", "/payments/pay_1/refund")
+        .match_body(mockito::Matcher::Json(json!({ "amount": 25.5 })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "data": {
+                    "refund_id": "rf_1",
+                    "payment_id": "pay_1",
+                    "amount": 25.5,
+                    "currency": "TRY",
+                    "status": "pending"
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let capture = server
+        .mock("POST", "/payments/pay_2/capture")
+        .match_body(mockito::Matcher::Json(json!({ "amount": null })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "payment_id": "pay_2",
+                "captured_amount": 100.0,
+                "status": "completed"
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let refunded = client.payments().refund("pay_1", Some(25.5)).unwrap();
+    refund.assert_async().await;
+    assert_eq!(refunded.refund_id, "rf_1");
+    assert_eq!(refunded.amount, 25.5);
+
+    let captured = client.payments().capture("pay_2", None).unwrap();
+    capture.assert_async().await;
+    assert_eq!(captured.captured_amount, 100.0);
+    assert!(matches!(
+        captured.status,
+        tapsilat::PaymentStatus::Completed
+    ));
+
+    assert!(client.payments().refund("pay_1", Some(-1.0)).is_err());
+}
+
+#[tokio::test]
+async fn test_payment_list_filters_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let mock = server
+        .mock("GET", "/payments")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("status".into(), "failed".into()),
+            mockito::Matcher::UrlEncoded("start_date".into(), "2024-01-01".into()),
+            mockito::Matcher::UrlEncoded("customer_id".into(), "cus_1".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "data": [{
+                    "id": "pay_1",
+                    "amount": 120.0,
+                    "currency": "TRY",
+                    "status": "failed",
+                    "created_at": "2024-01-03T10:00:00Z",
+                    "updated_at": "2024-01-03T10:00:05Z",
+                    "card_bin": "454360",
+                    "card_last_four": "4242",
+                    "card_brand": "VISA",
+                    "installment_count": 3,
+                    "failure_reason": "Insufficient funds"
+                }],
+                "pagination": { "current_page": 1, "per_page": 10, "total": 1, "total_pages": 1 }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let payments = client
+        .payments()
+        .list_filtered(&tapsilat::PaymentListFilter {
+            status: Some(tapsilat::PaymentStatus::Failed),
+            start_date: Some("2024-01-01".to_string()),
+            customer_id: Some("cus_1".to_string()),
+            ..Default::default()
+        })
+        .unwrap();
+    mock.assert_async().await;
+
+    let payment = &payments.data[0];
+    assert_eq!(payment.card_bin.as_deref(), Some("454360"));
+    assert_eq!(payment.card_last_four.as_deref(), Some("4242"));
+    assert_eq!(payment.installment_count, Some(3));
+    assert_eq!(
+        payment.failure_reason.as_deref(),
+        Some("Insufficient funds")
+    );
+}
+
+#[tokio::test]
+async fn test_etag_cache_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let settings = json!({ "name": "Acme", "currency": "TRY" });
+    let first = server
+        .mock("GET", "/organization/settings")
+        .match_header("if-none-match", mockito::Matcher::Missing)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("etag", "\"v1\"")
+        .with_body(settings.to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let revalidated = server
+        .mock("GET", "/organization/settings")
+        .match_header("if-none-match", "\"v1\"")
+        .with_status(304)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key")
+        .with_base_url(server.url())
+        .with_etag_cache(16);
+    let client = TapsilatClient::new(config).unwrap();
+
+    assert_eq!(client.organization().get_settings().unwrap(), settings);
+    assert_eq!(client.organization().get_settings().unwrap(), settings);
+    // Clones share the cache
+    assert_eq!(
+        client.clone().organization().get_settings().unwrap(),
+        settings
+    );
+
+    first.assert_async().await;
+    revalidated.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_not_modified_without_cache_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _mock = server
+        .mock("GET", "/organization/settings")
+        .with_status(304)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    assert!(matches!(
+        client.organization().get_settings(),
+        Err(tapsilat::TapsilatError::InvalidResponse(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_request_coalescing_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let mock = server
+        .mock("GET", "/order/order_123/status")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_chunked_body(|writer| {
+            std::thread::sleep(std::time::Duration::from_millis(300));
+            writer.write_all(br#"{"status": "processing"}"#)
+        })
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key")
+        .with_base_url(server.url())
+        .with_request_coalescing(true);
+    let client = TapsilatClient::new(config).unwrap();
+
+    let barrier = std::sync::Arc::new(std::sync::Barrier::new(4));
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let client = client.clone();
+            let barrier = std::sync::Arc::clone(&barrier);
+            std::thread::spawn(move || {
+                barrier.wait();
+                client.get_order_status("order_123").unwrap()
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        assert_eq!(handle.join().unwrap()["status"], "processing");
+    }
+    mock.assert_async().await;
+
+    // Requests with their own timeout are sent separately
+    let timed = server
+        .mock("GET", "/order/order_456/status")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_chunked_body(|writer| {
+            std::thread::sleep(std::time::Duration::from_millis(300));
+            writer.write_all(br#"{"status": "processing"}"#)
+        })
+        .expect(2)
+        .create_async()
+        .await;
+
+    let barrier = std::sync::Arc::new(std::sync::Barrier::new(2));
+    let handles: Vec<_> = (0..2)
+        .map(|_| {
+            let client = client.clone();
+            let barrier = std::sync::Arc::clone(&barrier);
+            std::thread::spawn(move || {
+                let options = tapsilat::RequestOptions::new().timeout(30);
+                barrier.wait();
+                client
+                    .orders()
+                    .get_status_with("order_456", &options)
+                    .unwrap()
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        assert_eq!(handle.join().unwrap()["status"], "processing");
+    }
+    timed.assert_async().await;
+}
+
+#[cfg(feature = "otel")]
+#[tokio::test]
+async fn test_otel_spans_with_mock() {
+    use opentelemetry::{global, Key, Value as OtelValue};
+    use opentelemetry_sdk::propagation::TraceContextPropagator;
+    use opentelemetry_sdk::trace::{InMemorySpanExporter, SdkTracerProvider};
+
+    let exporter = InMemorySpanExporter::default();
+    let provider = SdkTracerProvider::builder()
+        .with_simple_exporter(exporter.clone())
+        .build();
+    global::set_tracer_provider(provider);
+    global::set_text_map_propagator(TraceContextPropagator::new());
+
+    let mut server = setup_mock_server().await;
+    let mock = server
+        .mock("GET", "/order/otel_42/status")
+        .match_header(
+            "traceparent",
+            mockito::Matcher::Regex("^00-[0-9a-f]{32}-[0-9a-f]{16}-01$".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"status": "completed"}"#)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+    client.get_order_status("otel_42").unwrap();
+    mock.assert_async().await;
+
+    let spans = exporter.get_finished_spans().unwrap();
+    let span = spans
+        .iter()
+        .find(|span| span.name == "GET order/{id}/status")
+        .expect("span for the status request");
+    let attribute = |key: &str| {
+        span.attributes
+            .iter()
+            .find(|kv| kv.key == Key::new(key.to_string()))
+            .map(|kv| kv.value.clone())
+    };
+    assert_eq!(
+        attribute("http.response.status_code"),
+        Some(OtelValue::I64(200))
+    );
+    assert_eq!(
+        attribute("tapsilat.endpoint"),
+        Some(OtelValue::from("order/otel_42/status"))
+    );
+    assert!(attribute("tapsilat.duration_ms").is_some());
+}
+
+#[tokio::test]
+async fn test_metrics_sink_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let found = server
+        .mock("GET", "/order/order_123/status")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"status": "completed"}"#)
+        .create_async()
+        .await;
+    let missing = server
+        .mock("GET", "/order/missing_9/status")
+        .with_status(404)
+        .with_body(r#"{"message": "Order not found"}"#)
+        .create_async()
+        .await;
+
+    let recorded = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let sink = {
+        let recorded = std::sync::Arc::clone(&recorded);
+        move |endpoint: &str, method: &str, status: Option<u16>, _: std::time::Duration| {
+            recorded
+                .lock()
+                .unwrap()
+                .push((endpoint.to_string(), method.to_string(), status));
+        }
+    };
+    let config = Config::new("test-api-key")
+        .with_base_url(server.url())
+        .with_metrics_sink(sink);
+    let client = TapsilatClient::new(config).unwrap();
+
+    client.get_order_status("order_123").unwrap();
+    assert!(client.get_order_status("missing_9").is_err());
+    found.assert_async().await;
+    missing.assert_async().await;
+
+    let recorded = recorded.lock().unwrap();
+    assert_eq!(
+        *recorded,
+        vec![
+            (
+                "order/{id}/status".to_string(),
+                "GET".to_string(),
+                Some(200)
+            ),
+            (
+                "order/{id}/status".to_string(),
+                "GET".to_string(),
+                Some(404)
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_get_retries_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let unavailable = server
+        .mock("GET", "/order/order_123/status")
+        .with_status(503)
+        .with_body(r#"{"message": "Service unavailable"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let recovered = server
+        .mock("GET", "/order/order_123/status")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"status": "completed"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let rejected = server
+        .mock("POST", "/order/cancel")
+        .with_status(503)
+        .with_body(r#"{"message": "Service unavailable"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key")
+        .with_base_url(server.url())
+        .with_max_retries(2);
+    let client = TapsilatClient::new(config).unwrap();
+
+    let status = client.get_order_status("order_123").unwrap();
+    assert_eq!(status["status"], "completed");
+    // Non-GET requests are never retried
+    assert!(client.cancel_order("order_123").is_err());
+
+    unavailable.assert_async().await;
+    recovered.assert_async().await;
+    rejected.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_card_tokenize_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let mock = server
+        .mock("POST", "/card/tokenize")
+        .match_body(mockito::Matcher::PartialJson(json!({
+            "card_number": "4111111111111111",
+            "expiry_month": 12,
+            "expiry_year": 2099,
+            "holder_name": "John Doe"
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "card_token": "ctok_123",
+                "card_id": "card_9",
+                "masked_card_number": "411111******1111",
+                "card_brand": "VISA",
+                "expires_at": null
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let card = tapsilat::CardDetails {
+        card_number: "4111 1111 1111 1111".to_string(),
+        expiry_month: 12,
+        expiry_year: 2099,
+        holder_name: "John Doe".to_string(),
+    };
+    let debug = format!("{:?}", card);
+    assert!(!debug.contains("4111 1111 1111 1111"));
+    assert!(!debug.contains("John Doe"));
+
+    let token = client.tokenize_card(card.clone()).unwrap();
+    assert_eq!(token.card_token, "ctok_123");
+    assert_eq!(token.card_id.as_deref(), Some("card_9"));
+
+    let invalid = tapsilat::CardDetails {
+        card_number: "4111111111111112".to_string(),
+        ..card
+    };
+    assert!(matches!(
+        client.tokenize_card(invalid),
+        Err(tapsilat::TapsilatError::ValidationError(_))
+    ));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_wallet_payment_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let mock = server
+        .mock("POST", "/order/pay/wallet")
+        .match_body(mockito::Matcher::Json(json!({
+            "reference_id": "order_123",
+            "apple_pay_token": "{\"paymentData\":\"abc\"}"
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "payment": {
+                    "id": "pay_1",
+                    "amount": 150.75,
+                    "currency": "TRY",
+                    "status": "completed",
+                    "description": null,
+                    "customer_id": null,
+                    "created_at": "2024-01-01T00:00:00Z",
+                    "updated_at": "2024-01-01T00:00:00Z"
+                },
+                "checkout_url": null
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let request =
+        tapsilat::WalletPaymentRequest::ApplePayToken(r#"{"paymentData":"abc"}"#.to_string());
+    assert!(!format!("{:?}", request).contains("paymentData"));
+
+    let result = client.pay_order_with_wallet("order_123", request).unwrap();
+    assert_eq!(result.payment.status, tapsilat::PaymentStatus::Completed);
+    mock.assert_async().await;
+
+    assert!(client
+        .pay_order_with_wallet(
+            "order_123",
+            tapsilat::WalletPaymentRequest::GooglePayToken(String::new())
+        )
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_recurring_card_payment_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let mock = server
+        .mock("POST", "/order/pay/card")
+        .match_body(mockito::Matcher::Json(json!({
+            "reference_id": "order_123",
+            "card_token": "ctok_123",
+            "recurring": {
+                "sequence": "subsequent",
+                "merchant_initiated": true,
+                "original_transaction_reference": "txn_initial_1"
+            }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "payment": {
+                    "id": "pay_2",
+                    "amount": 99.0,
+                    "currency": "TRY",
+                    "status": "completed",
+                    "description": null,
+                    "customer_id": null,
+                    "created_at": "2024-02-01T00:00:00Z",
+                    "updated_at": "2024-02-01T00:00:00Z"
+                },
+                "checkout_url": null
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let result = client
+        .pay_order_with_card(
+            "order_123",
+            tapsilat::CardPaymentRequest {
+                card_token: "ctok_123".to_string(),
+                recurring: Some(tapsilat::RecurringIndicator::subsequent("txn_initial_1")),
+            },
+        )
+        .unwrap();
+    assert_eq!(result.payment.id, "pay_2");
+    mock.assert_async().await;
+
+    let missing_reference = tapsilat::RecurringIndicator {
+        original_transaction_reference: None,
+        ..tapsilat::RecurringIndicator::subsequent("")
+    };
+    assert!(matches!(
+        client.pay_order_with_card(
+            "order_123",
+            tapsilat::CardPaymentRequest {
+                card_token: "ctok_123".to_string(),
+                recurring: Some(missing_reference),
+            },
+        ),
+        Err(tapsilat::TapsilatError::ValidationError(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_item_capture_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _order = server
+        .mock("GET", "/order/ref_split")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "reference_id": "ref_split",
+                "basket_items": [
+                    { "id": "item_1", "name": "Boots", "price": 300.0, "quantity": 1 },
+                    { "id": "item_2", "name": "Socks", "price": 25.0, "quantity": 2 }
+                ]
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let capture = server
+        .mock("POST", "/order/postauth")
+        .match_body(mockito::Matcher::PartialJson(json!({
+            "reference_id": "ref_split",
+            "amount": 350.0,
+            "items": [
+                { "basket_item_id": "item_1", "amount": 300.0 },
+                { "basket_item_id": "item_2", "amount": 50.0 }
+            ]
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "captured": true }).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let request = |amount: f64, items: Vec<(&str, f64)>| tapsilat::OrderPostAuthRequest {
+        amount,
+        reference_id: "ref_split".to_string(),
+        items: Some(
+            items
+                .into_iter()
+                .map(|(id, amount)| tapsilat::ItemCapture {
+                    basket_item_id: id.to_string(),
+                    amount,
+                })
+                .collect(),
+        ),
+    };
+
+    client
+        .order_postauth(request(350.0, vec![("item_1", 300.0), ("item_2", 50.0)]))
+        .unwrap();
+    capture.assert_async().await;
+
+    // More than the item's total
+    assert!(matches!(
+        client.order_postauth(request(60.0, vec![("item_2", 60.0)])),
+        Err(tapsilat::TapsilatError::ValidationError(_))
+    ));
+    // Item amounts do not add up to the capture amount
+    assert!(matches!(
+        client.order_postauth(request(100.0, vec![("item_1", 90.0)])),
+        Err(tapsilat::TapsilatError::ValidationError(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_auto_tax_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let mock = server
+        .mock("POST", "/order/create")
+        .match_body(mockito::Matcher::PartialJson(json!({ "tax_amount": 20.0 })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "order_id": "order_1", "reference_id": "ref_1" }).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key")
+        .with_base_url(server.url())
+        .with_auto_tax(tapsilat::tax::PricingMode::Inclusive);
+    let client = TapsilatClient::new(config).unwrap();
+
+    let mut request = minimal_order_request("auto-tax-1");
+    request.amount = 120.0;
+    request.basket_items = Some(vec![tapsilat::types::BasketItemDTO {
+        id: Some("item_1".to_string()),
+        price: Some(120.0),
+        quantity: Some(1),
+        vat_rate: Some(tapsilat::tax::VatRate::Standard),
+        ..Default::default()
+    }]);
+
+    client.create_order(request).unwrap();
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_default_currency_and_locale_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let mock = server
+        .mock("POST", "/order/create")
+        .match_body(mockito::Matcher::PartialJson(
+            json!({ "currency": "TRY", "locale": "en" }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "order_id": "order_1", "reference_id": "ref_1" }).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key")
+        .with_base_url(server.url())
+        .with_default_currency(tapsilat::Currency::TRY)
+        .with_default_locale(tapsilat::Locale::En);
+    let client = TapsilatClient::new(config).unwrap();
+
+    let request = CreateOrderRequest::builder()
+        .amount(50.0)
+        .buyer(minimal_order_request("defaults-1").buyer)
+        .build()
+        .unwrap();
+    client.create_order(request).unwrap();
+    mock.assert_async().await;
+
+    let mut mismatched = minimal_order_request("defaults-2");
+    mismatched.currency = "USD".to_string();
+    assert!(matches!(
+        client.create_order(mismatched),
+        Err(tapsilat::TapsilatError::ValidationError(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_unique_external_reference_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _existing = server
+        .mock("GET", "/order/list")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "external_reference_id".into(),
+            "erp-1".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({ "rows": [{ "reference_id": "ref_1", "external_reference_id": "erp-1" }] })
+                .to_string(),
+        )
+        .create_async()
+        .await;
+    let _missing = server
+        .mock("GET", "/order/list")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "external_reference_id".into(),
+            "erp-2".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "rows": [] }).to_string())
+        .create_async()
+        .await;
+    let _unfiltered = server
+        .mock("GET", "/order/list")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "external_reference_id".into(),
+            "erp-3&page=2".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({ "rows": [{ "reference_id": "ref_9", "external_reference_id": "erp-9" }] })
+                .to_string(),
+        )
+        .create_async()
+        .await;
+    let create = server
+        .mock("POST", "/order/create")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "order_id": "order_2", "reference_id": "ref_2" }).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client =
+        TapsilatClient::new(Config::new("test-api-key").with_base_url(server.url())).unwrap();
+
+    let found = client.find_order_by_external_reference("erp-1").unwrap();
+    assert_eq!(found.unwrap().reference_id.as_deref(), Some("ref_1"));
+    assert!(client
+        .find_order_by_external_reference("erp-2")
+        .unwrap()
+        .is_none());
+    assert!(matches!(
+        client.find_order_by_external_reference("erp-3&page=2"),
+        Err(tapsilat::TapsilatError::InvalidResponse(_))
+    ));
+
+    let options = tapsilat::RequestOptions::new().unique_external_reference();
+    let mut request = minimal_order_request("erp-conv-1");
+    request.external_reference_id = Some("erp-1".to_string());
+    match client.orders().create_with(request, &options) {
+        Err(tapsilat::TapsilatError::DuplicateOrder { reference_id, .. }) => {
+            assert_eq!(reference_id.as_deref(), Some("ref_1"))
+        }
+        other => panic!("expected DuplicateOrder, got {:?}", other),
+    }
+
+    let mut request = minimal_order_request("erp-conv-2");
+    request.external_reference_id = Some("erp-2".to_string());
+    client.orders().create_with(request, &options).unwrap();
+    create.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_fx_rates_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let mock = server
+        .mock("GET", "/fx/rates?base=USD")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "base": "USD",
+                "rates": { "TRY": "32.50", "EUR": 0.9 },
+                "updated_at": "2024-03-01T10:00:00Z"
+            })
+            .to_string(),
+        )
+        .expect(2)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let rates = client.fx().get_rates("usd").unwrap();
+    assert_eq!(rates.rates["TRY"], 32.5);
+    assert_eq!(rates.convert(90.0, "EUR", "TRY").unwrap(), 3250.0);
+    assert!(rates.convert(10.0, "USD", "GBP").is_err());
+
+    assert_eq!(client.fx().convert(19.99, "USD", "TRY").unwrap(), 649.68);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_refund_batch_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let refunded = server
+        .mock("POST", "/order/refund")
+        .match_body(mockito::Matcher::Regex("ticket_[12]\"".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "refunded": true }).to_string())
+        .expect(2)
+        .create_async()
+        .await;
+    let rejected = server
+        .mock("POST", "/order/refund")
+        .match_body(mockito::Matcher::Regex("ticket_3\"".to_string()))
+        .with_status(400)
+        .with_body(json!({ "message": "Order already refunded" }).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let requests = ["ticket_1", "ticket_2", "ticket_3"]
+        .into_iter()
+        .map(|reference_id| RefundOrderRequest {
+            amount: 50.0,
+            reference_id: reference_id.to_string(),
+            order_item_id: None,
+            order_item_payment_id: None,
+            reason: None,
+        })
+        .collect();
+    let results = client.refund_orders(requests);
+
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_ok());
+    assert!(matches!(
+        results[2],
+        Err(tapsilat::TapsilatError::ApiError {
+            status_code: 400,
+            ..
+        })
+    ));
+    refunded.assert_async().await;
+    rejected.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_order_notes_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let add = server
+        .mock("POST", "/order/order_123/notes")
+        .match_body(mockito::Matcher::Json(
+            json!({ "text": "Customer disputes delivery" }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "data": {
+                    "id": "note_1",
+                    "text": "Customer disputes delivery",
+                    "author": "agent@example.com",
+                    "created_at": "2024-03-01T10:00:00Z"
+                }
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let list = server
+        .mock("GET", "/order/order_123/notes")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "rows": [
+                    { "id": "note_1", "text": "Customer disputes delivery", "author": null, "created_at": null },
+                    { "id": "note_2", "text": "Refund approved", "author": null, "created_at": null }
+                ]
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let note = client
+        .add_order_note("order_123", "Customer disputes delivery")
+        .unwrap();
+    assert_eq!(note.id, "note_1");
+    assert_eq!(note.author.as_deref(), Some("agent@example.com"));
+    assert!(client.add_order_note("order_123", "  ").is_err());
+
+    let notes = client.list_order_notes("order_123").unwrap();
+    assert_eq!(notes.len(), 2);
+    assert_eq!(notes[1].text, "Refund approved");
+
+    add.assert_async().await;
+    list.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_order_receipt_with_mock() {
+    let mut server = setup_mock_server().await;
+    let pdf = b"%PDF-1.4\n\x00\x01binary receipt\n%%EOF".to_vec();
+
+    let receipt = server
+        .mock("GET", "/order/order_123/receipt")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "data": {
+                    "receipt_number": "RCP-2024-0001",
+                    "reference_id": "order_123",
+                    "amount": "150.50",
+                    "currency": "TRY",
+                    "paid_at": "2024-03-01T10:00:00Z",
+                    "masked_card_number": "454360******4242",
+                    "pdf_url": "https://example.com/receipts/RCP-2024-0001.pdf"
+                }
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let download = server
+        .mock("GET", "/order/order_123/receipt/pdf")
+        .match_header("accept", "application/pdf")
+        .with_status(200)
+        .with_header("content-type", "application/pdf")
+        .with_body(pdf.clone())
+        .expect(1)
+        .create_async()
+        .await;
+    let missing = server
+        .mock("GET", "/order/order_404/receipt/pdf")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "message": "Receipt not found" }).to_string())
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let record = client.get_order_receipt("order_123").unwrap();
+    assert_eq!(record.receipt_number, "RCP-2024-0001");
+    assert_eq!(record.amount, Some(150.50));
+
+    let mut buffer = Vec::new();
+    let written = client
+        .download_order_receipt("order_123", &mut buffer)
+        .unwrap();
+    assert_eq!(written, pdf.len() as u64);
+    assert_eq!(buffer, pdf);
+
+    let mut buffer = Vec::new();
+    assert!(client
+        .download_order_receipt("order_404", &mut buffer)
+        .is_err());
+    assert!(buffer.is_empty());
+
+    receipt.assert_async().await;
+    download.assert_async().await;
+    missing.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_account_balance_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let balance = server
+        .mock("GET", "/account/balance")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "data": {
+                    "balances": [
+                        { "currency": "TRY", "available": "1250.40", "pending": 300.1, "reserved": "50.00" },
+                        { "currency": "USD", "available": 80, "pending": null }
+                    ],
+                    "updated_at": "2024-03-01T10:00:00Z"
+                }
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let account = client.account().get_balance().unwrap();
+    let lira = account.currency("try").unwrap();
+    assert_eq!(lira.available, 1250.40);
+    assert_eq!(lira.pending, 300.10);
+    assert_eq!(lira.total(), 1600.50);
+    let dollars = account.currency("USD").unwrap();
+    assert_eq!(dollars.pending, 0.0);
+    assert_eq!(dollars.reserved, 0.0);
+    assert!(account.currency("EUR").is_none());
+
+    balance.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_capabilities_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let _mock = server
+        .mock("GET", "/account/capabilities")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "data": {
+                    "payment_options": ["credit_card", "bank_transfer"],
+                    "currencies": ["TRY", "EUR"],
+                    "installments": { "min": 2, "max": 9 },
+                    "features": { "postauth": true, "subscriptions": true }
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let client =
+        TapsilatClient::new(Config::new("test-api-key").with_base_url(server.url())).unwrap();
+
+    let capabilities = client.capabilities().unwrap();
+    assert!(capabilities.supports_payment_option("CREDIT_CARD"));
+    assert!(!capabilities.supports_payment_option("apple_pay"));
+    assert!(capabilities.supports_currency("eur"));
+    assert!(!capabilities.supports_currency("USD"));
+    assert!(capabilities.allows_installments(9));
+    assert!(!capabilities.allows_installments(12));
+    assert!(capabilities.features.postauth);
+    assert!(!capabilities.features.partial_payment);
+}
+
+#[tokio::test]
+async fn test_payout_bank_accounts_with_mock() {
+    let mut server = setup_mock_server().await;
+    let account = json!({
+        "id": "ba_1",
+        "iban": "TR330006100519786457841326",
+        "account_holder": "Acme Ltd",
+        "bank_name": "Test Bank",
+        "currency": "TRY",
+        "is_default": true
+    });
+
+    let add = server
+        .mock("POST", "/account/bank-accounts")
+        .match_body(mockito::Matcher::Json(json!({
+            "iban": "TR330006100519786457841326",
+            "account_holder": "Acme Ltd",
+            "set_default": true
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "success": true, "data": account }).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let list = server
+        .mock("GET", "/account/bank-accounts")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "rows": [account, { "id": "ba_2", "iban": "TR..." }] }).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let set_default = server
+        .mock("POST", "/account/bank-accounts/ba_2/default")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "id": "ba_2", "iban": "TR...", "is_default": true }).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let remove = server
+        .mock("DELETE", "/account/bank-accounts/ba_1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "success": true }).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let invalid = client.add_bank_account(AddBankAccountRequest {
+        iban: "TR340006100519786457841326".to_string(),
+        account_holder: "Acme Ltd".to_string(),
+        currency: None,
+        set_default: None,
+    });
+    assert!(invalid.is_err());
+
+    let added = client
+        .add_bank_account(AddBankAccountRequest {
+            iban: "tr33 0006 1005 1978 6457 8413 26".to_string(),
+            account_holder: "Acme Ltd".to_string(),
+            currency: None,
+            set_default: Some(true),
+        })
+        .unwrap();
+    assert!(added.is_default);
+    assert!(!format!("{:?}", added).contains("0006100519786457"));
+
+    let accounts = client.list_bank_accounts().unwrap();
+    assert_eq!(accounts.len(), 2);
+    assert!(!accounts[1].is_default);
+
+    assert!(client.set_default_bank_account("ba_2").unwrap().is_default);
+    client.remove_bank_account("ba_1").unwrap();
+
+    add.assert_async().await;
+    list.assert_async().await;
+    set_default.assert_async().await;
+    remove.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_onboarding_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let status = server
+        .mock("GET", "/organization/suborganizations/sub_1/onboarding")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "data": {
+                    "sub_organization_id": "sub_1",
+                    "kyc_status": "pending",
+                    "documents": [
+                        { "document_type": "tax_certificate", "status": "approved" },
+                        { "document_type": "signature_circular", "status": "rejected", "rejection_reason": "Expired" },
+                        { "document_type": "bank_statement", "status": "missing" },
+                        { "document_type": "utility_bill", "status": "missing", "optional": true }
+                    ]
+                }
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let checklist = server
+        .mock(
+            "GET",
+            "/organization/suborganizations/sub_1/onboarding/documents",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({ "rows": [{ "document_type": "identity_card", "status": "uploaded" }] })
+                .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let upload = server
+        .mock(
+            "POST",
+            "/organization/suborganizations/sub_1/onboarding/documents",
+        )
+        .match_body(mockito::Matcher::Json(json!({
+            "document_type": "bank_statement",
+            "file_name": "statement.pdf",
+            "content_type": "application/pdf",
+            "content": "JVBERi0xLjQ="
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "doc_1",
+                "document_type": "bank_statement",
+                "status": "uploaded",
+                "file_name": "statement.pdf"
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let submit = server
+        .mock(
+            "POST",
+            "/organization/suborganizations/sub_1/onboarding/submit",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "sub_organization_id": "sub_1", "kyc_status": "in_review" }).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let onboarding = client.get_onboarding_status("sub_1").unwrap();
+    assert_eq!(onboarding.kyc_status, KycStatus::Pending);
+    assert!(!onboarding.is_approved());
+    let outstanding: Vec<DocumentType> = onboarding
+        .outstanding_documents()
+        .iter()
+        .map(|document| document.document_type)
+        .collect();
+    assert_eq!(
+        outstanding,
+        vec![DocumentType::SignatureCircular, DocumentType::BankStatement]
+    );
+    assert_eq!(onboarding.documents[3].document_type, DocumentType::Other);
+
+    let documents = client
+        .onboarding()
+        .list_required_documents("sub_1")
+        .unwrap();
+    assert_eq!(documents[0].document_type, DocumentType::IdentityCard);
+
+    let document = DocumentUpload {
+        document_type: DocumentType::BankStatement,
+        file_name: "statement.pdf".to_string(),
+        content_type: "application/pdf".to_string(),
+        content: b"%PDF-1.4".to_vec(),
+    };
+    assert!(!format!("{:?}", document).contains("PDF-1.4"));
+    let empty = DocumentUpload {
+        content: Vec::new(),
+        ..document.clone()
+    };
+    assert!(client.upload_onboarding_document("sub_1", empty).is_err());
+
+    let uploaded = client
+        .upload_onboarding_document("sub_1", document)
+        .unwrap();
+    assert_eq!(uploaded.id, "doc_1");
+
+    let submitted = client.submit_onboarding("sub_1").unwrap();
+    assert_eq!(submitted.kyc_status, KycStatus::InReview);
+
+    status.assert_async().await;
+    checklist.assert_async().await;
+    upload.assert_async().await;
+    submit.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_buyer_erasure_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let request = server
+        .mock("POST", "/compliance/erasure-requests")
+        .match_body(mockito::Matcher::Json(json!({ "buyer_id": "buyer_42" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "data": {
+                    "request_id": "er_1",
+                    "buyer_id": "buyer_42",
+                    "status": "pending",
+                    "requested_at": "2024-03-01T10:00:00Z"
+                }
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let status = server
+        .mock("GET", "/compliance/erasure-requests/er_1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "request_id": "er_1",
+                "buyer_id": "buyer_42",
+                "status": "rejected",
+                "reason": "Invoices must be retained for 10 years"
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    assert!(client.request_buyer_erasure(" ").is_err());
+    let erasure = client.request_buyer_erasure("buyer_42").unwrap();
+    assert_eq!(erasure.status, ErasureStatus::Pending);
+    assert!(!erasure.status.is_final());
+
+    let erasure = client.get_erasure_status(&erasure.request_id).unwrap();
+    assert_eq!(erasure.status, ErasureStatus::Rejected);
+    assert!(erasure.status.is_final());
+    assert!(erasure.reason.is_some());
+
+    request.assert_async().await;
+    status.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_api_keys_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let create = server
+        .mock("POST", "/keys")
+        .match_body(mockito::Matcher::Json(json!({
+            "name": "rotation-2024-q2",
+            "scopes": ["orders:write"]
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "data": {
+                    "id": "key_2",
+                    "name": "rotation-2024-q2",
+                    "prefix": "sk_live_9f",
+                    "scopes": ["orders:write"],
+                    "secret": "sk_live_9f8e7d6c5b4a3210"
+                }
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let list = server
+        .mock("GET", "/keys")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "rows": [
+                    { "id": "key_1", "name": "rotation-2024-q1", "revoked_at": null },
+                    { "id": "key_2", "name": "rotation-2024-q2", "scopes": ["orders:write"] }
+                ]
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let revoke = server
+        .mock("DELETE", "/keys/key_1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "success": true }).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let whoami = server
+        .mock("GET", "/keys/whoami")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "key_id": "key_1",
+                "merchant_id": "m_1",
+                "organization_name": "Acme",
+                "scopes": ["orders:read", "orders:write"],
+                "environment": "live"
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let identity = client.whoami().unwrap();
+    assert_eq!(identity.key_id, "key_1");
+    assert!(identity.has_scope("orders:write"));
+    assert!(!identity.has_scope("keys:write"));
+
+    let created = client
+        .create_api_key(CreateApiKeyRequest {
+            name: "rotation-2024-q2".to_string(),
+            scopes: Some(vec!["orders:write".to_string()]),
+            expires_at: None,
+        })
+        .unwrap();
+    assert_eq!(created.key.id, "key_2");
+    assert_eq!(created.secret.expose_secret(), "sk_live_9f8e7d6c5b4a3210");
+    assert!(!format!("{:?}", created).contains("sk_live_9f8e7d6c5b4a3210"));
+
+    let keys = client.list_api_keys().unwrap();
+    assert_eq!(keys.len(), 2);
+    assert!(keys.iter().all(|key| !key.is_revoked()));
+    client.revoke_api_key("key_1").unwrap();
+
+    whoami.assert_async().await;
+    create.assert_async().await;
+    list.assert_async().await;
+    revoke.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_read_only_client_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let get = server
+        .mock("GET", "/order/order_123")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "reference_id": "order_123", "status": 2 }).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let cancel = server
+        .mock("POST", "/order/cancel")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key")
+        .with_base_url(server.url())
+        .with_read_only(true);
+    let client = TapsilatClient::new(config).unwrap();
+
+    let order = client.get_order("order_123").unwrap();
+    assert_eq!(order.reference_id.as_deref(), Some("order_123"));
+
+    match client.cancel_order("order_123") {
+        Err(tapsilat::TapsilatError::ReadOnly { method, endpoint }) => {
+            assert_eq!(method, "POST");
+            assert_eq!(endpoint, "order/cancel");
+        }
+        other => panic!("expected a read-only error, got {:?}", other),
+    }
+
+    get.assert_async().await;
+    cancel.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_dry_run_with_mock() {
+    let mut server = setup_mock_server().await;
+
+    let create = server
+        .mock("POST", "/order/create")
+        .expect(0)
+        .create_async()
+        .await;
+    let get = server
+        .mock("GET", "/order/order_123")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "reference_id": "order_123" }).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key")
+        .with_base_url(server.url())
+        .with_dry_run(true);
+    let client = TapsilatClient::new(config).unwrap();
+
+    let response = client
+        .create_order(minimal_order_request("dry-run-1"))
+        .unwrap();
+    assert!(response.reference_id.is_none());
+    assert!(client.last_trace_id().is_none());
+
+    // Local validation still runs
+    let mut invalid = minimal_order_request("dry-run-2");
+    invalid.payment_mode = Some("NON_3D".to_string());
+    invalid.three_ds = ThreeDSPreference::Force;
+    assert!(client.create_order(invalid).is_err());
+
+    // Lookups are still sent
+    client.get_order("order_123").unwrap();
+
+    // Per-request dry runs on a normal client
+    let client =
+        TapsilatClient::new(Config::new("test-api-key").with_base_url(server.url())).unwrap();
+    let options = tapsilat::RequestOptions::new().dry_run();
+    client
+        .orders()
+        .create_with(minimal_order_request("dry-run-3"), &options)
+        .unwrap();
+
+    create.assert_async().await;
+    get.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_typed_request_with_mock() {
+    #[derive(serde::Serialize)]
+    struct PayoutRequest {
+        amount: f64,
+    }
+
+    #[derive(serde::Deserialize)]
+    struct Payout {
+        id: String,
+        amount: f64,
+    }
+
+    let mut server = setup_mock_server().await;
+
+    let create = server
+        .mock("POST", "/payouts")
+        .match_body(mockito::Matcher::Json(json!({ "amount": 250.0 })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({ "success": true, "data": { "id": "po_1", "amount": 250.0 } }).to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let list = server
+        .mock("GET", "/payouts?status=pending")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!([{ "id": "po_1", "amount": 250.0 }]).to_string())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let payout: Payout = client
+        .request(
+            tapsilat::Method::Post,
+            "payouts",
+            Some(&PayoutRequest { amount: 250.0 }),
+        )
+        .unwrap();
+    assert_eq!(payout.id, "po_1");
+    assert_eq!(payout.amount, 250.0);
+
+    let payouts: Vec<Payout> = client
+        .request::<(), _>(tapsilat::Method::Get, "payouts?status=pending", None)
+        .unwrap();
+    assert_eq!(payouts.len(), 1);
+
+    let mismatch: tapsilat::Result<Vec<String>> =
+        client.request::<(), _>(tapsilat::Method::Get, "payouts?status=pending", None);
+    assert!(matches!(
+        mismatch,
+        Err(tapsilat::TapsilatError::InvalidResponse(_))
+    ));
+
+    create.assert_async().await;
+    list.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_detailed_responses_with_mock() {
+    let mut server = setup_mock_server().await;
+    let body = json!({
+        "success": true,
+        "data": { "reference_id": "order_123", "status": 2 },
+        "message": "ok"
+    });
+
+    let get = server
+        .mock("GET", "/order/order_123")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("X-Request-Id", "trace-detailed")
+        .with_header("X-RateLimit-Remaining", "42")
+        .with_body(body.to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let create = server
+        .mock("POST", "/order/create")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "order_id": "o_1", "reference_id": "order_124" }).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = Config::new("test-api-key").with_base_url(server.url());
+    let client = TapsilatClient::new(config).unwrap();
+
+    let detailed = client.get_order_detailed("order_123").unwrap();
+    assert_eq!(detailed.value.reference_id.as_deref(), Some("order_123"));
+    assert_eq!(detailed.raw, body);
+    assert_eq!(detailed.status, Some(200));
+    assert_eq!(detailed.header("x-ratelimit-remaining"), Some("42"));
+    assert_eq!(detailed.header("X-Request-Id"), Some("trace-detailed"));
+
+    let created = client
+        .create_order_detailed(minimal_order_request("detailed-1"))
+        .unwrap();
+    assert_eq!(created.status, Some(201));
+    assert_eq!(created.into_inner().order_id.as_deref(), Some("o_1"));
+
+    get.assert_async().await;
+    create.assert_async().await;
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn test_subscription_stream_with_mock() {
+    use futures_core::Stream;
+    use std::pin::Pin;
+
+    let mut server = setup_mock_server().await;
+
+    let first = server
+        .mock("GET", "/subscription/list?page=1&per_page=2")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({ "rows": [{ "reference_id": "sub_1" }, { "reference_id": "sub_2" }] })
+                .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let second = server
+        .mock("GET", "/subscription/list?page=2&per_page=2")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "rows": [{ "reference_id": "sub_3" }] }).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let _slow = server
+        .mock("GET", "/subscription/list?page=1&per_page=5")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_chunked_body(|writer| {
+            std::thread::sleep(std::time::Duration::from_millis(500));
+            writer.write_all(br#"{"rows": []}"#)
+        })
+        .create_async()
+        .await;
+
+    let client =
+        TapsilatClient::new(Config::new("test-api-key").with_base_url(server.url())).unwrap();
+
+    let mut stream = client.subscriptions().stream().per_page(2);
+    let mut references = Vec::new();
+    while let Some(item) = std::future::poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await {
+        references.push(item.unwrap().reference_id.unwrap());
+    }
+    assert_eq!(references, ["sub_1", "sub_2", "sub_3"]);
+    first.assert_async().await;
+    second.assert_async().await;
+
+    let mut stream = client
+        .subscriptions()
+        .stream()
+        .per_page(5)
+        .page_timeout(std::time::Duration::from_millis(50));
+    match std::future::poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await {
+        Some(Err(tapsilat::TapsilatError::Timeout { url })) => {
+            assert!(url.ends_with("/subscription/list?page=1&per_page=5"))
+        }
+        other => panic!("expected page timeout, got {:?}", other),
+    }
+    assert!(
+        std::future::poll_fn(|cx| Pin::new(&mut stream).poll_next(cx))
+            .await
+            .is_none()
+    );
+}
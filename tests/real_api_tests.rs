@@ -3,7 +3,8 @@
 
 use std::env;
 use tapsilat::{
-    Config, CreateInstallmentPlanRequest, CreateOrderRequest, TapsilatClient, Validators,
+    Config, CreateInstallmentPlanRequest, CreateOrderRequest, TapsilatClient, ThreeDSPreference,
+    Validators,
 };
 
 fn skip_if_no_api_key() -> Option<String> {
@@ -71,6 +72,7 @@ fn test_real_api_order_validation() {
                 quantity_unit: None,
                 sub_merchant_key: None,
                 sub_merchant_price: None,
+                vat_rate: None,
             }]),
             buyer: tapsilat::types::CreateBuyerRequest {
                 name: "John".to_string(),
@@ -83,6 +85,7 @@ fn test_real_api_order_validation() {
                 city: None,
                 country: None,
                 zip_code: None,
+                citizenship: None,
             },
             metadata: None,
             billing_address: None,
@@ -105,8 +108,10 @@ fn test_real_api_order_validation() {
             sub_organization: None,
             submerchants: None,
             tax_amount: None,
-            three_d_force: None,
+            three_ds: ThreeDSPreference::Auto,
             consents: None,
+            checkout_expires_in: None,
+            invoice: None,
         };
 
         // Validate the order request structure
@@ -191,6 +196,7 @@ fn test_real_api_live_order_creation() {
                 quantity_unit: None,
                 sub_merchant_key: None,
                 sub_merchant_price: None,
+                vat_rate: None,
             }]),
             buyer: tapsilat::types::CreateBuyerRequest {
                 name: "John".to_string(),
@@ -203,6 +209,7 @@ fn test_real_api_live_order_creation() {
                 city: None,
                 country: None,
                 zip_code: None,
+                citizenship: None,
             },
             metadata: None,
             billing_address: None,
@@ -225,8 +232,10 @@ fn test_real_api_live_order_creation() {
             sub_organization: None,
             submerchants: None,
             tax_amount: None,
-            three_d_force: None,
+            three_ds: ThreeDSPreference::Auto,
             consents: None,
+            checkout_expires_in: None,
+            invoice: None,
         };
 
         // This would make a real API call